@@ -41,3 +41,29 @@ pub struct LittleEndian {}
 impl Endianness for LittleEndian {
     const KIND: EndianKind = EndianKind::Little;
 }
+
+/// The endianness of the target platform, i.e. [LittleEndian] or [BigEndian].
+/// Reading and writing fields of a layout with native endianness doesn't need to swap any bytes,
+/// but the binary representation then depends on the platform, so only use it for data that doesn't leave the machine.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(samples, NativeEndian, {
+///   data: [u32],
+/// });
+///
+/// # fn main() {
+/// let storage = 7u32.to_ne_bytes();
+/// assert_eq!(Some(7), samples::View::new(&storage).data().get(0));
+/// # }
+/// ```
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+/// The endianness of the target platform, i.e. [LittleEndian] or [BigEndian].
+/// Reading and writing fields of a layout with native endianness doesn't need to swap any bytes,
+/// but the binary representation then depends on the platform, so only use it for data that doesn't leave the machine.
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
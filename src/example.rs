@@ -4,9 +4,13 @@ use crate::prelude::*;
 
 // See https://en.wikipedia.org/wiki/Internet_Control_Message_Protocol for ICMP package layout
 define_layout!(icmp_packet, BigEndian, {
+  /// The type of the ICMP message, e.g. 8 for an echo request
   packet_type: u8,
+  /// Further specifies the message within its type
   code: u8,
+  /// Internet checksum over the whole ICMP message
   checksum: u16,
+  /// Contents depend on the type and code of the message
   rest_of_header: [u8; 4],
   data_section: [u8], // open ended byte array, matches until the end of the packet
 });
@@ -0,0 +1,48 @@
+use super::primitive::FieldCopyAccess;
+
+/// Implemented by the `View` struct the [define_layout!](crate::define_layout!) macro generates for each layout,
+/// once for each of its fields with [copy access](crate::FieldCopyAccess). This allows generic code to
+/// require a view that has a field `F` with a certain type at a certain offset, independent of which layout it belongs to.
+///
+/// `F` is the field, e.g. `my_layout::field2`. `K` is a hidden key type generated for each field. It is needed because
+/// zero sized fields at the same offset have the same field type. Generic code doesn't need to name it and can leave it to type inference.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(header, LittleEndian, {
+///   version: u16,
+///   length: u32,
+/// });
+///
+/// define_layout!(packet, LittleEndian, {
+///   version: u16,
+///   payload: [u8; 4],
+/// });
+///
+/// fn version<F, K>(view: &impl ReadField<F, K>) -> u16
+/// where
+///   F: FieldCopyAccess<HighLevelType = u16>,
+/// {
+///   view.read_field()
+/// }
+///
+/// # fn main() {
+/// let header = header::View::new([1, 0, 0, 0, 0, 0]);
+/// let packet = packet::View::new([2, 0, 0, 0, 0, 0]);
+/// assert_eq!(1, version::<header::version, _>(&header));
+/// assert_eq!(2, version::<packet::version, _>(&packet));
+/// # }
+/// ```
+pub trait ReadField<F: FieldCopyAccess, K> {
+    /// Read the field `F` from the storage of the view. This panics if the storage is too small to contain the field.
+    fn read_field(&self) -> F::HighLevelType;
+}
+
+/// Implemented by the `View` struct the [define_layout!](crate::define_layout!) macro generates for each layout
+/// if its storage is writable, once for each of its fields with [copy access](crate::FieldCopyAccess). See [ReadField].
+pub trait WriteField<F: FieldCopyAccess, K>: ReadField<F, K> {
+    /// Write the field `F` to the storage of the view. This panics if the storage is too small to contain the field.
+    fn write_field(&mut self, value: F::HighLevelType);
+}
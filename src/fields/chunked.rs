@@ -0,0 +1,119 @@
+use super::peek::BufferTooSmall;
+use super::primitive::FieldCopyAccess;
+use crate::LayoutError;
+
+/// A [ChunkedReader] reads fields with [copy access](crate::FieldCopyAccess) from a storage that is split into several
+/// non-contiguous chunks, e.g. buffers of a message that arrived separately and haven't been reassembled yet.
+/// The chunks are given in order and together make up the storage of the layout.
+///
+/// Fields that lie within the first chunk are read from it directly. For all other fields, only the bytes of the field are copied,
+/// even if they span a boundary between chunks. This is only available if the `std` feature is enabled.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::ChunkedReader;
+///
+/// define_layout!(header, BigEndian, {
+///   msg_type: u8,
+///   length: u32,
+/// });
+///
+/// # fn main() {
+/// let chunks: [&[u8]; 2] = [&[1, 0, 0], &[1, 2, 0xFF]];
+/// let reader = ChunkedReader::new(&chunks);
+/// assert_eq!(Ok(1), reader.read::<header::msg_type>());
+/// assert_eq!(Ok(0x0102), reader.read::<header::length>());
+/// assert_eq!(6, reader.len());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkedReader<'a> {
+    chunks: &'a [&'a [u8]],
+}
+
+impl<'a> ChunkedReader<'a> {
+    /// Create a reader over the given chunks, in the order they make up the storage.
+    #[inline]
+    pub fn new(chunks: &'a [&'a [u8]]) -> Self {
+        Self { chunks }
+    }
+
+    /// Total number of bytes in all chunks
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.len()).sum()
+    }
+
+    /// Return true if the chunks don't contain any bytes
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read the field `F` from the chunks, copying its bytes if they aren't within the first chunk.
+    /// This returns a [LayoutError::BufferTooSmall] error if the chunks together are too small to contain the field.
+    pub fn read<F: FieldCopyAccess>(&self) -> Result<F::HighLevelType, LayoutError> {
+        // Fields with copy access always have a static size
+        let required = F::OFFSET + F::SIZE.unwrap_or(0);
+        if let Some(first) = self.chunks.first() {
+            if required <= first.len() {
+                return Ok(F::read(first));
+            }
+        }
+
+        let actual = self.len();
+        if actual < required {
+            return Err(BufferTooSmall { required, actual }.into());
+        }
+
+        // The field accessors expect the field at its offset, so we copy its bytes into a zeroed buffer at that offset.
+        let mut storage = vec![0; required];
+        let mut chunk_start = 0;
+        for chunk in self.chunks {
+            let chunk_end = chunk_start + chunk.len();
+            let start = F::OFFSET.max(chunk_start);
+            let end = required.min(chunk_end);
+            if start < end {
+                storage[start..end]
+                    .copy_from_slice(&chunk[(start - chunk_start)..(end - chunk_start)]);
+            }
+            if chunk_end >= required {
+                break;
+            }
+            chunk_start = chunk_end;
+        }
+        Ok(F::read(&storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::PrimitiveField;
+
+    #[test]
+    fn test_read_across_chunks() {
+        type Field1 = PrimitiveField<u32, LittleEndian, 3>;
+
+        let chunks: [&[u8]; 4] = [&[0, 0], &[0, 1, 2], &[], &[3, 4, 5]];
+        let reader = ChunkedReader::new(&chunks);
+        assert_eq!(Ok(0x04030201), reader.read::<Field1>());
+        assert_eq!(8, reader.len());
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 7,
+                actual: 5
+            }),
+            ChunkedReader::new(&chunks[..2]).read::<Field1>()
+        );
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 7,
+                actual: 0
+            }),
+            ChunkedReader::new(&[]).read::<Field1>()
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use super::info::FieldInfo;
+use super::migrate::LayoutViewMut;
+use super::primitive::{FieldCopyAccess, FieldSliceAccess};
+use super::Field;
+
+/// Writes the fields of a view one after the other, in the order they were declared in the layout.
+/// The [define_layout!](crate::define_layout!) macro generates a `Cursor<S>` alias for it in each layout module,
+/// which is created with the `cursor(storage)` function of the layout.
+///
+/// The cursor remembers which field comes next and panics if a different field is written,
+/// so fields that were forgotten or written out of order are caught when writing the record.
+/// A field is identified by its offset and its size, so a field of another layout with the same offset and size is accepted as well.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: [u8; 2],
+///   field3: u32,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 8];
+/// let mut cursor = my_layout::cursor(&mut storage);
+/// cursor
+///     .write_next::<my_layout::field1>(10)
+///     .write_next_bytes::<my_layout::field2>(&[1, 2])
+///     .write_next::<my_layout::field3>(20);
+/// assert!(cursor.is_complete());
+/// assert_eq!([10, 0, 1, 2, 20, 0, 0, 0], storage);
+/// # }
+/// ```
+pub struct LayoutCursor<V> {
+    view: V,
+    index: usize,
+}
+
+impl<V: LayoutViewMut> LayoutCursor<V> {
+    /// Create a cursor that starts at the first field of the view
+    #[inline]
+    pub fn new(view: V) -> Self {
+        Self { view, index: 0 }
+    }
+
+    /// Return the metadata of the field that has to be written next, or `None` if all fields were written
+    #[inline]
+    pub fn next_field(&self) -> Option<&'static FieldInfo> {
+        V::FIELDS.get(self.index)
+    }
+
+    /// Return true if all fields of the layout were written
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.index == V::FIELDS.len()
+    }
+
+    /// Write the field `F` with [copy access](crate::FieldCopyAccess) and advance to the next field.
+    /// This panics if `F` isn't the next field of the layout or if the storage is too small to contain it.
+    #[inline]
+    pub fn write_next<F: FieldCopyAccess>(&mut self, value: F::HighLevelType) -> &mut Self {
+        self.advance::<F>();
+        F::write(self.view.bytes_mut(), value);
+        self
+    }
+
+    /// Copy `bytes` into the byte array field `F` and advance to the next field.
+    /// This panics if `F` isn't the next field of the layout, if the storage is too small to contain it
+    /// or if `bytes` doesn't have the length of the field.
+    #[inline]
+    pub fn write_next_bytes<F>(&mut self, bytes: &[u8]) -> &mut Self
+    where
+        F: for<'a> FieldSliceAccess<'a>,
+        for<'a> <F as FieldSliceAccess<'a>>::MutSliceType: AsMut<[u8]>,
+    {
+        self.advance::<F>();
+        F::data_mut(self.view.bytes_mut())
+            .as_mut()
+            .copy_from_slice(bytes);
+        self
+    }
+
+    /// This destroys the cursor and returns the view back to you
+    #[inline]
+    pub fn into_view(self) -> V {
+        self.view
+    }
+
+    fn advance<F: Field>(&mut self) {
+        match self.next_field() {
+            Some(field) if field.offset == F::OFFSET && field.size == F::SIZE => self.index += 1,
+            Some(field) => panic!(
+                "Error: Fields must be written in declaration order, the next field is `{}`",
+                field.name
+            ),
+            None => panic!("Error: All fields of the layout were already written"),
+        }
+    }
+}
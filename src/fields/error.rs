@@ -0,0 +1,207 @@
+use core::fmt;
+use core::str::Utf8Error;
+
+use super::peek::BufferTooSmall;
+use super::primitive::{InvalidChar, IterLengthError, LengthError, RangeError, TooShort};
+
+/// The common error type of this crate. It is returned by all fallible accessors, e.g. `peek_${field_name}`, `data_checked`,
+/// `copy_into` or reading a `char` field, and when a storage doesn't match the size of a layout,
+/// e.g. by `View::new_exact` generated by the [define_layout!](crate::define_layout!) macro.
+///
+/// The more specific types [BufferTooSmall], [RangeError], [InvalidChar], [TooShort], [LengthError], [IterLengthError] and [Utf8Error]
+/// describe a single kind of failure and all convert into it, so they can be propagated together with the `?` operator.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::LayoutError;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: char,
+/// });
+///
+/// fn parse(storage: &[u8]) -> Result<(u16, char), LayoutError> {
+///   let view = my_layout::View::new_exact(storage)?;
+///   Ok((view.field1().read(), view.field2().read()?))
+/// }
+///
+/// # fn main() {
+/// assert_eq!(Ok((5, 'a')), parse(&[5, 0, 0x61, 0, 0, 0]));
+/// assert_eq!(Err(LayoutError::InvalidChar(0xD800)), parse(&[5, 0, 0, 0xD8, 0, 0]));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LayoutError {
+    /// The storage is too small to contain all fields of the layout
+    BufferTooSmall {
+        /// Number of bytes the storage would need to have
+        required: usize,
+        /// Number of bytes the storage actually has
+        actual: usize,
+    },
+    /// The storage has trailing bytes after the last field of the layout
+    BufferTooLarge {
+        /// Number of bytes the storage should have
+        expected: usize,
+        /// Number of bytes the storage actually has
+        actual: usize,
+    },
+    /// An open ended byte array is shorter than required, see [TooShort]
+    TooShort {
+        /// The minimal length that was required
+        min_len: usize,
+        /// The actual length of the byte array
+        actual_len: usize,
+    },
+    /// A destination buffer is too small to hold the data copied into it, see [LengthError]
+    DestinationTooShort {
+        /// Number of bytes the destination buffer would need to have
+        required: usize,
+        /// Number of bytes the destination buffer actually has
+        actual: usize,
+    },
+    /// An iterator written into a field yielded too few or too many elements, see [IterLengthError]
+    IterLength {
+        /// Number of elements the field holds
+        expected: usize,
+        /// Number of elements the iterator yielded, or `expected + 1` if it yielded too many
+        actual: usize,
+    },
+    /// A value can't be converted into the type of a field without losing information, see [RangeError]
+    OutOfRange,
+    /// A stored code point isn't a valid `char`, see [InvalidChar]. It carries the raw stored value.
+    InvalidChar(u32),
+    /// A tag value doesn't select any of the layouts of a layout enum, see [define_layout_enum!](crate::define_layout_enum!).
+    /// It carries the tag value.
+    UnknownTag(u128),
+    /// A stored flags value has bits set that don't correspond to any defined flag.
+    /// It carries the raw stored value.
+    UnknownFlags(u128),
+    /// Stored bytes that are read as a string aren't valid UTF-8.
+    Utf8Error(Utf8Error),
+    /// A field of a record built with a `Builder` was neither set nor defaulted, see `MissingFields`.
+    /// It carries the name of the first missing field.
+    MissingField(&'static str),
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::BufferTooSmall { required, actual } => write!(
+                f,
+                "Storage too small: required {} bytes but only got {}",
+                required, actual
+            ),
+            LayoutError::BufferTooLarge { expected, actual } => write!(
+                f,
+                "Storage too large: expected {} bytes but got {}",
+                expected, actual
+            ),
+            LayoutError::TooShort {
+                min_len,
+                actual_len,
+            } => fmt::Display::fmt(
+                &TooShort {
+                    min_len: *min_len,
+                    actual_len: *actual_len,
+                },
+                f,
+            ),
+            LayoutError::DestinationTooShort { required, actual } => fmt::Display::fmt(
+                &LengthError {
+                    required: *required,
+                    actual: *actual,
+                },
+                f,
+            ),
+            LayoutError::IterLength { expected, actual } => fmt::Display::fmt(
+                &IterLengthError {
+                    expected: *expected,
+                    actual: *actual,
+                },
+                f,
+            ),
+            LayoutError::OutOfRange => fmt::Display::fmt(&RangeError, f),
+            LayoutError::InvalidChar(value) => fmt::Display::fmt(&InvalidChar(*value), f),
+            LayoutError::UnknownTag(value) => write!(f, "Unknown tag value {}", value),
+            LayoutError::UnknownFlags(value) => {
+                write!(f, "Stored value {:#x} has unknown flags set", value)
+            }
+            LayoutError::Utf8Error(error) => fmt::Display::fmt(error, f),
+            LayoutError::MissingField(name) => write!(f, "Missing required field: {}", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LayoutError {}
+
+impl From<BufferTooSmall> for LayoutError {
+    fn from(error: BufferTooSmall) -> Self {
+        LayoutError::BufferTooSmall {
+            required: error.required,
+            actual: error.actual,
+        }
+    }
+}
+
+impl From<TooShort> for LayoutError {
+    fn from(error: TooShort) -> Self {
+        LayoutError::TooShort {
+            min_len: error.min_len,
+            actual_len: error.actual_len,
+        }
+    }
+}
+
+impl From<LengthError> for LayoutError {
+    fn from(error: LengthError) -> Self {
+        LayoutError::DestinationTooShort {
+            required: error.required,
+            actual: error.actual,
+        }
+    }
+}
+
+impl From<IterLengthError> for LayoutError {
+    fn from(error: IterLengthError) -> Self {
+        LayoutError::IterLength {
+            expected: error.expected,
+            actual: error.actual,
+        }
+    }
+}
+
+impl From<RangeError> for LayoutError {
+    fn from(_: RangeError) -> Self {
+        LayoutError::OutOfRange
+    }
+}
+
+impl From<InvalidChar> for LayoutError {
+    fn from(error: InvalidChar) -> Self {
+        LayoutError::InvalidChar(error.0)
+    }
+}
+
+impl From<Utf8Error> for LayoutError {
+    fn from(error: Utf8Error) -> Self {
+        LayoutError::Utf8Error(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<super::owned::MissingFields> for LayoutError {
+    fn from(error: super::owned::MissingFields) -> Self {
+        LayoutError::MissingField(error.fields.first().copied().unwrap_or_default())
+    }
+}
+
+#[cfg(feature = "bitflags")]
+impl<B: Into<u128>> From<super::primitive::UnknownFlags<B>> for LayoutError {
+    fn from(error: super::primitive::UnknownFlags<B>) -> Self {
+        LayoutError::UnknownFlags(error.0.into())
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::{self, Read, Write};
+
+use super::error::LayoutError;
+use super::primitive::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+type FrameLength<E> = PrimitiveField<u32, E, 0>;
+
+/// Internal function, don't use!
+/// Writes the storage of a view to `w`, prefixed with its length as a `u32` in endianness `E`. This backs `View::write_framed`
+/// generated by the define_layout! macro. Storages longer than [u32::MAX] bytes can't be framed and return an [io::ErrorKind::InvalidInput] error.
+pub fn write_framed<E: Endianness>(storage: &[u8], mut w: impl Write) -> io::Result<()> {
+    let len: u32 = storage.len().try_into().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Error: The storage is too large to be framed with a u32 length",
+        )
+    })?;
+    let mut prefix = [0; 4];
+    FrameLength::<E>::write(&mut prefix, len);
+    w.write_all(&prefix)?;
+    w.write_all(storage)
+}
+
+/// Internal function, don't use!
+/// Reads a frame written by [write_framed] from `r` and returns the bytes of the record. This backs `View::read_framed`
+/// generated by the define_layout! macro. Frames longer than `max_len` are rejected with an [io::ErrorKind::InvalidData] error
+/// before anything is allocated for them.
+pub fn read_framed<E: Endianness>(mut r: impl Read, max_len: Option<usize>) -> io::Result<Vec<u8>> {
+    let mut prefix = [0; 4];
+    r.read_exact(&mut prefix)?;
+    let len = FrameLength::<E>::read(&prefix) as usize;
+    if let Some(max_len) = max_len {
+        if len > max_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                LayoutError::BufferTooLarge {
+                    expected: max_len,
+                    actual: len,
+                },
+            ));
+        }
+    }
+    let mut storage = vec![0; len];
+    r.read_exact(&mut storage)?;
+    Ok(storage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_round_trip() {
+        let mut buffer = Vec::new();
+        write_framed::<BigEndian>(&[1, 2, 3], &mut buffer).unwrap();
+        write_framed::<BigEndian>(&[], &mut buffer).unwrap();
+        assert_eq!(&[0, 0, 0, 3, 1, 2, 3, 0, 0, 0, 0], &*buffer);
+
+        let mut cursor = Cursor::new(buffer);
+        assert_eq!(
+            vec![1, 2, 3],
+            read_framed::<BigEndian>(&mut cursor, None).unwrap()
+        );
+        assert_eq!(
+            Vec::<u8>::new(),
+            read_framed::<BigEndian>(&mut cursor, None).unwrap()
+        );
+        assert_eq!(
+            io::ErrorKind::UnexpectedEof,
+            read_framed::<BigEndian>(&mut cursor, None)
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn test_too_long_frame() {
+        let mut buffer = Vec::new();
+        write_framed::<BigEndian>(&[1, 2, 3], &mut buffer).unwrap();
+        assert_eq!(
+            vec![1, 2, 3],
+            read_framed::<BigEndian>(Cursor::new(&buffer), Some(3)).unwrap()
+        );
+        assert_eq!(
+            io::ErrorKind::InvalidData,
+            read_framed::<BigEndian>(Cursor::new(&buffer), Some(2))
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn test_truncated_frame() {
+        let mut buffer = Vec::new();
+        write_framed::<LittleEndian>(&[1, 2, 3], &mut buffer).unwrap();
+        assert_eq!(&[3, 0, 0, 0], &buffer[..4]);
+        buffer.pop();
+        assert_eq!(
+            io::ErrorKind::UnexpectedEof,
+            read_framed::<LittleEndian>(Cursor::new(buffer), None)
+                .unwrap_err()
+                .kind()
+        );
+    }
+}
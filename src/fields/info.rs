@@ -0,0 +1,319 @@
+/// Metadata of a field in a layout. The [define_layout!](crate::define_layout!) macro generates a `FIELDS` table
+/// with one entry for each field, in the order they were declared.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::FieldInfo;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   tail: [u8],
+/// });
+///
+/// assert_eq!(
+///   &[
+///     FieldInfo { name: "field1", offset: 0, size: Some(2) },
+///     FieldInfo { name: "tail", offset: 2, size: None },
+///   ],
+///   my_layout::FIELDS,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Name of the field
+    pub name: &'static str,
+    /// Offset of the field in the layout, see [Field::OFFSET](crate::Field::OFFSET)
+    pub offset: usize,
+    /// Size of the field, see [Field::SIZE](crate::Field::SIZE)
+    pub size: Option<usize>,
+}
+
+/// Metadata of a field in a layout, extending [FieldInfo] with the declared type and the byte order of the field.
+/// The [define_layout!](crate::define_layout!) macro generates a `FIELD_META` table with one entry for each field,
+/// in the order they were declared, so tools can render the complete schema of a layout at runtime.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::FieldMeta;
+///
+/// define_layout!(my_layout, BigEndian, {
+///   field1: u16,
+///   tail: [u8],
+/// });
+///
+/// assert_eq!(
+///   &[
+///     FieldMeta { name: "field1", offset: 0, size: Some(2), type_name: "u16", little_endian: false },
+///     FieldMeta { name: "tail", offset: 2, size: None, type_name: "[u8]", little_endian: false },
+///   ],
+///   my_layout::FIELD_META,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldMeta {
+    /// Name of the field
+    pub name: &'static str,
+    /// Offset of the field in the layout, see [Field::OFFSET](crate::Field::OFFSET)
+    pub offset: usize,
+    /// Size of the field, see [Field::SIZE](crate::Field::SIZE)
+    pub size: Option<usize>,
+    /// Type of the field as written in the layout definition, e.g. `"u16"` or `"[u8; 4]"`
+    pub type_name: &'static str,
+    /// Whether the field is stored in little endian byte order, see [Field::IS_LITTLE_ENDIAN](crate::Field::IS_LITTLE_ENDIAN)
+    pub little_endian: bool,
+}
+
+/// Internal function, don't use!
+/// Returns true if the shorter one of the two field tables is a prefix of the other one, comparing field offsets and sizes.
+pub const fn fields_prefix_compatible(lhs: &[FieldInfo], rhs: &[FieldInfo]) -> bool {
+    let mut i = 0;
+    while i < lhs.len() && i < rhs.len() {
+        if lhs[i].offset != rhs[i].offset {
+            return false;
+        }
+        match (lhs[i].size, rhs[i].size) {
+            (Some(lhs_size), Some(rhs_size)) if lhs_size == rhs_size => {}
+            (None, None) => {}
+            _ => return false,
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Internal function, don't use!
+/// Panics if the byte ranges of two fields intersect. Fields without a static size extend to the end of the storage
+/// and fields with a size of zero don't overlap with anything. An open ended field followed by a `@trailer` section
+/// doesn't overlap the trailer fields, since their offsets are the ones they have if the open ended field is empty.
+pub const fn check_no_overlap(fields: &[FieldInfo]) {
+    let mut i = 0;
+    while i < fields.len() {
+        let mut j = if is_trailed(fields, i) {
+            fields.len()
+        } else {
+            i + 1
+        };
+        while j < fields.len() {
+            assert!(
+                !ranges_overlap(&fields[i], &fields[j]),
+                "Error: Two fields of the layout overlap"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Internal function, don't use!
+/// Panics if the offset of a field isn't strictly greater than the offset of the field declared before it.
+/// Only a field following a zero-size field may start at the same offset. An open ended field followed by a `@trailer` section
+/// counts as zero-size here, since the offsets of the trailer fields are the ones they have if the open ended field is empty.
+pub const fn check_ascending_offsets(fields: &[FieldInfo]) {
+    let mut i = 1;
+    while i < fields.len() {
+        let previous_is_zero_size = match fields[i - 1].size {
+            Some(size) => size == 0,
+            None => true,
+        };
+        assert!(
+            fields[i].offset > fields[i - 1].offset
+                || (previous_is_zero_size && fields[i].offset == fields[i - 1].offset),
+            "Error: The fields of the layout aren't in ascending order of their offsets"
+        );
+        i += 1;
+    }
+}
+
+const fn ranges_overlap(lhs: &FieldInfo, rhs: &FieldInfo) -> bool {
+    const fn starts_before_end(field: &FieldInfo, end_of: &FieldInfo) -> bool {
+        match end_of.size {
+            Some(size) => field.offset < end_of.offset + size,
+            None => true,
+        }
+    }
+    if matches!(lhs.size, Some(0)) || matches!(rhs.size, Some(0)) {
+        return false;
+    }
+    starts_before_end(lhs, rhs) && starts_before_end(rhs, lhs)
+}
+
+// The first trailer field has the offset of the open ended field, which is where it is if the open ended field is empty.
+const fn is_trailed(fields: &[FieldInfo], index: usize) -> bool {
+    fields[index].size.is_none()
+        && index + 1 < fields.len()
+        && fields[index + 1].offset == fields[index].offset
+}
+
+/// Internal function, don't use!
+/// Returns the offset of the open ended field of a layout, or `min_size` if the layout doesn't have one.
+/// This backs the `tail_offset` method generated by the define_layout! macro.
+pub const fn tail_offset(min_size: usize, fields: &[FieldInfo]) -> usize {
+    let mut i = 0;
+    while i < fields.len() {
+        if fields[i].size.is_none() {
+            return fields[i].offset;
+        }
+        i += 1;
+    }
+    min_size
+}
+
+/// Internal function, don't use!
+/// Looks up the field with the given name in a field table and returns its offset and size. This backs the `field_info`
+/// function generated by the define_layout! macro and is a `const fn`, so it compares the names byte by byte.
+pub const fn field_info(fields: &[FieldInfo], name: &str) -> Option<(usize, Option<usize>)> {
+    let mut i = 0;
+    while i < fields.len() {
+        if str_eq(fields[i].name, name) {
+            return Some((fields[i].offset, fields[i].size));
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn info(name: &'static str, offset: usize, size: Option<usize>) -> FieldInfo {
+        FieldInfo { name, offset, size }
+    }
+
+    #[test]
+    fn prefix_compatible() {
+        let a = [info("a", 0, Some(2)), info("b", 2, Some(4))];
+        let b = [
+            info("x", 0, Some(2)),
+            info("y", 2, Some(4)),
+            info("z", 6, None),
+        ];
+        assert!(fields_prefix_compatible(&a, &b));
+        assert!(fields_prefix_compatible(&b, &a));
+        assert!(fields_prefix_compatible(&a, &[]));
+    }
+
+    #[test]
+    fn prefix_incompatible() {
+        let a = [info("a", 0, Some(2)), info("b", 2, Some(4))];
+        let b = [info("a", 0, Some(2)), info("b", 2, Some(2))];
+        let c = [info("a", 0, Some(2)), info("b", 2, None)];
+        assert!(!fields_prefix_compatible(&a, &b));
+        assert!(!fields_prefix_compatible(&a, &c));
+        assert!(!fields_prefix_compatible(&c, &a));
+    }
+
+    #[test]
+    fn no_overlap() {
+        check_no_overlap(&[
+            info("a", 0, Some(2)),
+            info("b", 2, Some(0)),
+            info("c", 2, Some(4)),
+            info("d", 6, None),
+        ]);
+        check_no_overlap(&[info("a", 0, None), info("b", 0, Some(0))]);
+        check_no_overlap(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Two fields of the layout overlap")]
+    fn overlap() {
+        check_no_overlap(&[
+            info("a", 0, Some(2)),
+            info("b", 2, Some(4)),
+            info("c", 5, Some(1)),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Two fields of the layout overlap")]
+    fn overlap_with_open_ended_field() {
+        check_no_overlap(&[info("a", 0, None), info("b", 4, Some(1))]);
+    }
+
+    #[test]
+    fn ascending_offsets() {
+        check_ascending_offsets(&[
+            info("a", 0, Some(2)),
+            info("b", 2, Some(0)),
+            info("c", 2, Some(4)),
+            info("d", 8, None),
+            info("e", 8, Some(2)),
+        ]);
+        check_ascending_offsets(&[]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Error: The fields of the layout aren't in ascending order of their offsets"
+    )]
+    fn descending_offsets() {
+        check_ascending_offsets(&[info("a", 4, Some(2)), info("b", 0, Some(4))]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Error: The fields of the layout aren't in ascending order of their offsets"
+    )]
+    fn equal_offsets() {
+        check_ascending_offsets(&[info("a", 0, Some(2)), info("b", 0, Some(2))]);
+    }
+
+    #[test]
+    fn trailer_after_open_ended_field() {
+        check_no_overlap(&[
+            info("a", 0, Some(4)),
+            info("b", 4, None),
+            info("c", 4, Some(2)),
+            info("d", 6, Some(2)),
+        ]);
+        assert_eq!(
+            4,
+            tail_offset(
+                8,
+                &[
+                    info("a", 0, Some(4)),
+                    info("b", 4, None),
+                    info("c", 4, Some(4))
+                ]
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Two fields of the layout overlap")]
+    fn overlapping_trailer_fields() {
+        check_no_overlap(&[
+            info("a", 0, None),
+            info("b", 0, Some(2)),
+            info("c", 1, Some(2)),
+        ]);
+    }
+
+    #[test]
+    fn lookup() {
+        let fields = [info("a", 0, Some(2)), info("ab", 2, None)];
+        assert_eq!(Some((0, Some(2))), field_info(&fields, "a"));
+        assert_eq!(Some((2, None)), field_info(&fields, "ab"));
+        assert_eq!(None, field_info(&fields, "b"));
+        assert_eq!(None, field_info(&fields, ""));
+        assert_eq!(None, field_info(&[], "a"));
+    }
+}
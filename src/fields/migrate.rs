@@ -0,0 +1,143 @@
+use super::info::FieldInfo;
+use super::size::min_size;
+
+/// Implemented by the `View` struct the [define_layout!](crate::define_layout!) macro generates for each layout.
+/// It gives [migrate] access to the field metadata and the storage of a view, and offers helpers that work on the whole storage.
+///
+/// These helpers are trait methods instead of methods of the `View`, so they can't clash with the accessors of a field with the same name.
+/// If a layout has a field named like one of them, e.g. `len`, `view.len()` calls the field accessor and `LayoutView::len(&view)` calls the helper.
+pub trait LayoutView {
+    /// Metadata of the fields in the layout, in the order they were declared. This is the `FIELDS` table of the layout.
+    const FIELDS: &'static [FieldInfo];
+
+    #[doc(hidden)]
+    /// Declaration of each field including the endianness of the layout, used to check that two fields have the same type.
+    const FIELD_DECLARATIONS: &'static [&'static str];
+
+    /// The storage of the view
+    fn bytes(&self) -> &[u8];
+
+    /// Return the offset at which the open ended field of the layout starts, e.g. to slice the storage yourself.
+    /// This is the `OFFSET` of that field and equal to the `MIN_SIZE` of the layout, unless the layout has a `@trailer` section after it.
+    /// For layouts without an open ended field, this is `MIN_SIZE` as well, i.e. the offset at which any data following the record starts.
+    fn tail_offset(&self) -> usize;
+
+    /// Return a pointer to the first byte of the storage, which is the first byte of the first field, e.g. to pass the record to C code.
+    ///
+    /// The pointer is only valid while the view is alive and its storage isn't moved or resized.
+    /// The view must not be modified while the pointer is in use, and C code must not write through it.
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.bytes().as_ptr()
+    }
+
+    /// Number of bytes in the storage
+    #[inline]
+    fn len(&self) -> usize {
+        self.bytes().len()
+    }
+
+    /// Return true if the storage doesn't contain any bytes
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.bytes().is_empty()
+    }
+
+    /// Format the whole storage like `xxd` does, for debugging: 16 bytes per line, each line starting
+    /// with the offset, followed by the bytes in hex and an ASCII gutter. This is only available if the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn hexdump(&self) -> std::string::String {
+        crate::utils::hexdump::hexdump(self.bytes())
+    }
+}
+
+/// Implemented by the `View` struct the [define_layout!](crate::define_layout!) macro generates for each layout
+/// if its storage is writable.
+pub trait LayoutViewMut: LayoutView {
+    /// The storage of the view
+    fn bytes_mut(&mut self) -> &mut [u8];
+
+    /// Return a mutable pointer to the first byte of the storage, which is the first byte of the first field, e.g. to let C code fill in the record.
+    ///
+    /// The pointer is only valid while the view is alive and its storage isn't moved or resized.
+    /// The view must not be accessed while the pointer is in use, and C code must not write more than [len](LayoutView::len) bytes through it.
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.bytes_mut().as_mut_ptr()
+    }
+}
+
+/// Copy each field of `src` into the field of `dst` with the same name, if both fields are declared with the same type
+/// and the layouts have the same endianness. Fields of `dst` that don't have a matching field in `src` keep their value.
+/// This is useful to migrate a record between two versions of a format. Returns the number of fields copied.
+///
+/// Matching fields are copied by their binary representation, independent of their offsets in the two layouts.
+/// Fields that don't have a static size, e.g. open ended byte arrays, aren't copied.
+/// This panics if one of the storages is too small to contain a field that is copied.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::migrate;
+///
+/// define_layout!(v1, LittleEndian, {
+///   id: u32,
+///   value: u16,
+/// });
+///
+/// define_layout!(v2, LittleEndian, {
+///   id: u32,
+///   flags: u8,
+///   value: u16,
+/// });
+///
+/// # fn main() {
+/// let mut src = v1::View::new(vec![0; 6]);
+/// src.id_mut().write(7);
+/// src.value_mut().write(300);
+///
+/// let mut dst = v2::View::new(vec![0; 7]);
+/// assert_eq!(2, migrate(&src, &mut dst));
+/// assert_eq!(7, dst.id().read());
+/// assert_eq!(0, dst.flags().read());
+/// assert_eq!(300, dst.value().read());
+/// # }
+/// ```
+pub fn migrate<Src: LayoutView, Dst: LayoutViewMut>(src: &Src, dst: &mut Dst) -> usize {
+    let mut copied = 0;
+    for (src_index, (src_field, src_declaration)) in
+        Src::FIELDS.iter().zip(Src::FIELD_DECLARATIONS).enumerate()
+    {
+        let size = match src_field.size {
+            Some(size) => size,
+            None => continue,
+        };
+        let dst_index =
+            Dst::FIELDS
+                .iter()
+                .zip(Dst::FIELD_DECLARATIONS)
+                .position(|(field, declaration)| {
+                    field.name == src_field.name && declaration == src_declaration
+                });
+        if let Some(dst_index) = dst_index {
+            let src_offset = storage_offset(Src::FIELDS, src_index, src.bytes().len());
+            let dst_offset = storage_offset(Dst::FIELDS, dst_index, dst.bytes_mut().len());
+            dst.bytes_mut()[dst_offset..(dst_offset + size)]
+                .copy_from_slice(&src.bytes()[src_offset..(src_offset + size)]);
+            copied += 1;
+        }
+    }
+    copied
+}
+
+// Fields in a `@trailer` section, i.e. after an open ended field, are anchored to the end of the storage.
+// Their offset in the field table is their offset if the open ended field is empty.
+fn storage_offset(fields: &[FieldInfo], index: usize, storage_len: usize) -> usize {
+    let offset = fields[index].offset;
+    if fields[..index].iter().any(|field| field.size.is_none()) {
+        offset + storage_len.saturating_sub(min_size(None, fields))
+    } else {
+        offset
+    }
+}
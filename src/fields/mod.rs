@@ -1,6 +1,24 @@
-use super::endianness::Endianness;
+use super::endianness::{EndianKind, Endianness};
 
+pub mod access;
+#[cfg(feature = "std")]
+pub mod chunked;
+pub mod cursor;
+pub mod error;
+#[cfg(feature = "std")]
+pub mod framed;
+pub mod info;
+pub mod migrate;
+pub mod optional;
+#[cfg(feature = "std")]
+pub mod owned;
+pub mod peek;
 pub mod primitive;
+pub mod sentinel;
+pub mod size;
+pub mod tuple;
+pub mod validated;
+pub mod value;
 pub mod wrapped;
 
 ///
@@ -90,6 +108,111 @@ pub trait Field {
     /// assert_eq!(None, my_layout::tail::SIZE);
     /// ```
     const SIZE: Option<usize>;
+
+    /// The maximum size of the field in the layout. This is the same as [SIZE](Field::SIZE) for all fields
+    /// except open ended byte arrays with a declared maximum length like `[u8; ..=64]`, see [BoundedBytes](crate::BoundedBytes).
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   field1: u16,
+    ///   tail: [u8; ..=64],
+    /// });
+    ///
+    /// assert_eq!(Some(2), my_layout::field1::MAX_SIZE);
+    /// assert_eq!(None, my_layout::tail::SIZE);
+    /// assert_eq!(Some(64), my_layout::tail::MAX_SIZE);
+    /// ```
+    const MAX_SIZE: Option<usize> = Self::SIZE;
+
+    /// Whether the field is stored in little endian byte order, i.e. whether its [Endian](Field::Endian) is [LittleEndian](crate::LittleEndian).
+    /// Tools that interpret the raw bytes of a field, e.g. to swap them, can use this without knowing the endianness type.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, BigEndian, {
+    ///   field1: u16,
+    /// });
+    ///
+    /// assert!(!my_layout::field1::IS_LITTLE_ENDIAN);
+    /// ```
+    const IS_LITTLE_ENDIAN: bool = matches!(<Self::Endian as Endianness>::KIND, EndianKind::Little);
+
+    #[doc(hidden)]
+    /// Whether the field is an array with a length of zero, e.g. `[u8; 0]`. The define_layout! macro checks this for each field
+    /// at compile time and rejects such fields, since they don't hold any data.
+    const IS_ZERO_LENGTH_ARRAY: bool = false;
+
+    #[doc(hidden)]
+    /// The range of bytes the field covers in a storage of `storage_len` bytes. This is `OFFSET..(OFFSET + SIZE)`,
+    /// or `OFFSET..storage_len` for open ended fields, except for fields in the trailer of a layout, which are anchored to the end of the storage.
+    /// The range isn't checked against the storage, so it can be out of bounds if the storage is too small.
+    #[inline(always)]
+    fn byte_range(storage_len: usize) -> core::ops::Range<usize> {
+        match Self::SIZE {
+            Some(size) => Self::OFFSET..(Self::OFFSET + size),
+            None => Self::OFFSET..storage_len,
+        }
+    }
+}
+
+/// Return the offset of the field `F` for a record of its layout that starts at `base` within a larger storage.
+/// This is a `const fn`, so it can be used to compute offsets in const context, e.g. to place a layout at a computed
+/// position within a larger const-defined buffer. At runtime, a view created with `View::new_at` offers the same as `absolute_offset::<F>()`.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::absolute_offset;
+///
+/// define_layout!(header, LittleEndian, {
+///   magic: u32,
+///   version: u16,
+/// });
+///
+/// const HEADER_BASE: usize = 16;
+/// const VERSION_OFFSET: usize = absolute_offset::<header::version>(HEADER_BASE);
+/// assert_eq!(20, VERSION_OFFSET);
+/// ```
+#[inline]
+pub const fn absolute_offset<F: Field>(base: usize) -> usize {
+    base + F::OFFSET
+}
+
+/// Check that the field `F` ends within the first `max_size` bytes of a storage, i.e. that `F::OFFSET + F::SIZE <= max_size`.
+/// Open ended fields only need to start within the first `max_size` bytes. This is a `const fn` meant to be evaluated
+/// at compile time in a `const _: () = ...;` item, so field types that are constructed directly instead of with the
+/// [define_layout!](crate::define_layout!) macro can be checked against the size of the buffer they're used with.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::{check_field_bounds, PrimitiveField};
+///
+/// type Checksum = PrimitiveField<u32, LittleEndian, 12>;
+/// const _: () = check_field_bounds::<Checksum>(16);
+///
+/// let mut buffer = [0; 16];
+/// Checksum::write(&mut buffer, 0xDEADBEEF);
+/// assert_eq!(0xDEADBEEF, Checksum::read(&buffer));
+/// ```
+#[inline]
+pub const fn check_field_bounds<F: Field>(max_size: usize) {
+    let end = match F::SIZE {
+        Some(size) => match F::OFFSET.checked_add(size) {
+            Some(end) => end,
+            None => panic!("Error: The end of the field overflows usize"),
+        },
+        None => F::OFFSET,
+    };
+    assert!(
+        end <= max_size,
+        "Error: The field exceeds the declared maximum size"
+    );
 }
 
 #[doc(hidden)]
@@ -106,3 +229,105 @@ pub trait StorageToFieldView<S> {
     type View;
     fn view(storage: S) -> Self::View;
 }
+
+/// Internal function, don't use!
+/// Returns true if the field has the same bytes in both storages.
+/// If a storage is too small to contain the field, it only compares equal to another storage that is too small as well.
+#[inline]
+pub fn field_bytes_eq<F: Field>(lhs: &[u8], rhs: &[u8]) -> bool {
+    fn region<F: Field>(storage: &[u8]) -> Option<&[u8]> {
+        storage.get(F::byte_range(storage.len()))
+    }
+    region::<F>(lhs) == region::<F>(rhs)
+}
+
+/// Internal function, don't use!
+/// Returns the region of the storage spanning from the start of field `Start` to the end of field `End`.
+/// If `End` is an open ended field, the region extends to the end of the storage.
+#[inline]
+pub fn field_region<Start: Field, End: Field>(storage: &[u8]) -> &[u8] {
+    let len = storage.len();
+    &storage[Start::byte_range(len).start..End::byte_range(len).end]
+}
+
+/// Internal function, don't use!
+/// Checks that `Start` and `End` are fields of a layout, identified by their offset and size in its `FIELDS` table,
+/// and that `Start` isn't declared after `End`. This is evaluated at compile time by `View::field_region` generated by the define_layout! macro.
+pub const fn check_field_region<Start: Field, End: Field>(fields: &[info::FieldInfo]) {
+    let start = match field_index(fields, Start::OFFSET, Start::SIZE, false) {
+        Some(index) => index,
+        None => panic!("Error: The `Start` field of `field_region` isn't a field of this layout"),
+    };
+    let end = match field_index(fields, End::OFFSET, End::SIZE, true) {
+        Some(index) => index,
+        None => panic!("Error: The `End` field of `field_region` isn't a field of this layout"),
+    };
+    assert!(
+        start <= end,
+        "Error: The `Start` field of `field_region` must not come after its `End` field"
+    );
+}
+
+// Returns the index of the first field (or the last one if `last` is true) with the given offset and size.
+// Zero-size fields can share their offset with the next field, so they're only told apart by their order.
+const fn field_index(
+    fields: &[info::FieldInfo],
+    offset: usize,
+    size: Option<usize>,
+    last: bool,
+) -> Option<usize> {
+    let mut result = None;
+    let mut i = 0;
+    while i < fields.len() {
+        let same_size = match (fields[i].size, size) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            (None, None) => true,
+            _ => false,
+        };
+        if fields[i].offset == offset && same_size {
+            result = Some(i);
+            if !last {
+                return result;
+            }
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Internal function, don't use!
+/// Copies a record of the given layout size from `src` to `dst` with a single `copy_from_slice`.
+/// If the layout is open ended, the whole `src` storage is copied.
+#[inline]
+pub fn copy_record(size: Option<usize>, dst: &mut [u8], src: &[u8]) {
+    let size = size.unwrap_or(src.len());
+    dst[..size].copy_from_slice(&src[..size]);
+}
+
+/// Internal function, don't use!
+/// Returns true if the storage has the length of the template and all bits set in `mask` are equal in both.
+#[inline]
+pub fn matches_template<const N: usize>(
+    storage: &[u8],
+    template: &[u8; N],
+    mask: &[u8; N],
+) -> bool {
+    storage.len() == N
+        && storage
+            .iter()
+            .zip(template)
+            .zip(mask)
+            .all(|((byte, expected), mask)| (byte ^ expected) & mask == 0)
+}
+
+/// Internal function, don't use!
+/// Splits a storage into consecutive records of the given layout size, ignoring a trailing partial record.
+/// The define_layout! macro only calls this for layouts with a fixed size. This panics if the layout has a size of zero.
+#[inline]
+pub fn records(size: usize, storage: &[u8]) -> core::slice::ChunksExact<'_, u8> {
+    assert!(
+        size > 0,
+        "Error: Layouts with a size of zero can't be split into records"
+    );
+    storage.chunks_exact(size)
+}
@@ -0,0 +1,86 @@
+// Optional fields are generated by the `optional(...)` variant of the define_layout! macro.
+// Their offsets aren't known at compile time because they depend on which of the
+// earlier optional fields are present, so the code in this module computes them at runtime
+// based on the presence bitmap stored at the beginning of the layout.
+
+/// Internal trait. Don't use this in user code.
+/// Implemented for the unsigned integer types that can be used as a presence bitmap.
+pub trait PresenceBitmap: Copy {
+    /// Number of bits in the bitmap, i.e. the maximum number of optional fields
+    const BITS: u32;
+
+    /// Returns true if the given bit is set in the bitmap
+    fn is_set(self, bit: usize) -> bool;
+}
+
+macro_rules! presence_bitmap {
+    ($type: ty) => {
+        impl PresenceBitmap for $type {
+            const BITS: u32 = <$type>::BITS;
+
+            #[inline(always)]
+            fn is_set(self, bit: usize) -> bool {
+                bit < Self::BITS as usize && (self >> bit) & 1 == 1
+            }
+        }
+    };
+}
+
+presence_bitmap!(u8);
+presence_bitmap!(u16);
+presence_bitmap!(u32);
+presence_bitmap!(u64);
+presence_bitmap!(u128);
+
+/// Internal function, don't use!
+/// Checks that the presence bitmap has a bit for each optional field. This is evaluated at compile time by the `optional(...)` variant
+/// of the define_layout! macro, so a layout with more optional fields than bits in its presence bitmap is a compile error.
+pub const fn check_optional_field_count(field_count: usize, bitmap_bits: u32) {
+    assert!(
+        field_count <= bitmap_bits as usize,
+        "Error: The layout has more optional fields than its presence bitmap has bits. Use a larger unsigned integer type for the bitmap."
+    );
+}
+
+/// Internal function, don't use!
+/// Computes the offset of the optional field with the given index, assuming that the optional fields
+/// start at `base` and that only the fields whose bit is set in `presence` take up space.
+/// Calling this with `index == sizes.len()` returns the total size of the layout.
+#[inline]
+pub fn optional_field_offset<B: PresenceBitmap>(
+    presence: B,
+    base: usize,
+    sizes: &[usize],
+    index: usize,
+) -> usize {
+    sizes[..index]
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| presence.is_set(*bit))
+        .fold(base, |offset, (_, size)| offset + size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits_are_numbered_from_the_least_significant_bit() {
+        assert!(0b0000_0001u8.is_set(0));
+        assert!(!0b0000_0001u8.is_set(1));
+        assert!(0b1000_0000u8.is_set(7));
+        assert!(!0b1111_1111u8.is_set(8));
+        assert!(0x8000u16.is_set(15));
+    }
+
+    #[test]
+    fn offsets_skip_absent_fields() {
+        let sizes = [2, 4, 8];
+        assert_eq!(1, optional_field_offset(0b000u8, 1, &sizes, 0));
+        assert_eq!(1, optional_field_offset(0b000u8, 1, &sizes, 2));
+        assert_eq!(3, optional_field_offset(0b001u8, 1, &sizes, 2));
+        assert_eq!(5, optional_field_offset(0b010u8, 1, &sizes, 2));
+        assert_eq!(7, optional_field_offset(0b011u8, 1, &sizes, 2));
+        assert_eq!(15, optional_field_offset(0b111u8, 1, &sizes, 3));
+    }
+}
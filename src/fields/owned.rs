@@ -0,0 +1,1015 @@
+#[cfg(feature = "bitflags")]
+use super::primitive::FlagsAs;
+use super::primitive::{
+    AlignTo, BitFields, BitFlags, BitOrder, BoundedBytes, ExcessK, FieldCopyAccess,
+    FieldSliceAccess, Fixed, FromEnd, Included, InvalidChar, MixedEndian, NanBoxed, NanBoxing,
+    NestedViewInfo, OnesComplement, PrimitiveField, RepeatedLayout, Scaled, SignMagnitude, Trailed,
+};
+#[cfg(feature = "time")]
+use super::primitive::{DurationAs, TimeUnit};
+use super::sentinel::{SentinelField, SentinelValue};
+use super::validated::{FieldValidator, ValidatedField};
+use super::wrapped::{LayoutAs, WrappedField};
+use super::{Field, StorageToFieldView};
+use crate::endianness::Endianness;
+use crate::macro_define_layout::unwrap_field_size;
+use core::convert::TryInto;
+use core::fmt;
+
+// The define_layout! macro generates an `Owned` struct for each layout that holds a decoded copy of all of its fields.
+// The types of the struct members are defined by the [FieldToOwned] implementations in this module, and
+// [FieldFromOwned] defines how they're written back into a storage. Nested layouts implement [OwnedNestedView]
+// and [OwnedNestedViewMut] for their `NestedView` marker type so that they can be decoded into and written from
+// the `Owned` struct of the nested layout.
+
+/// Internal trait. Don't use this in user code.
+/// Implemented for fields that can be decoded into an owned value.
+pub trait FieldToOwned: Field {
+    /// The type the field is decoded into
+    type Owned;
+
+    /// Decode the field from the given storage into an owned value
+    fn read_owned(storage: &[u8]) -> Self::Owned;
+}
+
+/// Internal trait. Don't use this in user code.
+/// Implemented for fields that can be written from a borrowed owned value.
+pub trait FieldFromOwned: FieldToOwned {
+    /// Number of bytes the field takes up in the storage when written from the given value
+    fn owned_size(v: &Self::Owned) -> usize;
+
+    /// Write the field from the given owned value into the storage
+    fn write_owned(storage: &mut [u8], v: &Self::Owned);
+}
+
+/// Internal trait. Don't use this in user code.
+/// Implemented by the `NestedView` marker type generated by the define_layout! macro.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a supported field type for `define_layout!`",
+    label = "unsupported field type",
+    note = "supported field types are integers, floats, `char`, `()`, byte arrays like `[u8; N]` or `[u8]`, `[bool; N]`, the other field types of this crate and the `NestedView` of another layout",
+    note = "to use a custom type, wrap a supported type with `{Self} as <supported type>` and implement `LayoutAs` for it"
+)]
+pub trait OwnedNestedView {
+    /// The `Owned` struct of the nested layout
+    type Owned;
+
+    /// Takes a storage pointing only to the space of the nested field and decodes it into the `Owned` struct of the nested layout.
+    fn read_owned(storage: &[u8]) -> Self::Owned;
+}
+
+/// Internal trait. Don't use this in user code.
+/// Implemented by the `NestedView` marker type generated by the define_layout! macro if all fields of the nested layout implement [FieldFromOwned].
+pub trait OwnedNestedViewMut: OwnedNestedView {
+    /// Number of bytes the nested layout takes up in the storage when written from the given value
+    fn owned_size(v: &Self::Owned) -> usize;
+
+    /// Takes a storage pointing only to the space of the nested field and writes the `Owned` struct of the nested layout into it.
+    fn write_owned(storage: &mut [u8], v: &Self::Owned);
+}
+
+macro_rules! copy_field_to_owned {
+    ($type: ty) => {
+        copy_field_to_owned!($type, $type);
+    };
+    ($type: ty, $owned: ty) => {
+        impl<E: Endianness, const OFFSET_: usize> FieldToOwned
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            type Owned = $owned;
+
+            #[inline(always)]
+            fn read_owned(storage: &[u8]) -> Self::Owned {
+                Self::read(storage)
+            }
+        }
+
+        impl<E: Endianness, const OFFSET_: usize> FieldFromOwned
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            #[inline(always)]
+            fn owned_size(_v: &Self::Owned) -> usize {
+                core::mem::size_of::<$owned>()
+            }
+
+            #[inline(always)]
+            fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+                Self::write(storage, *v)
+            }
+        }
+    };
+}
+
+copy_field_to_owned!(i8);
+copy_field_to_owned!(i16);
+copy_field_to_owned!(i32);
+copy_field_to_owned!(i64);
+copy_field_to_owned!(i128);
+copy_field_to_owned!(u8);
+copy_field_to_owned!(u16);
+copy_field_to_owned!(u32);
+copy_field_to_owned!(u64);
+copy_field_to_owned!(u128);
+copy_field_to_owned!(f32);
+copy_field_to_owned!(f64);
+copy_field_to_owned!(SignMagnitude<u8>, i8);
+copy_field_to_owned!(SignMagnitude<u16>, i16);
+copy_field_to_owned!(SignMagnitude<u32>, i32);
+copy_field_to_owned!(SignMagnitude<u64>, i64);
+copy_field_to_owned!(OnesComplement<u8>, i8);
+copy_field_to_owned!(OnesComplement<u16>, i16);
+copy_field_to_owned!(OnesComplement<u32>, i32);
+copy_field_to_owned!(OnesComplement<u64>, i64);
+copy_field_to_owned!(MixedEndian<u32>, u32);
+copy_field_to_owned!(MixedEndian<i32>, i32);
+copy_field_to_owned!(());
+
+/// Bit fields are decoded into their whole integer.
+impl<U, O: BitOrder, E: Endianness, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<BitFields<U, O>, E, OFFSET_>
+where
+    Self: FieldCopyAccess<HighLevelType = U>,
+{
+    type Owned = U;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+impl<U: Copy, O: BitOrder, E: Endianness, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<BitFields<U, O>, E, OFFSET_>
+where
+    Self: FieldCopyAccess<HighLevelType = U>,
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        core::mem::size_of::<U>()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, *v)
+    }
+}
+
+/// Field type `char` is decoded into the result of reading it, so that invalid code points survive a round trip.
+impl<E: Endianness, const OFFSET_: usize> FieldToOwned for PrimitiveField<char, E, OFFSET_> {
+    type Owned = Result<char, InvalidChar>;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read_code_point(storage)
+    }
+}
+
+impl<E: Endianness, const OFFSET_: usize> FieldFromOwned for PrimitiveField<char, E, OFFSET_> {
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        core::mem::size_of::<u32>()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        match *v {
+            Ok(value) => Self::write(storage, value),
+            Err(InvalidChar(code_point)) => {
+                PrimitiveField::<u32, E, OFFSET_>::write(storage, code_point)
+            }
+        }
+    }
+}
+
+/// Padding fields don't hold any data, they're decoded into `()`.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    type Owned = ();
+
+    #[inline(always)]
+    fn read_owned(_storage: &[u8]) -> Self::Owned {}
+}
+
+/// Writing padding fields zeroes the padding bytes.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Padding fields always have a static size
+        Self::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], _v: &Self::Owned) {
+        Self::data_mut(storage).fill(0);
+    }
+}
+
+/// NaN-boxed fields are decoded into the tagged value of their [NanBoxing] scheme.
+impl<C: NanBoxing, E: Endianness, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+{
+    type Owned = C::Value;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+impl<C: NanBoxing, E: Endianness, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+where
+    C::Value: Clone,
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        core::mem::size_of::<u64>()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, v.clone())
+    }
+}
+
+/// Excess-K fields are decoded into the signed integer type they're read as.
+impl<U, E: Endianness, const K: i64, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<ExcessK<U, K>, E, OFFSET_>
+where
+    Self: FieldCopyAccess,
+    <Self as FieldCopyAccess>::HighLevelType: Copy,
+{
+    type Owned = <Self as FieldCopyAccess>::HighLevelType;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+impl<U, E: Endianness, const K: i64, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<ExcessK<U, K>, E, OFFSET_>
+where
+    Self: FieldCopyAccess,
+    <Self as FieldCopyAccess>::HighLevelType: Copy,
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Excess-K fields always have a static size
+        Self::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, *v)
+    }
+}
+
+/// Scaled fields are decoded into an [f64].
+impl<U, E: Endianness, const NUM: i64, const DEN: i64, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<Scaled<U, NUM, DEN>, E, OFFSET_>
+where
+    Self: FieldCopyAccess<HighLevelType = f64>,
+{
+    type Owned = f64;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+impl<U, E: Endianness, const NUM: i64, const DEN: i64, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<Scaled<U, NUM, DEN>, E, OFFSET_>
+where
+    Self: FieldCopyAccess<HighLevelType = f64>,
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Scaled fields always have a static size
+        Self::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, *v)
+    }
+}
+
+/// Fixed-point fields are decoded into an [f64].
+impl<E: Endianness, const I: usize, const F: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    type Owned = f64;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+impl<E: Endianness, const I: usize, const F: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Fixed-point fields always have a static size
+        Self::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, *v)
+    }
+}
+
+/// Flags fields are decoded into the flags type, with unknown bits masked out.
+#[cfg(feature = "bitflags")]
+impl<T: bitflags::Flags, E: Endianness, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+where
+    PrimitiveField<T::Bits, E, OFFSET_>: FieldCopyAccess<HighLevelType = T::Bits>,
+{
+    type Owned = T;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+#[cfg(feature = "bitflags")]
+impl<T: bitflags::Flags, E: Endianness, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+where
+    PrimitiveField<T::Bits, E, OFFSET_>: FieldCopyAccess<HighLevelType = T::Bits>,
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        core::mem::size_of::<T::Bits>()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, T::from_bits_retain(v.bits()))
+    }
+}
+
+/// Duration fields are decoded into a [Duration](core::time::Duration).
+#[cfg(feature = "time")]
+impl<U: TimeUnit, E: Endianness, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    type Owned = core::time::Duration;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+#[cfg(feature = "time")]
+impl<U: TimeUnit, E: Endianness, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        core::mem::size_of::<u64>()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, *v)
+    }
+}
+
+/// Wrapped fields are decoded into their high level type.
+impl<U, T: LayoutAs<U>, F: FieldCopyAccess<HighLevelType = U>> FieldToOwned
+    for WrappedField<U, T, F>
+{
+    type Owned = T;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+/// Wrapped fields can only be written from a borrowed value if their high level type can be cloned.
+impl<U, T: LayoutAs<U> + Clone, F: FieldCopyAccess<HighLevelType = U>> FieldFromOwned
+    for WrappedField<U, T, F>
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Wrapped fields always wrap a primitive field with a static size
+        F::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, v.clone())
+    }
+}
+
+/// Sentinel fields are decoded into an [Option] that is `None` if the field holds the sentinel value.
+impl<V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> FieldToOwned for SentinelField<V, F>
+where
+    F::HighLevelType: PartialEq,
+{
+    type Owned = Option<F::HighLevelType>;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+impl<V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> FieldFromOwned for SentinelField<V, F>
+where
+    F::HighLevelType: PartialEq + Clone,
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Sentinel fields always wrap a primitive field with a static size
+        F::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, v.clone())
+    }
+}
+
+/// Validated fields are decoded like the wrapped field, checking the invariant in debug builds.
+impl<V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess> FieldToOwned
+    for ValidatedField<V, F>
+{
+    type Owned = F::HighLevelType;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::read(storage)
+    }
+}
+
+impl<V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess> FieldFromOwned
+    for ValidatedField<V, F>
+where
+    F::HighLevelType: Clone,
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Validated fields always wrap a primitive field with a static size
+        F::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::write(storage, v.clone())
+    }
+}
+
+/// Boolean arrays are decoded into a copy of the flags.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<[bool; N], E, OFFSET_>
+{
+    type Owned = [bool; N];
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        core::array::from_fn(|index| Self::get(storage, index))
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<[bool; N], E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        for (index, value) in v.iter().enumerate() {
+            Self::set(storage, index, *value);
+        }
+    }
+}
+
+/// Bit packed flags are decoded into an array of booleans.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<BitFlags<N>, E, OFFSET_>
+{
+    type Owned = [bool; N];
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        core::array::from_fn(|index| Self::get(storage, index))
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<BitFlags<N>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        // Bit flags always have a static size
+        Self::SIZE.unwrap()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        for (index, value) in v.iter().enumerate() {
+            Self::set(storage, index, *value);
+        }
+    }
+}
+
+/// Field type `[u8; N]` is decoded into a copy of the array.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<[u8; N], E, OFFSET_>
+{
+    type Owned = [u8; N];
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        *Self::data(storage)
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<[u8; N], E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(_v: &Self::Owned) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        *Self::data_mut(storage) = *v;
+    }
+}
+
+/// Field type `[u8]` is decoded into a [Vec] holding a copy of the data.
+impl<E: Endianness, const OFFSET_: usize> FieldToOwned for PrimitiveField<[u8], E, OFFSET_> {
+    type Owned = Vec<u8>;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::data(storage).to_vec()
+    }
+}
+
+/// Writing field type `[u8]` panics if the remaining storage doesn't have exactly the length of the given [Vec].
+impl<E: Endianness, const OFFSET_: usize> FieldFromOwned for PrimitiveField<[u8], E, OFFSET_> {
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        v.len()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::data_mut(storage).copy_from_slice(v);
+    }
+}
+
+/// Field type `[u8; ..=MAX]` is decoded into a [Vec] holding a copy of the data, like `[u8]`.
+impl<E: Endianness, const MAX: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    type Owned = Vec<u8>;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        Self::data(storage).to_vec()
+    }
+}
+
+/// Writing field type `[u8; ..=MAX]` panics if the remaining storage doesn't have exactly the length of the given [Vec].
+impl<E: Endianness, const MAX: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        v.len()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        Self::data_mut(storage).copy_from_slice(v);
+    }
+}
+
+macro_rules! elements_field_to_owned {
+    ($type: ty) => {
+        /// Field type `[T]` for an integer or float type `T` is decoded into a [Vec] holding a copy of the elements.
+        impl<E: Endianness, const OFFSET_: usize> FieldToOwned
+            for PrimitiveField<[$type], E, OFFSET_>
+        {
+            type Owned = Vec<$type>;
+
+            #[inline(always)]
+            fn read_owned(storage: &[u8]) -> Self::Owned {
+                <Self as StorageToFieldView<&[u8]>>::view(storage)
+                    .iter()
+                    .collect()
+            }
+        }
+
+        /// Writing field type `[T]` panics if the remaining storage doesn't hold exactly the number of elements of the given [Vec].
+        impl<E: Endianness, const OFFSET_: usize> FieldFromOwned
+            for PrimitiveField<[$type], E, OFFSET_>
+        {
+            #[inline(always)]
+            fn owned_size(v: &Self::Owned) -> usize {
+                v.len() * core::mem::size_of::<$type>()
+            }
+
+            #[inline(always)]
+            fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+                let mut view = <Self as StorageToFieldView<&mut [u8]>>::view(storage);
+                assert_eq!(
+                    v.len(),
+                    view.len(),
+                    "Error: The storage holds {} elements but the value has {}",
+                    view.len(),
+                    v.len()
+                );
+                for (index, value) in v.iter().enumerate() {
+                    view.set(index, *value);
+                }
+            }
+        }
+    };
+}
+
+elements_field_to_owned!(i8);
+elements_field_to_owned!(i16);
+elements_field_to_owned!(i32);
+elements_field_to_owned!(i64);
+elements_field_to_owned!(i128);
+elements_field_to_owned!(u16);
+elements_field_to_owned!(u32);
+elements_field_to_owned!(u64);
+elements_field_to_owned!(u128);
+elements_field_to_owned!(f32);
+elements_field_to_owned!(f64);
+
+/// Nested layouts are decoded into the `Owned` struct of the nested layout.
+impl<N: OwnedNestedView, E: Endianness, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<N, E, OFFSET_>
+where
+    Self: Field,
+{
+    type Owned = N::Owned;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        if let Some(size) = Self::SIZE {
+            N::read_owned(&storage[Self::OFFSET..(Self::OFFSET + size)])
+        } else {
+            N::read_owned(&storage[Self::OFFSET..])
+        }
+    }
+}
+
+impl<N: OwnedNestedViewMut, E: Endianness, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<N, E, OFFSET_>
+where
+    Self: Field,
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        N::owned_size(v)
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        if let Some(size) = Self::SIZE {
+            N::write_owned(&mut storage[Self::OFFSET..(Self::OFFSET + size)], v)
+        } else {
+            N::write_owned(&mut storage[Self::OFFSET..], v)
+        }
+    }
+}
+
+/// Repetitions of a nested layout are decoded into an array of the `Owned` structs of the nested layout.
+impl<
+        N: OwnedNestedView + NestedViewInfo,
+        E: Endianness,
+        const COUNT: usize,
+        const OFFSET_: usize,
+    > FieldToOwned for PrimitiveField<[N; COUNT], E, OFFSET_>
+{
+    type Owned = [N::Owned; COUNT];
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        let size = unwrap_field_size(N::SIZE);
+        core::array::from_fn(|index| {
+            let start = Self::OFFSET + index * size;
+            N::read_owned(&storage[start..(start + size)])
+        })
+    }
+}
+
+impl<
+        N: OwnedNestedViewMut + NestedViewInfo,
+        E: Endianness,
+        const COUNT: usize,
+        const OFFSET_: usize,
+    > FieldFromOwned for PrimitiveField<[N; COUNT], E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        v.iter().map(N::owned_size).sum()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        let size = unwrap_field_size(N::SIZE);
+        for (index, value) in v.iter().enumerate() {
+            let start = Self::OFFSET + index * size;
+            N::write_owned(&mut storage[start..(start + size)], value);
+        }
+    }
+}
+
+/// Repetitions of a nested layout until the end of the storage are decoded into a [Vec] of the `Owned` structs of the nested layout.
+impl<N: OwnedNestedView + NestedViewInfo, E: Endianness, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<[N], E, OFFSET_>
+{
+    type Owned = Vec<N::Owned>;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        let (_, region) = Self::region(storage.len());
+        storage[region]
+            .chunks_exact(unwrap_field_size(N::SIZE))
+            .map(N::read_owned)
+            .collect()
+    }
+}
+
+/// This panics if the storage after the offset of the field doesn't have exactly the size of the given repetitions.
+impl<N: OwnedNestedViewMut + NestedViewInfo, E: Endianness, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<[N], E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        v.iter().map(N::owned_size).sum()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        let size = unwrap_field_size(N::SIZE);
+        let storage = &mut storage[Self::OFFSET..];
+        assert_eq!(
+            v.len() * size,
+            storage.len(),
+            "Error: The storage doesn't have the size of the given repetitions"
+        );
+        for (chunk, value) in storage.chunks_exact_mut(size).zip(v) {
+            N::write_owned(chunk, value);
+        }
+    }
+}
+
+/// Field type [RepeatedLayout] is decoded into a [Vec] of the `Owned` structs of the nested layout.
+impl<
+        N: OwnedNestedView + NestedViewInfo,
+        C: FieldCopyAccess,
+        E: Endianness,
+        const OFFSET_: usize,
+    > FieldToOwned for PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+where
+    C::HighLevelType: TryInto<usize>,
+{
+    type Owned = Vec<N::Owned>;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        let (_, region) = Self::region(storage);
+        storage[region]
+            .chunks_exact(unwrap_field_size(N::SIZE))
+            .map(N::read_owned)
+            .collect()
+    }
+}
+
+/// Writing field type [RepeatedLayout] doesn't update the count field, it only writes the given repetitions.
+/// This panics if the storage is too small to hold them.
+impl<
+        N: OwnedNestedViewMut + NestedViewInfo,
+        C: FieldCopyAccess,
+        E: Endianness,
+        const OFFSET_: usize,
+    > FieldFromOwned for PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+where
+    C::HighLevelType: TryInto<usize>,
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        v.iter().map(N::owned_size).sum()
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        let size = unwrap_field_size(N::SIZE);
+        let storage = &mut storage[Self::OFFSET..(Self::OFFSET + v.len() * size)];
+        for (chunk, value) in storage.chunks_exact_mut(size).zip(v) {
+            N::write_owned(chunk, value);
+        }
+    }
+}
+
+/// Fields taken over from an included layout are decoded like the field in the included layout.
+impl<F: FieldToOwned, E: Endianness, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    type Owned = F::Owned;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        F::read_owned(storage)
+    }
+}
+
+impl<F: FieldFromOwned, E: Endianness, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        F::owned_size(v)
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        F::write_owned(storage, v)
+    }
+}
+
+/// The open ended field before the trailer of a layout is decoded like the open ended field, without the trailer bytes.
+impl<T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: FieldToOwned,
+{
+    type Owned = <PrimitiveField<T, E, OFFSET_> as FieldToOwned>::Owned;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        PrimitiveField::<T, E, OFFSET_>::read_owned(&storage[..Self::byte_range(storage.len()).end])
+    }
+}
+
+impl<T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: FieldFromOwned,
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        PrimitiveField::<T, E, OFFSET_>::owned_size(v)
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        let end = Self::byte_range(storage.len()).end;
+        PrimitiveField::<T, E, OFFSET_>::write_owned(&mut storage[..end], v)
+    }
+}
+
+/// Fields in the trailer of a layout are decoded like the field they wrap.
+impl<T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize> FieldToOwned
+    for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: FieldToOwned,
+{
+    type Owned = <PrimitiveField<T, E, 0> as FieldToOwned>::Owned;
+
+    #[inline(always)]
+    fn read_owned(storage: &[u8]) -> Self::Owned {
+        PrimitiveField::<T, E, 0>::read_owned(&storage[Self::byte_range(storage.len()).start..])
+    }
+}
+
+impl<T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize> FieldFromOwned
+    for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: FieldFromOwned,
+{
+    #[inline(always)]
+    fn owned_size(v: &Self::Owned) -> usize {
+        PrimitiveField::<T, E, 0>::owned_size(v)
+    }
+
+    #[inline(always)]
+    fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+        let start = Self::byte_range(storage.len()).start;
+        PrimitiveField::<T, E, 0>::write_owned(&mut storage[start..], v)
+    }
+}
+
+/// This error is returned by the `build` method of the `Builder` generated by the [define_layout!](crate::define_layout!) macro
+/// if some fields of the layout were neither set nor explicitly defaulted.
+///
+/// It converts into [LayoutError::MissingField](crate::LayoutError::MissingField), so it can be propagated with the `?` operator.
+/// Since [LayoutError](crate::LayoutError) is `Copy`, the converted error only keeps the name of the first missing field.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::MissingFields;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+///   field3: u8,
+/// });
+///
+/// # fn main() {
+/// let result = my_layout::Builder::new().set_field2(5).build();
+/// assert_eq!(Some(MissingFields { fields: vec!["field1", "field3"] }), result.err());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFields {
+    /// Names of the fields that weren't set, in declaration order
+    pub fields: Vec<&'static str>,
+}
+
+impl fmt::Display for MissingFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Missing required fields: {}", self.fields.join(", "))
+    }
+}
+
+impl std::error::Error for MissingFields {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_copy_access() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u16, LittleEndian, 5>;
+        type Field2 = PrimitiveField<i32, BigEndian, 7>;
+
+        Field1::write_owned(&mut storage, &1000);
+        Field2::write_owned(&mut storage, &-50_000);
+
+        assert_eq!(1000, Field1::read(&storage));
+        assert_eq!(-50_000, Field2::read(&storage));
+        assert_eq!(1000, Field1::read_owned(&storage));
+        assert_eq!(-50_000, Field2::read_owned(&storage));
+        assert_eq!(2, Field1::owned_size(&1000));
+        assert_eq!(4, Field2::owned_size(&-50_000));
+    }
+
+    #[test]
+    fn test_array() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<[u8; 2], LittleEndian, 5>;
+
+        Field1::write_owned(&mut storage, &[10, 20]);
+
+        assert_eq!(&[10, 20], Field1::data(&storage));
+        let owned: [u8; 2] = Field1::read_owned(&storage);
+        assert_eq!([10, 20], owned);
+        assert_eq!(2, Field1::owned_size(&owned));
+    }
+
+    #[test]
+    fn test_slice() {
+        let mut storage = vec![0; 10];
+
+        type Field1 = PrimitiveField<[u8], LittleEndian, 5>;
+
+        Field1::write_owned(&mut storage, &vec![10, 20, 30, 40, 50]);
+
+        assert_eq!(&[10, 20, 30, 40, 50], Field1::data(&storage));
+        let owned: Vec<u8> = Field1::read_owned(&storage);
+        assert_eq!(vec![10, 20, 30, 40, 50], owned);
+        assert_eq!(5, Field1::owned_size(&owned));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_with_wrong_length() {
+        let mut storage = vec![0; 10];
+
+        type Field1 = PrimitiveField<[u8], LittleEndian, 5>;
+
+        Field1::write_owned(&mut storage, &vec![10, 20, 30]);
+    }
+}
@@ -0,0 +1,146 @@
+use core::fmt;
+
+use super::primitive::{FieldCopyAccess, PrimitiveField};
+use super::Field;
+use crate::endianness::Endianness;
+use crate::LayoutError;
+
+/// Describes a storage that is too small to contain the accessed field. Fallible accessors like the `peek_${field_name}` functions
+/// report it as [LayoutError::BufferTooSmall], which this converts into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// Number of bytes the storage would need to have to contain the field
+    pub required: usize,
+    /// Number of bytes the storage actually has
+    pub actual: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Storage too small: required {} bytes but only got {}",
+            self.required, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+/// Internal function, don't use!
+/// Bounds-checks the storage and then reads the field from it. This backs the `peek_${field_name}` functions generated by the define_layout! macro.
+#[inline]
+pub fn peek<F: FieldCopyAccess>(storage: &[u8]) -> Result<F::HighLevelType, LayoutError> {
+    // Fields with copy access always have a static size
+    let required = F::OFFSET + F::SIZE.unwrap_or(0);
+    if storage.len() < required {
+        return Err(BufferTooSmall {
+            required,
+            actual: storage.len(),
+        }
+        .into());
+    }
+    Ok(F::read(storage))
+}
+
+impl<T: ?Sized, E: Endianness, const OFFSET_: usize> PrimitiveField<T, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: FieldCopyAccess,
+{
+    /// Read a field of this type at position `pos` of the storage instead of at its offset in the layout,
+    /// and advance `pos` by the size of the field. This is meant for manual streaming parsers that read
+    /// fields one after the other without a [View](crate::example::icmp_packet::View).
+    /// Returns an error and leaves `pos` unchanged if the storage is too small to contain the field at that position.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, BigEndian, {
+    ///   kind: u8,
+    ///   length: u16,
+    /// });
+    ///
+    /// # fn main() {
+    /// let storage = [1, 0, 5, 2, 0, 6];
+    /// let mut pos = 0;
+    /// let mut entries = Vec::new();
+    /// while pos < storage.len() {
+    ///   let kind = my_layout::kind::read_advancing(&storage, &mut pos).unwrap();
+    ///   let length = my_layout::length::read_advancing(&storage, &mut pos).unwrap();
+    ///   entries.push((kind, length));
+    /// }
+    /// assert_eq!(vec![(1, 5), (2, 6)], entries);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn read_advancing(
+        storage: &[u8],
+        pos: &mut usize,
+    ) -> Result<<PrimitiveField<T, E, 0> as FieldCopyAccess>::HighLevelType, LayoutError> {
+        // Fields with copy access always have a static size
+        let end = pos.saturating_add(<PrimitiveField<T, E, 0>>::SIZE.unwrap_or(0));
+        let field = storage.get(*pos..end).ok_or(BufferTooSmall {
+            required: end,
+            actual: storage.len(),
+        })?;
+        *pos = end;
+        Ok(<PrimitiveField<T, E, 0>>::read(field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::PrimitiveField;
+
+    #[test]
+    fn test_peek() {
+        type Field1 = PrimitiveField<u16, BigEndian, 2>;
+
+        assert_eq!(Ok(0x0506), peek::<Field1>(&[1, 2, 5, 6]));
+        assert_eq!(Ok(0x0506), peek::<Field1>(&[1, 2, 5, 6, 7]));
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 4,
+                actual: 3
+            }),
+            peek::<Field1>(&[1, 2, 5])
+        );
+    }
+
+    #[test]
+    fn test_read_advancing() {
+        type Field1 = PrimitiveField<u16, BigEndian, 2>;
+        type Field2 = PrimitiveField<i8, LittleEndian, 4>;
+
+        let storage = [5, 6, 0xFF, 1, 2];
+        let mut pos = 0;
+        assert_eq!(Ok(0x0506), Field1::read_advancing(&storage, &mut pos));
+        assert_eq!(2, pos);
+        assert_eq!(Ok(-1), Field2::read_advancing(&storage, &mut pos));
+        assert_eq!(3, pos);
+        assert_eq!(Ok(0x0102), Field1::read_advancing(&storage, &mut pos));
+        assert_eq!(5, pos);
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 6,
+                actual: 5
+            }),
+            Field2::read_advancing(&storage, &mut pos)
+        );
+        assert_eq!(5, pos);
+
+        let mut pos = 4;
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 6,
+                actual: 5
+            }),
+            Field1::read_advancing(&storage, &mut pos)
+        );
+        assert_eq!(4, pos);
+    }
+}
@@ -0,0 +1,131 @@
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::{FieldSliceAccess, PrimitiveField};
+use crate::endianness::Endianness;
+use crate::utils::data::Data;
+
+/// Use this as a field type to insert the minimal padding needed to bring the offset of the next field
+/// to a multiple of `N`. This is useful to match the layout of C structs with natural alignment padding.
+/// The padding bytes can be accessed like a [fixed size byte array](crate#fixed-size-byte-arrays-u8-n),
+/// but since the padding size depends on the offset, it is returned as a slice.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// // struct my_struct {
+/// //   uint8_t field1;
+/// //   uint64_t field2;
+/// // };
+/// define_layout!(my_struct, LittleEndian, {
+///   field1: u8,
+///   _padding: AlignTo<8>,
+///   field2: u64,
+/// });
+///
+/// # fn main() {
+/// assert_eq!(Some(7), my_struct::_padding::SIZE);
+/// assert_eq!(8, my_struct::field2::OFFSET);
+/// # }
+/// ```
+pub struct AlignTo<const N: usize>;
+
+impl<const N: usize> AlignTo<N> {
+    const fn padding(offset: usize) -> usize {
+        assert!(N > 0, "Error: AlignTo<N> requires N > 0");
+        (N - offset % N) % N
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> Field
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(AlignTo::<N>::padding(OFFSET_));
+}
+
+/// Field type [AlignTo]:
+/// This field represents padding bytes. In this impl, we define accessors for them.
+impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> FieldSliceAccess<'a>
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    type SliceType = &'a [u8];
+    type MutSliceType = &'a mut [u8];
+
+    /// Borrow the padding bytes with read access using the [Field] API.
+    #[inline(always)]
+    fn data(storage: &'a [u8]) -> &'a [u8] {
+        &storage[Self::OFFSET..(Self::OFFSET + AlignTo::<N>::padding(OFFSET_))]
+    }
+
+    /// Borrow the padding bytes with write access using the [Field] API.
+    #[inline(always)]
+    fn data_mut(storage: &'a mut [u8]) -> &'a mut [u8] {
+        &mut storage[Self::OFFSET..(Self::OFFSET + AlignTo::<N>::padding(OFFSET_))]
+    }
+}
+
+impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    type View = &'a [u8];
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::data(storage)
+    }
+}
+
+impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    type View = &'a mut [u8];
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::data_mut(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, E: Endianness, const N: usize, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    type View = Data<S>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Data::from(storage)
+            .into_subregion(Self::OFFSET..(Self::OFFSET + AlignTo::<N>::padding(OFFSET_)))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(Some(0), PrimitiveField::<AlignTo<8>, LittleEndian, 0>::SIZE);
+        assert_eq!(Some(7), PrimitiveField::<AlignTo<8>, LittleEndian, 1>::SIZE);
+        assert_eq!(Some(3), PrimitiveField::<AlignTo<8>, LittleEndian, 5>::SIZE);
+        assert_eq!(Some(0), PrimitiveField::<AlignTo<8>, LittleEndian, 8>::SIZE);
+        assert_eq!(Some(1), PrimitiveField::<AlignTo<2>, BigEndian, 9>::SIZE);
+        assert_eq!(Some(0), PrimitiveField::<AlignTo<1>, BigEndian, 9>::SIZE);
+    }
+
+    #[test]
+    fn test_data() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<AlignTo<8>, LittleEndian, 5>;
+
+        Field1::data_mut(&mut storage).copy_from_slice(&[1, 2, 3]);
+
+        assert_eq!(&[1, 2, 3], Field1::data(&storage));
+        assert_eq!(&[0, 0, 0, 0, 0, 1, 2, 3, 0], &storage[..9]);
+    }
+}
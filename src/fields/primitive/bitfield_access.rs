@@ -0,0 +1,243 @@
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+/// Defines how the bits of a [BitFields] field are numbered within its integer.
+/// This is implemented by [LsbFirst] and [MsbFirst].
+pub trait BitOrder {
+    /// True if bit 0 is the most significant bit of the integer
+    const MSB_FIRST: bool;
+}
+
+/// Bit order for [BitFields] where bit 0 is the least significant bit of the integer.
+pub struct LsbFirst;
+
+impl BitOrder for LsbFirst {
+    const MSB_FIRST: bool = false;
+}
+
+/// Bit order for [BitFields] where bit 0 is the most significant bit of the integer,
+/// like in the packet diagrams of many RFCs.
+pub struct MsbFirst;
+
+impl BitOrder for MsbFirst {
+    const MSB_FIRST: bool = true;
+}
+
+/// Use this as a field type to store an unsigned integer `U` that packs several values into ranges of its bits.
+/// The integer is stored in the endianness of the layout and `O` defines how its bits are numbered,
+/// either [LsbFirst] (the default) or [MsbFirst]. `U` can be `u8`, `u16`, `u32` or `u64`.
+///
+/// Besides reading and writing the whole integer, the field offers `read_bits(range)` and `write_bits(range, value)`
+/// to access the value stored in a range of its bits. A range of bits is always read as an integer whose
+/// most significant bit is the bit in the range that is most significant in `U`. Accessing a range outside of the
+/// bits of `U` panics, and so does writing a value that doesn't fit into the range.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// // The first byte of an IPv4 header, in RFC 791 bit numbering
+/// define_layout!(ipv4_start, BigEndian, {
+///   version_and_ihl: BitFields<u8, MsbFirst>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 1];
+/// let mut view = ipv4_start::View::new(&mut storage);
+/// view.version_and_ihl_mut().write_bits(0..4, 4);
+/// view.version_and_ihl_mut().write_bits(4..8, 5);
+/// assert_eq!(4, view.version_and_ihl().read_bits(0..4));
+/// assert_eq!([0x45], storage);
+/// # }
+/// ```
+pub struct BitFields<U, O: BitOrder = LsbFirst> {
+    _p: PhantomData<(U, O)>,
+}
+
+macro_rules! impl_bitfields {
+    ($type: ty) => {
+        impl<O: BitOrder> BitFields<$type, O> {
+            /// Return the shift and the unshifted mask of the given bit range
+            #[inline(always)]
+            fn shift_and_mask(bits: &Range<u32>) -> (u32, $type) {
+                assert!(
+                    bits.start < bits.end && bits.end <= <$type>::BITS,
+                    "Error: Bit range {:?} is out of bounds for a {}-bit field",
+                    bits,
+                    <$type>::BITS
+                );
+                let len = bits.end - bits.start;
+                let shift = if O::MSB_FIRST {
+                    <$type>::BITS - bits.end
+                } else {
+                    bits.start
+                };
+                (shift, <$type>::MAX >> (<$type>::BITS - len))
+            }
+        }
+
+        impl<O: BitOrder, E: Endianness, const OFFSET_: usize>
+            PrimitiveField<BitFields<$type, O>, E, OFFSET_>
+        {
+            /// Read the value stored in the given range of bits from a given data region, assuming the defined layout, using the [Field] API.
+            /// This panics if the range is empty or outside of the bits of the field.
+            ///
+            /// # Example:
+            /// See [BitFields] for an example
+            #[inline(always)]
+            pub fn read_bits(storage: &[u8], bits: Range<u32>) -> $type {
+                let (shift, mask) = BitFields::<$type, O>::shift_and_mask(&bits);
+                (Self::read(storage) >> shift) & mask
+            }
+
+            /// Write the value into the given range of bits in a given data region, assuming the defined layout, using the [Field] API.
+            /// The other bits of the field keep their value. This panics if the range is empty or outside of the bits of the field,
+            /// or if the value doesn't fit into the range.
+            ///
+            /// # Example:
+            /// See [BitFields] for an example
+            #[inline(always)]
+            pub fn write_bits(storage: &mut [u8], bits: Range<u32>, value: $type) {
+                let (shift, mask) = BitFields::<$type, O>::shift_and_mask(&bits);
+                assert!(
+                    value <= mask,
+                    "Error: Value {} doesn't fit into bit range {:?}",
+                    value,
+                    bits
+                );
+                let previous = Self::read(storage) & !(mask << shift);
+                Self::write(storage, previous | (value << shift));
+            }
+        }
+
+        impl<O: BitOrder, E: Endianness, const OFFSET_: usize> FieldCopyAccess
+            for PrimitiveField<BitFields<$type, O>, E, OFFSET_>
+        {
+            /// See [FieldCopyAccess::HighLevelType]
+            type HighLevelType = $type;
+
+            /// Read the whole integer of the field from a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn read(storage: &[u8]) -> $type {
+                PrimitiveField::<$type, E, OFFSET_>::read(storage)
+            }
+
+            /// Write the whole integer of the field to a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn write(storage: &mut [u8], value: $type) {
+                PrimitiveField::<$type, E, OFFSET_>::write(storage, value)
+            }
+        }
+
+        impl<O: BitOrder, E: Endianness, const OFFSET_: usize> Field
+            for PrimitiveField<BitFields<$type, O>, E, OFFSET_>
+        {
+            /// See [Field::Endian]
+            type Endian = E;
+            /// See [Field::OFFSET]
+            const OFFSET: usize = OFFSET_;
+            /// See [Field::SIZE]
+            const SIZE: Option<usize> = Some(core::mem::size_of::<$type>());
+        }
+
+        impl<'a, O: BitOrder, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+            for PrimitiveField<BitFields<$type, O>, E, OFFSET_>
+        {
+            type View = FieldView<&'a [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<'a, O: BitOrder, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+            for PrimitiveField<BitFields<$type, O>, E, OFFSET_>
+        {
+            type View = FieldView<&'a mut [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a mut [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<S: AsRef<[u8]>, O: BitOrder, E: Endianness, const OFFSET_: usize>
+            StorageIntoFieldView<S> for PrimitiveField<BitFields<$type, O>, E, OFFSET_>
+        {
+            type View = FieldView<S, Self>;
+
+            #[inline(always)]
+            fn into_view(storage: S) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+    };
+}
+
+impl_bitfields!(u8);
+impl_bitfields!(u16);
+impl_bitfields!(u32);
+impl_bitfields!(u64);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bit_order() {
+        let mut storage = vec![0; 1024];
+        storage[5] = 0b1011_0010;
+
+        type Lsb = PrimitiveField<BitFields<u8, LsbFirst>, BigEndian, 5>;
+        type Msb = PrimitiveField<BitFields<u8, MsbFirst>, BigEndian, 5>;
+
+        assert_eq!(0b010, Lsb::read_bits(&storage, 0..3));
+        assert_eq!(0b101, Msb::read_bits(&storage, 0..3));
+        assert_eq!(0b1011, Lsb::read_bits(&storage, 4..8));
+        assert_eq!(0b0010, Msb::read_bits(&storage, 4..8));
+        assert_eq!(0b1011_0010, Lsb::read_bits(&storage, 0..8));
+        assert_eq!(0b1011_0010, Msb::read_bits(&storage, 0..8));
+    }
+
+    #[test]
+    fn test_write_bits() {
+        let mut storage = vec![0; 1024];
+
+        type Lsb = PrimitiveField<BitFields<u16, LsbFirst>, LittleEndian, 5>;
+        type Msb = PrimitiveField<BitFields<u16, MsbFirst>, BigEndian, 20>;
+
+        Lsb::write(&mut storage, 0xFFFF);
+        Lsb::write_bits(&mut storage, 4..12, 0x12);
+        assert_eq!(0xF12F, Lsb::read(&storage));
+        assert_eq!(&[0x2F, 0xF1], &storage[5..7]);
+
+        Msb::write_bits(&mut storage, 0..3, 0b101);
+        Msb::write_bits(&mut storage, 13..16, 0b011);
+        assert_eq!(0b1010_0000_0000_0011, Msb::read(&storage));
+        assert_eq!(&[0xA0, 0x03], &storage[20..22]);
+        assert_eq!(0b101, Msb::read_bits(&storage, 0..3));
+
+        assert_eq!(Some(2), Lsb::SIZE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Bit range 4..9 is out of bounds for a 8-bit field")]
+    fn test_bits_out_of_bounds() {
+        let storage = vec![0; 1024];
+        PrimitiveField::<BitFields<u8>, BigEndian, 5>::read_bits(&storage, 4..9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Value 8 doesn't fit into bit range 0..3")]
+    fn test_value_too_large() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<BitFields<u8>, BigEndian, 5>::write_bits(&mut storage, 0..3, 8);
+    }
+}
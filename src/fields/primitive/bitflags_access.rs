@@ -0,0 +1,208 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use bitflags::Flags;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+/// This error describes a stored value of a [FlagsAs] field that has bits set that don't correspond to any flag
+/// defined for the flags type. It carries the raw stored value. `try_read` reports it as
+/// [LayoutError::UnknownFlags](crate::LayoutError::UnknownFlags).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownFlags<B>(pub B);
+
+impl<B: fmt::LowerHex> fmt::Display for UnknownFlags<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stored value {:#x} has unknown flags set", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: fmt::Debug + fmt::LowerHex> std::error::Error for UnknownFlags<B> {}
+
+/// Use this as a field type to store a flags type generated by the [bitflags](https://docs.rs/bitflags) crate.
+/// The field is stored as the integer backing the flags type, in the endianness of the layout.
+///
+/// Reading the field with `read` masks out any stored bits that don't correspond to a flag defined for the flags type.
+/// Use `try_read` instead to get a [LayoutError::UnknownFlags](crate::LayoutError::UnknownFlags) error for such values.
+///
+/// This field type is only available if the `bitflags` feature is enabled.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::FlagsAs;
+///
+/// bitflags::bitflags! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub struct Permissions: u16 {
+///         const READ = 0b001;
+///         const WRITE = 0b010;
+///         const EXECUTE = 0b100;
+///     }
+/// }
+///
+/// define_layout!(file_entry, LittleEndian, {
+///   permissions: FlagsAs<Permissions>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 2];
+/// let mut view = file_entry::View::new(&mut storage);
+/// view.permissions_mut().write(Permissions::READ | Permissions::WRITE);
+/// assert_eq!(Permissions::READ | Permissions::WRITE, view.permissions().read());
+/// assert_eq!([0b011, 0], storage);
+/// # }
+/// ```
+pub struct FlagsAs<T: Flags> {
+    _p: PhantomData<T>,
+}
+
+impl<T: Flags, E: Endianness, const OFFSET_: usize> PrimitiveField<FlagsAs<T>, E, OFFSET_>
+where
+    PrimitiveField<T::Bits, E, OFFSET_>: FieldCopyAccess<HighLevelType = T::Bits>,
+{
+    /// Read the flags field from a given data region, assuming the defined layout, using the [Field] API.
+    /// In contrast to [read](FieldCopyAccess::read), this returns [LayoutError::UnknownFlags](crate::LayoutError::UnknownFlags) if the stored
+    /// value has bits set that don't correspond to any defined flag.
+    ///
+    /// # Example:
+    /// See [FlagsAs] for an example
+    #[inline(always)]
+    pub fn try_read(storage: &[u8]) -> Result<T, crate::LayoutError>
+    where
+        T::Bits: Into<u128>,
+    {
+        let bits = PrimitiveField::<T::Bits, E, OFFSET_>::read(storage);
+        Ok(T::from_bits(bits).ok_or(UnknownFlags(bits))?)
+    }
+}
+
+impl<T: Flags, E: Endianness, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+where
+    PrimitiveField<T::Bits, E, OFFSET_>: FieldCopyAccess<HighLevelType = T::Bits>,
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = T;
+
+    /// Read the flags field from a given data region, assuming the defined layout, using the [Field] API.
+    /// Stored bits that don't correspond to any defined flag are masked out.
+    #[inline(always)]
+    fn read(storage: &[u8]) -> T {
+        T::from_bits_truncate(PrimitiveField::<T::Bits, E, OFFSET_>::read(storage))
+    }
+
+    /// Write the flags field to a given data region, assuming the defined layout, using the [Field] API.
+    #[inline(always)]
+    fn write(storage: &mut [u8], value: T) {
+        PrimitiveField::<T::Bits, E, OFFSET_>::write(storage, value.bits())
+    }
+}
+
+impl<T: Flags, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(core::mem::size_of::<T::Bits>());
+}
+
+impl<'a, T: Flags, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, T: Flags, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, T: Flags, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use core::convert::TryInto;
+
+    bitflags::bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Permissions: u32 {
+            const READ = 0b001;
+            const WRITE = 0b010;
+            const EXECUTE = 0b100;
+        }
+    }
+
+    #[test]
+    fn test_flags() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<FlagsAs<Permissions>, LittleEndian, 5>;
+        type Field2 = PrimitiveField<FlagsAs<Permissions>, BigEndian, 20>;
+
+        Field1::write(&mut storage, Permissions::READ | Permissions::EXECUTE);
+        Field2::write(&mut storage, Permissions::WRITE);
+
+        assert_eq!(
+            Permissions::READ | Permissions::EXECUTE,
+            Field1::read(&storage)
+        );
+        assert_eq!(Permissions::WRITE, Field2::read(&storage));
+        assert_eq!(
+            0b101,
+            u32::from_le_bytes((&storage[5..9]).try_into().unwrap())
+        );
+        assert_eq!(
+            0b010,
+            u32::from_be_bytes((&storage[20..24]).try_into().unwrap())
+        );
+
+        assert_eq!(Some(4), Field1::SIZE);
+    }
+
+    #[test]
+    fn test_unknown_flags() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<FlagsAs<Permissions>, LittleEndian, 5>;
+
+        PrimitiveField::<u32, LittleEndian, 5>::write(&mut storage, 0b1001);
+        assert_eq!(Permissions::READ, Field1::read(&storage));
+        assert_eq!(
+            Err(crate::LayoutError::UnknownFlags(0b1001)),
+            Field1::try_read(&storage)
+        );
+
+        Field1::write(&mut storage, Permissions::WRITE);
+        assert_eq!(Ok(Permissions::WRITE), Field1::try_read(&storage));
+    }
+}
@@ -0,0 +1,125 @@
+use core::marker::PhantomData;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::{Endianness, FieldSliceAccess, PrimitiveField};
+use crate::utils::data::Data;
+
+/// Field type of an [open ended byte array](crate#open-ended-byte-arrays-u8) with a declared maximum length of `MAX` bytes.
+/// A field declared as `tail: [u8; ..=64]` in the [define_layout!](crate::define_layout!) macro has this type.
+///
+/// The field is accessed like a `[u8]` field and covers the storage from its offset to the end.
+/// The maximum length isn't enforced by the accessors, but by the checking constructors of the layout like
+/// `View::new_exact`, which rejects storages whose tail is longer than `MAX` bytes.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::LayoutError;
+///
+/// define_layout!(message, LittleEndian, {
+///   id: u16,
+///   payload: [u8; ..=4],
+/// });
+///
+/// # fn main() {
+/// assert_eq!(Some(6), message::MAX_SIZE);
+///
+/// let view = message::View::new_exact(&[1, 0, 1, 2, 3][..]).unwrap();
+/// assert_eq!(&[1, 2, 3], view.payload());
+///
+/// assert_eq!(
+///   Err(LayoutError::BufferTooLarge { expected: 6, actual: 7 }),
+///   message::View::new_exact(&[1, 0, 1, 2, 3, 4, 5][..]).map(|_| ()),
+/// );
+/// # }
+/// ```
+pub struct BoundedBytes<const MAX: usize> {
+    _p: PhantomData<[u8; MAX]>,
+}
+
+impl<E: Endianness, const MAX: usize, const OFFSET_: usize> Field
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = None;
+    /// See [Field::MAX_SIZE]
+    const MAX_SIZE: Option<usize> = Some(MAX);
+}
+
+impl<'a, E: Endianness, const MAX: usize, const OFFSET_: usize> FieldSliceAccess<'a>
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    type SliceType = &'a [u8];
+    type MutSliceType = &'a mut [u8];
+
+    /// Borrow the data in the byte array with read access using the [Field] API.
+    /// See [FieldSliceAccess::data]
+    #[inline(always)]
+    fn data(storage: &'a [u8]) -> &'a [u8] {
+        &storage[Self::OFFSET..]
+    }
+
+    /// Borrow the data in the byte array with write access using the [Field] API.
+    /// See [FieldSliceAccess::data_mut]
+    #[inline(always)]
+    fn data_mut(storage: &'a mut [u8]) -> &'a mut [u8] {
+        &mut storage[Self::OFFSET..]
+    }
+}
+
+impl<'a, E: Endianness, const MAX: usize, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    type View = &'a [u8];
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        &storage[Self::OFFSET..]
+    }
+}
+
+impl<'a, E: Endianness, const MAX: usize, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    type View = &'a mut [u8];
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        &mut storage[Self::OFFSET..]
+    }
+}
+
+impl<S: AsRef<[u8]>, E: Endianness, const MAX: usize, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    type View = Data<S>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Data::from(storage).into_subregion(Self::OFFSET..)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bounded_bytes() {
+        let mut storage = vec![0; 10];
+
+        type Field1 = PrimitiveField<BoundedBytes<8>, LittleEndian, 5>;
+
+        Field1::data_mut(&mut storage).copy_from_slice(&[1, 2, 3, 4, 5]);
+        assert_eq!(&[1, 2, 3, 4, 5], Field1::data(&storage));
+        assert_eq!(&[1, 2, 3, 4, 5], &storage[5..]);
+
+        assert_eq!(None, Field1::SIZE);
+        assert_eq!(Some(8), Field1::MAX_SIZE);
+    }
+}
@@ -0,0 +1,197 @@
+use core::fmt;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+use crate::LayoutError;
+
+/// Describes a stored code point of a `char` field that isn't a valid
+/// [Unicode scalar value](https://www.unicode.org/glossary/#unicode_scalar_value),
+/// i.e. it is a surrogate or larger than `0x10FFFF`. It carries the raw stored value.
+/// Reading such a field returns it as [LayoutError::InvalidChar], which this converts into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidChar(pub u32);
+
+impl fmt::Display for InvalidChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid unicode scalar value: {:#x}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidChar {}
+
+/// Field type `char`:
+/// This field is stored as its `u32` code point in the endianness of the layout.
+/// Since not every `u32` is a valid `char`, reading it is fallible.
+impl<E: Endianness, const OFFSET_: usize> PrimitiveField<char, E, OFFSET_> {
+    /// Read the char field from a given data region, assuming the defined layout, using the [Field] API.
+    /// Returns a [LayoutError::InvalidChar] error if the stored code point isn't a valid `char`.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_char_field: char,
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///     let read: Result<char, binary_layout::LayoutError> = my_layout::some_char_field::read(storage_data);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn read(storage: &[u8]) -> Result<char, LayoutError> {
+        Ok(Self::read_code_point(storage)?)
+    }
+
+    /// Read the char field, keeping the stored code point if it isn't a valid `char`, e.g. to write it back unchanged.
+    #[inline(always)]
+    pub(crate) fn read_code_point(storage: &[u8]) -> Result<char, InvalidChar> {
+        let code_point = PrimitiveField::<u32, E, OFFSET_>::read(storage);
+        char::from_u32(code_point).ok_or(InvalidChar(code_point))
+    }
+
+    /// Write the char field to a given data region, assuming the defined layout, using the [Field] API.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_char_field: char,
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///     my_layout::some_char_field::write(storage_data, 'ß');
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn write(storage: &mut [u8], value: char) {
+        PrimitiveField::<u32, E, OFFSET_>::write(storage, u32::from(value))
+    }
+}
+
+impl<E: Endianness, const OFFSET_: usize> Field for PrimitiveField<char, E, OFFSET_> {
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(core::mem::size_of::<u32>());
+}
+
+impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<char, E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<char, E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<char, E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use core::convert::TryInto;
+
+    #[test]
+    fn test_char_littleendian() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<char, LittleEndian, 5>;
+        type Field2 = PrimitiveField<char, LittleEndian, 20>;
+
+        Field1::write(&mut storage, 'a');
+        Field2::write(&mut storage, '🦀');
+
+        assert_eq!(Ok('a'), Field1::read(&storage));
+        assert_eq!(Ok('🦀'), Field2::read(&storage));
+
+        assert_eq!(
+            0x61,
+            u32::from_le_bytes((&storage[5..9]).try_into().unwrap())
+        );
+        assert_eq!(
+            0x1F980,
+            u32::from_le_bytes((&storage[20..24]).try_into().unwrap())
+        );
+
+        assert_eq!(Some(4), PrimitiveField::<char, LittleEndian, 5>::SIZE);
+    }
+
+    #[test]
+    fn test_char_bigendian() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<char, BigEndian, 5>;
+        type Field2 = PrimitiveField<char, BigEndian, 20>;
+
+        Field1::write(&mut storage, 'a');
+        Field2::write(&mut storage, '🦀');
+
+        assert_eq!(Ok('a'), Field1::read(&storage));
+        assert_eq!(Ok('🦀'), Field2::read(&storage));
+
+        assert_eq!(
+            0x61,
+            u32::from_be_bytes((&storage[5..9]).try_into().unwrap())
+        );
+        assert_eq!(
+            0x1F980,
+            u32::from_be_bytes((&storage[20..24]).try_into().unwrap())
+        );
+
+        assert_eq!(Some(4), PrimitiveField::<char, BigEndian, 5>::SIZE);
+    }
+
+    #[test]
+    fn test_invalid_char() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<char, LittleEndian, 5>;
+
+        storage[5..9].copy_from_slice(&0x110000u32.to_le_bytes());
+        assert_eq!(
+            Err(LayoutError::InvalidChar(0x110000)),
+            Field1::read(&storage)
+        );
+
+        // Surrogates aren't valid chars either
+        storage[5..9].copy_from_slice(&0xD800u32.to_le_bytes());
+        assert_eq!(
+            Err(LayoutError::InvalidChar(0xD800)),
+            Field1::read(&storage)
+        );
+    }
+}
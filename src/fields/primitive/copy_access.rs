@@ -1,7 +1,25 @@
+use core::convert::TryInto;
+use core::fmt;
+
 use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
 use super::view::FieldView;
 use super::PrimitiveField;
 use crate::endianness::{EndianKind, Endianness};
+use crate::fields::error::LayoutError;
+
+/// This error describes a value that can't be converted into the type of a field without losing information,
+/// for example `300u32` for a `u8` field. [FieldCopyAccess::write_checked] reports it as [LayoutError::OutOfRange].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeError;
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Value is out of range for the field type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
 
 /// This trait is implemented for fields with "copy access",
 /// i.e. fields that read/write data by copying it from/to the
@@ -33,6 +51,32 @@ pub trait FieldCopyAccess: Field {
     /// ```
     fn read(storage: &[u8]) -> Self::HighLevelType;
 
+    /// Read the field from a given data region, assuming the defined layout, using the [Field] API,
+    /// and convert it into a wider type `U`. For signed integers, this sign-extends the value,
+    /// e.g. reading `-1` from an `i8` field as an `i32` gives `-1`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: i8,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   my_layout::some_integer_field::write(storage_data, -100);
+    ///   let read: i32 = my_layout::some_integer_field::read_as(storage_data);
+    ///   assert_eq!(-100, read);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn read_as<U: From<Self::HighLevelType>>(storage: &[u8]) -> U {
+        U::from(Self::read(storage))
+    }
+
     /// Write the field to a given data region, assuming the defined layout, using the [Field] API.
     ///
     /// # Example:
@@ -51,6 +95,93 @@ pub trait FieldCopyAccess: Field {
     /// }
     /// ```
     fn write(storage: &mut [u8], v: Self::HighLevelType);
+
+    /// Write the field to a given data region, assuming the defined layout, using the [Field] API.
+    /// In contrast to [write](FieldCopyAccess::write), this accepts any value that can be converted
+    /// into the type of the field, e.g. a `u32` for a `u8` field, and returns [LayoutError::OutOfRange] without
+    /// writing anything if the value doesn't fit.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: u8,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   let value: u32 = 200;
+    ///   my_layout::some_integer_field::write_checked(storage_data, value).unwrap();
+    ///
+    ///   let value: u32 = 300;
+    ///   assert!(my_layout::some_integer_field::write_checked(storage_data, value).is_err());
+    /// }
+    /// ```
+    #[inline(always)]
+    fn write_checked<V: TryInto<Self::HighLevelType>>(
+        storage: &mut [u8],
+        v: V,
+    ) -> Result<(), LayoutError> {
+        let v = v.try_into().map_err(|_| RangeError)?;
+        Self::write(storage, v);
+        Ok(())
+    }
+
+    /// Write the field to a given data region, assuming the defined layout, using the [Field] API,
+    /// and return the value the field held before.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: u16,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   my_layout::some_integer_field::write(storage_data, 10);
+    ///   let previous: u16 = my_layout::some_integer_field::swap(storage_data, 20);
+    ///   assert_eq!(10, previous);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn swap(storage: &mut [u8], v: Self::HighLevelType) -> Self::HighLevelType {
+        let previous = Self::read(storage);
+        Self::write(storage, v);
+        previous
+    }
+
+    /// Read the field from a given data region, assuming the defined layout, using the [Field] API,
+    /// and write back the value returned by `f` for it.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: u16,
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   my_layout::some_integer_field::write(storage_data, 10);
+    ///   my_layout::some_integer_field::update(storage_data, |v| v * 2);
+    ///   assert_eq!(20, my_layout::some_integer_field::read(storage_data));
+    /// }
+    /// ```
+    #[inline(always)]
+    fn update(storage: &mut [u8], f: impl FnOnce(Self::HighLevelType) -> Self::HighLevelType) {
+        let value = f(Self::read(storage));
+        Self::write(storage, value);
+    }
 }
 
 macro_rules! impl_field_traits {
@@ -186,6 +317,251 @@ int_field!(u32);
 int_field!(u64);
 int_field!(u128);
 
+/// Internal trait. Don't use this in user code.
+/// Implemented for all integer types, so `write_saturating` and `write_wrapping` accept values of any of them.
+pub trait WideInt: Copy {
+    /// Returns true if the value is less than zero
+    fn is_negative(self) -> bool;
+
+    /// Returns the two's complement bits of the value, sign extended to 128 bits
+    fn to_bits(self) -> u128;
+}
+
+macro_rules! wide_int {
+    (signed $type: ty) => {
+        impl WideInt for $type {
+            #[inline(always)]
+            fn is_negative(self) -> bool {
+                self < 0
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> u128 {
+                self as i128 as u128
+            }
+        }
+    };
+    (unsigned $type: ty) => {
+        impl WideInt for $type {
+            #[inline(always)]
+            fn is_negative(self) -> bool {
+                false
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> u128 {
+                self as u128
+            }
+        }
+    };
+}
+
+wide_int!(signed i8);
+wide_int!(signed i16);
+wide_int!(signed i32);
+wide_int!(signed i64);
+wide_int!(signed i128);
+wide_int!(unsigned u8);
+wide_int!(unsigned u16);
+wide_int!(unsigned u32);
+wide_int!(unsigned u64);
+wide_int!(unsigned u128);
+
+macro_rules! int_overflow_write {
+    ($type:ty) => {
+        impl<E: Endianness, const OFFSET_: usize> PrimitiveField<$type, E, OFFSET_> {
+            doc_comment::doc_comment! {
+                concat! {"
+                Write a value of any integer type, including `i128` and `u128`, to the field, assuming the defined layout, using the [Field] API.
+                Values that don't fit into the field are clamped to `", stringify!($type), "::MIN` or `", stringify!($type), "::MAX`.
+
+                # Example:
+
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                    //... other fields ...
+                    some_integer_field: ", stringify!($type), "
+                    //... other fields ...
+                });
+
+                fn func(storage_data: &mut [u8]) {
+                    my_layout::some_integer_field::write_saturating(storage_data, u128::MAX);
+                    assert_eq!(", stringify!($type), "::MAX, my_layout::some_integer_field::read(storage_data));
+                }
+                ```
+                "},
+                #[inline(always)]
+                pub fn write_saturating<V: WideInt>(storage: &mut [u8], value: V) {
+                    let bits = value.to_bits();
+                    let value = if value.is_negative() {
+                        (bits as i128).max(<$type>::MIN as i128) as $type
+                    } else {
+                        bits.min(<$type>::MAX as u128) as $type
+                    };
+                    Self::write(storage, value);
+                }
+            }
+
+            doc_comment::doc_comment! {
+                concat! {"
+                Write a value of any integer type, including `i128` and `u128`, to the field, assuming the defined layout, using the [Field] API.
+                Values that don't fit into the field wrap around like an `as` cast, i.e. only the low bits of the value that fit into a `", stringify!($type), "` are written.
+
+                # Example:
+
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                    //... other fields ...
+                    some_integer_field: ", stringify!($type), "
+                    //... other fields ...
+                });
+
+                fn func(storage_data: &mut [u8]) {
+                    my_layout::some_integer_field::write_wrapping(storage_data, i128::MAX);
+                    assert_eq!(i128::MAX as ", stringify!($type), ", my_layout::some_integer_field::read(storage_data));
+                }
+                ```
+                "},
+                #[inline(always)]
+                pub fn write_wrapping<V: WideInt>(storage: &mut [u8], value: V) {
+                    Self::write(storage, value.to_bits() as $type);
+                }
+            }
+        }
+    };
+}
+
+int_overflow_write!(i8);
+int_overflow_write!(i16);
+int_overflow_write!(i32);
+int_overflow_write!(i64);
+int_overflow_write!(i128);
+int_overflow_write!(u8);
+int_overflow_write!(u16);
+int_overflow_write!(u32);
+int_overflow_write!(u64);
+int_overflow_write!(u128);
+
+macro_rules! int_normalized {
+    ($type:ty) => {
+        impl<E: Endianness, const OFFSET_: usize> PrimitiveField<$type, E, OFFSET_> {
+            doc_comment::doc_comment! {
+                concat! {"
+                Read the field as a ratio in the range `0.0..=1.0`, assuming the defined layout, using the [Field] API.
+                The stored value is divided by `", stringify!($type), "::MAX`, i.e. `0` reads as `0.0` and `", stringify!($type), "::MAX` reads as `1.0`.
+
+                # Example:
+
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                    //... other fields ...
+                    some_integer_field: ", stringify!($type), "
+                    //... other fields ...
+                });
+
+                fn func(storage_data: &mut [u8]) {
+                    my_layout::some_integer_field::write(storage_data, ", stringify!($type), "::MAX);
+                    assert_eq!(1.0, my_layout::some_integer_field::read_normalized(storage_data));
+                }
+                ```
+                "},
+                #[inline(always)]
+                pub fn read_normalized(storage: &[u8]) -> f32 {
+                    (Self::read(storage) as f64 / <$type>::MAX as f64) as f32
+                }
+            }
+
+            doc_comment::doc_comment! {
+                concat! {"
+                Write a ratio in the range `0.0..=1.0` to the field, assuming the defined layout, using the [Field] API.
+                The ratio is multiplied by `", stringify!($type), "::MAX` and rounded to the nearest integer.
+                Ratios outside of the range are clamped to it and NaN is written as `0`.
+
+                # Example:
+
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                    //... other fields ...
+                    some_integer_field: ", stringify!($type), "
+                    //... other fields ...
+                });
+
+                fn func(storage_data: &mut [u8]) {
+                    my_layout::some_integer_field::write_normalized(storage_data, 1.0);
+                    assert_eq!(", stringify!($type), "::MAX, my_layout::some_integer_field::read(storage_data));
+                }
+                ```
+                "},
+                #[inline(always)]
+                pub fn write_normalized(storage: &mut [u8], value: f32) {
+                    // The `as` cast saturates at the bounds of the integer type and maps NaN to zero,
+                    // adding 0.5 rounds to the nearest integer because `f64::round` isn't available in no_std
+                    let scaled = value.clamp(0.0, 1.0) as f64 * <$type>::MAX as f64 + 0.5;
+                    Self::write(storage, scaled as $type);
+                }
+            }
+        }
+    };
+}
+
+int_normalized!(u8);
+int_normalized!(u16);
+int_normalized!(u32);
+int_normalized!(u64);
+
+macro_rules! int_count_ones {
+    ($type:ty) => {
+        impl<E: Endianness, const OFFSET_: usize> PrimitiveField<$type, E, OFFSET_> {
+            doc_comment::doc_comment! {
+                concat! {"
+                Count the set bits of the field, assuming the defined layout, using the [Field] API.
+                The result doesn't depend on the endianness, which makes this useful for presence bitmaps or capability masks.
+
+                # Example:
+
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                    //... other fields ...
+                    some_integer_field: ", stringify!($type), "
+                    //... other fields ...
+                });
+
+                fn func(storage_data: &mut [u8]) {
+                    my_layout::some_integer_field::write(storage_data, 0b1011);
+                    assert_eq!(3, my_layout::some_integer_field::count_ones(storage_data));
+                }
+                ```
+                "},
+                #[inline(always)]
+                pub fn count_ones(storage: &[u8]) -> u32 {
+                    Self::read(storage).count_ones()
+                }
+            }
+        }
+    };
+}
+
+int_count_ones!(i8);
+int_count_ones!(i16);
+int_count_ones!(i32);
+int_count_ones!(i64);
+int_count_ones!(i128);
+int_count_ones!(u8);
+int_count_ones!(u16);
+int_count_ones!(u32);
+int_count_ones!(u64);
+int_count_ones!(u128);
+
 macro_rules! float_field {
     ($type:ty) => {
         impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess for PrimitiveField<$type, E, OFFSET_> {
@@ -352,11 +728,11 @@ impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess for PrimitiveField<(),
 
 impl_field_traits!(());
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     #![allow(clippy::float_cmp)]
     use crate::prelude::*;
-    use crate::PrimitiveField;
+    use crate::{FieldView, LayoutError, PrimitiveField};
     use core::convert::TryInto;
 
     #[test]
@@ -636,6 +1012,279 @@ mod tests {
         assert_eq!(Some(1), PrimitiveField::<u8, LittleEndian, 5>::SIZE);
     }
 
+    #[test]
+    fn test_u8_write_checked() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u8, LittleEndian, 5>;
+
+        assert_eq!(Ok(()), Field1::write_checked(&mut storage, 200u32));
+        assert_eq!(200, Field1::read(&storage));
+
+        assert_eq!(
+            Err(LayoutError::OutOfRange),
+            Field1::write_checked(&mut storage, 256u32)
+        );
+        assert_eq!(200, Field1::read(&storage));
+
+        let mut view = FieldView::<_, Field1>::new(&mut storage);
+        assert_eq!(Ok(()), view.write_checked(100u32));
+        assert_eq!(Err(LayoutError::OutOfRange), view.write_checked(1000u32));
+        assert_eq!(100, view.read());
+    }
+
+    #[test]
+    fn test_u8_write_saturating() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u8, LittleEndian, 5>;
+
+        Field1::write_saturating(&mut storage, 255u32);
+        assert_eq!(255, Field1::read(&storage));
+        Field1::write_saturating(&mut storage, 256u32);
+        assert_eq!(255, Field1::read(&storage));
+        Field1::write_saturating(&mut storage, 0i64);
+        assert_eq!(0, Field1::read(&storage));
+        Field1::write_saturating(&mut storage, -1i64);
+        assert_eq!(0, Field1::read(&storage));
+
+        let mut view = FieldView::<_, Field1>::new(&mut storage);
+        view.write_saturating(1000u16);
+        assert_eq!(255, view.read());
+        view.write_saturating(i64::MIN);
+        assert_eq!(0, view.read());
+    }
+
+    #[test]
+    fn test_i8_write_saturating() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<i8, BigEndian, 5>;
+
+        Field1::write_saturating(&mut storage, 128);
+        assert_eq!(127, Field1::read(&storage));
+        Field1::write_saturating(&mut storage, -129);
+        assert_eq!(-128, Field1::read(&storage));
+        Field1::write_saturating(&mut storage, u64::MAX);
+        assert_eq!(127, Field1::read(&storage));
+    }
+
+    #[test]
+    fn test_write_saturating_128_bit() {
+        let mut storage = vec![0; 1024];
+
+        type U8Field = PrimitiveField<u8, LittleEndian, 0>;
+        type I64Field = PrimitiveField<i64, BigEndian, 1>;
+        type I128Field = PrimitiveField<i128, LittleEndian, 9>;
+        type U128Field = PrimitiveField<u128, BigEndian, 25>;
+
+        U8Field::write_saturating(&mut storage, u128::MAX);
+        assert_eq!(u8::MAX, U8Field::read(&storage));
+        U8Field::write_saturating(&mut storage, i128::MIN);
+        assert_eq!(0, U8Field::read(&storage));
+
+        I64Field::write_saturating(&mut storage, u128::MAX);
+        assert_eq!(i64::MAX, I64Field::read(&storage));
+        I64Field::write_saturating(&mut storage, i128::MIN);
+        assert_eq!(i64::MIN, I64Field::read(&storage));
+
+        I128Field::write_saturating(&mut storage, u128::MAX);
+        assert_eq!(i128::MAX, I128Field::read(&storage));
+        I128Field::write_saturating(&mut storage, i128::MAX as u128 + 1);
+        assert_eq!(i128::MAX, I128Field::read(&storage));
+        I128Field::write_saturating(&mut storage, i128::MAX as u128);
+        assert_eq!(i128::MAX, I128Field::read(&storage));
+        I128Field::write_saturating(&mut storage, i128::MIN);
+        assert_eq!(i128::MIN, I128Field::read(&storage));
+
+        U128Field::write_saturating(&mut storage, u128::MAX);
+        assert_eq!(u128::MAX, U128Field::read(&storage));
+        U128Field::write_saturating(&mut storage, -1i8);
+        assert_eq!(0, U128Field::read(&storage));
+        U128Field::write_saturating(&mut storage, i128::MAX);
+        assert_eq!(i128::MAX as u128, U128Field::read(&storage));
+
+        let mut view = FieldView::<_, U128Field>::new(&mut storage);
+        view.write_saturating(i128::MIN);
+        assert_eq!(0, view.read());
+    }
+
+    #[test]
+    fn test_write_wrapping_128_bit() {
+        let mut storage = vec![0; 1024];
+
+        type I8Field = PrimitiveField<i8, LittleEndian, 0>;
+        type I128Field = PrimitiveField<i128, LittleEndian, 1>;
+        type U128Field = PrimitiveField<u128, BigEndian, 17>;
+
+        I8Field::write_wrapping(&mut storage, 0x180u128);
+        assert_eq!(i8::MIN, I8Field::read(&storage));
+        I8Field::write_wrapping(&mut storage, u128::MAX);
+        assert_eq!(-1, I8Field::read(&storage));
+
+        I128Field::write_wrapping(&mut storage, u128::MAX);
+        assert_eq!(-1, I128Field::read(&storage));
+        I128Field::write_wrapping(&mut storage, i128::MAX as u128 + 1);
+        assert_eq!(i128::MIN, I128Field::read(&storage));
+
+        U128Field::write_wrapping(&mut storage, -1i32);
+        assert_eq!(u128::MAX, U128Field::read(&storage));
+        U128Field::write_wrapping(&mut storage, i128::MIN);
+        assert_eq!(1 << 127, U128Field::read(&storage));
+
+        let mut view = FieldView::<_, I128Field>::new(&mut storage);
+        view.write_wrapping(u64::MAX);
+        assert_eq!(u64::MAX as i128, view.read());
+    }
+
+    #[test]
+    fn test_u8_write_wrapping() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u8, LittleEndian, 5>;
+
+        Field1::write_wrapping(&mut storage, 255u32);
+        assert_eq!(255, Field1::read(&storage));
+        Field1::write_wrapping(&mut storage, 256u32);
+        assert_eq!(0, Field1::read(&storage));
+        Field1::write_wrapping(&mut storage, 300u32);
+        assert_eq!(44, Field1::read(&storage));
+        Field1::write_wrapping(&mut storage, -1i64);
+        assert_eq!(255, Field1::read(&storage));
+
+        let mut view = FieldView::<_, Field1>::new(&mut storage);
+        view.write_wrapping(257u16);
+        assert_eq!(1, view.read());
+        view.write_wrapping(-256i32);
+        assert_eq!(0, view.read());
+    }
+
+    #[test]
+    fn test_read_le_and_be() {
+        let mut storage = vec![0; 1024];
+        storage[4..8].copy_from_slice(&[0x01, 0x02, 0x03, 0x04]);
+
+        let view = FieldView::<_, PrimitiveField<u32, BigEndian, 4>>::new(&storage);
+        assert_eq!(0x01020304, view.read());
+        assert_eq!(0x01020304, view.read_be());
+        assert_eq!(0x04030201, view.read_le());
+
+        let view = FieldView::<_, PrimitiveField<i16, LittleEndian, 4>>::new(&storage);
+        assert_eq!(0x0201, view.read());
+        assert_eq!(0x0201, view.read_le());
+        assert_eq!(0x0102, view.read_be());
+    }
+
+    #[test]
+    fn test_u8_normalized() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u8, LittleEndian, 5>;
+
+        Field1::write(&mut storage, 0);
+        assert_eq!(0.0, Field1::read_normalized(&storage));
+        Field1::write(&mut storage, 255);
+        assert_eq!(1.0, Field1::read_normalized(&storage));
+        Field1::write(&mut storage, 128);
+        assert!((128.0 / 255.0 - Field1::read_normalized(&storage)).abs() < 1e-6);
+
+        Field1::write_normalized(&mut storage, 0.0);
+        assert_eq!(0, Field1::read(&storage));
+        Field1::write_normalized(&mut storage, 1.0);
+        assert_eq!(255, Field1::read(&storage));
+        Field1::write_normalized(&mut storage, 0.5);
+        assert_eq!(128, Field1::read(&storage));
+
+        // Out of range ratios are clamped
+        Field1::write_normalized(&mut storage, 1.5);
+        assert_eq!(255, Field1::read(&storage));
+        Field1::write_normalized(&mut storage, -0.5);
+        assert_eq!(0, Field1::read(&storage));
+        Field1::write_normalized(&mut storage, f32::NAN);
+        assert_eq!(0, Field1::read(&storage));
+
+        let mut view = FieldView::<_, Field1>::new(&mut storage);
+        view.write_normalized(1.0);
+        assert_eq!(1.0, view.read_normalized());
+    }
+
+    #[test]
+    fn test_u16_and_u64_normalized() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u16, BigEndian, 5>;
+        type Field2 = PrimitiveField<u64, LittleEndian, 10>;
+
+        Field1::write_normalized(&mut storage, 0.5);
+        assert_eq!(32768, Field1::read(&storage));
+        Field1::write_normalized(&mut storage, 1.0);
+        assert_eq!(u16::MAX, Field1::read(&storage));
+        assert_eq!(1.0, Field1::read_normalized(&storage));
+
+        Field2::write_normalized(&mut storage, 1.0);
+        assert_eq!(u64::MAX, Field2::read(&storage));
+        assert_eq!(1.0, Field2::read_normalized(&storage));
+        Field2::write_normalized(&mut storage, 0.0);
+        assert_eq!(0, Field2::read(&storage));
+        assert_eq!(0.0, Field2::read_normalized(&storage));
+    }
+
+    #[test]
+    fn test_count_ones() {
+        let mut storage = vec![0xFF; 1024];
+
+        type Field1 = PrimitiveField<u16, BigEndian, 5>;
+        type Field2 = PrimitiveField<i32, LittleEndian, 7>;
+        type Field3 = PrimitiveField<u128, LittleEndian, 11>;
+
+        Field1::write(&mut storage, 0b1010_0000_0000_0101);
+        assert_eq!(4, Field1::count_ones(&storage));
+        Field2::write(&mut storage, -1);
+        assert_eq!(32, Field2::count_ones(&storage));
+        Field2::write(&mut storage, 0);
+        assert_eq!(0, Field2::count_ones(&storage));
+        Field3::write(&mut storage, 0x8000_0000_0000_0000_0000_0000_0000_0001);
+        assert_eq!(2, Field3::count_ones(&storage));
+
+        let view = FieldView::<_, Field1>::new(&storage);
+        assert_eq!(4, view.count_ones());
+    }
+
+    #[test]
+    fn test_u8_swap() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u8, LittleEndian, 5>;
+
+        Field1::write(&mut storage, 50);
+        assert_eq!(50, Field1::swap(&mut storage, 60));
+        assert_eq!(60, Field1::read(&storage));
+
+        let mut view = FieldView::<_, Field1>::new(&mut storage);
+        assert_eq!(60, view.swap(70));
+        assert_eq!(70, view.read());
+    }
+
+    #[test]
+    fn test_u32_update() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u32, BigEndian, 5>;
+
+        Field1::write(&mut storage, 0xFFFF);
+        for _ in 0..3 {
+            Field1::update(&mut storage, |counter| counter + 1);
+        }
+        assert_eq!(0x1_0002, Field1::read(&storage));
+        assert_eq!(&[0, 1, 0, 2], &storage[5..9]);
+
+        let mut view = FieldView::<_, Field1>::new(&mut storage);
+        for _ in 0..3 {
+            view.update(|counter| counter + 1);
+        }
+        assert_eq!(0x1_0005, view.read());
+    }
+
     #[test]
     fn test_u8_bigendian() {
         let mut storage = vec![0; 1024];
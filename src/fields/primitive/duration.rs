@@ -0,0 +1,234 @@
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+/// A unit of time used by [DurationAs] fields to define what one tick of the stored `u64` represents.
+pub trait TimeUnit {
+    /// Convert a number of ticks into a [Duration]
+    fn to_duration(ticks: u64) -> Duration;
+
+    /// Convert a [Duration] into a number of ticks, rounding down.
+    /// Returns `None` if the number of ticks doesn't fit into a `u64`.
+    fn from_duration(duration: Duration) -> Option<u64>;
+}
+
+/// Marker type for a [DurationAs] field storing seconds
+pub struct Seconds;
+impl TimeUnit for Seconds {
+    #[inline(always)]
+    fn to_duration(ticks: u64) -> Duration {
+        Duration::from_secs(ticks)
+    }
+
+    #[inline(always)]
+    fn from_duration(duration: Duration) -> Option<u64> {
+        Some(duration.as_secs())
+    }
+}
+
+/// Marker type for a [DurationAs] field storing milliseconds
+pub struct Milliseconds;
+impl TimeUnit for Milliseconds {
+    #[inline(always)]
+    fn to_duration(ticks: u64) -> Duration {
+        Duration::from_millis(ticks)
+    }
+
+    #[inline(always)]
+    fn from_duration(duration: Duration) -> Option<u64> {
+        u64::try_from(duration.as_millis()).ok()
+    }
+}
+
+/// Marker type for a [DurationAs] field storing microseconds
+pub struct Microseconds;
+impl TimeUnit for Microseconds {
+    #[inline(always)]
+    fn to_duration(ticks: u64) -> Duration {
+        Duration::from_micros(ticks)
+    }
+
+    #[inline(always)]
+    fn from_duration(duration: Duration) -> Option<u64> {
+        u64::try_from(duration.as_micros()).ok()
+    }
+}
+
+/// Marker type for a [DurationAs] field storing nanoseconds
+pub struct Nanoseconds;
+impl TimeUnit for Nanoseconds {
+    #[inline(always)]
+    fn to_duration(ticks: u64) -> Duration {
+        Duration::from_nanos(ticks)
+    }
+
+    #[inline(always)]
+    fn from_duration(duration: Duration) -> Option<u64> {
+        u64::try_from(duration.as_nanos()).ok()
+    }
+}
+
+/// Use this as a field type to store a [Duration] as a `u64` number of ticks in the endianness of the layout.
+/// The type parameter defines the unit of a tick, e.g. [Milliseconds]. A common use case are timestamps stored
+/// as the duration since the unix epoch.
+///
+/// Reading the field returns a [Duration] and writing it takes a [Duration]. Writing a [Duration] rounds down
+/// to whole ticks and panics if the number of ticks doesn't fit into a `u64`.
+///
+/// This field type is only available if the `time` feature is enabled.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::time::{DurationAs, Milliseconds};
+/// use core::time::Duration;
+///
+/// define_layout!(log_entry, BigEndian, {
+///   timestamp: DurationAs<Milliseconds>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 8];
+/// let mut view = log_entry::View::new(&mut storage);
+/// view.timestamp_mut().write(Duration::from_millis(1_600_000_000_000));
+/// assert_eq!(Duration::from_millis(1_600_000_000_000), view.timestamp().read());
+/// assert_eq!(1_600_000_000_000u64.to_be_bytes(), storage);
+/// # }
+/// ```
+pub struct DurationAs<U: TimeUnit> {
+    _p: PhantomData<U>,
+}
+
+impl<U: TimeUnit, E: Endianness, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = Duration;
+
+    /// Read the duration field from a given data region, assuming the defined layout, using the [Field] API.
+    #[inline(always)]
+    fn read(storage: &[u8]) -> Duration {
+        U::to_duration(PrimitiveField::<u64, E, OFFSET_>::read(storage))
+    }
+
+    /// Write the duration field to a given data region, assuming the defined layout, using the [Field] API.
+    /// This panics if the number of ticks doesn't fit into a `u64`.
+    #[inline(always)]
+    fn write(storage: &mut [u8], value: Duration) {
+        let ticks = U::from_duration(value)
+            .expect("Error: Duration is too large to be stored in a u64 number of ticks");
+        PrimitiveField::<u64, E, OFFSET_>::write(storage, ticks)
+    }
+}
+
+impl<U: TimeUnit, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(core::mem::size_of::<u64>());
+}
+
+impl<'a, U: TimeUnit, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, U: TimeUnit, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, U: TimeUnit, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use core::convert::TryInto;
+
+    #[test]
+    fn test_units() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<DurationAs<Seconds>, LittleEndian, 0>;
+        type Field2 = PrimitiveField<DurationAs<Milliseconds>, LittleEndian, 8>;
+        type Field3 = PrimitiveField<DurationAs<Microseconds>, BigEndian, 16>;
+        type Field4 = PrimitiveField<DurationAs<Nanoseconds>, BigEndian, 24>;
+
+        let duration = Duration::new(1_600_000_000, 123_456_789);
+        Field1::write(&mut storage, duration);
+        Field2::write(&mut storage, duration);
+        Field3::write(&mut storage, duration);
+        Field4::write(&mut storage, duration);
+
+        assert_eq!(Duration::from_secs(1_600_000_000), Field1::read(&storage));
+        assert_eq!(
+            Duration::new(1_600_000_000, 123_000_000),
+            Field2::read(&storage)
+        );
+        assert_eq!(
+            Duration::new(1_600_000_000, 123_456_000),
+            Field3::read(&storage)
+        );
+        assert_eq!(duration, Field4::read(&storage));
+
+        assert_eq!(
+            1_600_000_000,
+            u64::from_le_bytes((&storage[0..8]).try_into().unwrap())
+        );
+        assert_eq!(
+            1_600_000_000_123,
+            u64::from_le_bytes((&storage[8..16]).try_into().unwrap())
+        );
+        assert_eq!(
+            1_600_000_000_123_456,
+            u64::from_be_bytes((&storage[16..24]).try_into().unwrap())
+        );
+        assert_eq!(
+            1_600_000_000_123_456_789,
+            u64::from_be_bytes((&storage[24..32]).try_into().unwrap())
+        );
+
+        assert_eq!(Some(8), Field1::SIZE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Duration is too large to be stored in a u64 number of ticks")]
+    fn test_too_large() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<DurationAs<Nanoseconds>, LittleEndian, 0>;
+
+        Field1::write(&mut storage, Duration::from_secs(u64::MAX));
+    }
+}
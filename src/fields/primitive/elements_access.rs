@@ -0,0 +1,336 @@
+use core::marker::PhantomData;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::{write_from_iter, Endianness, FieldCopyAccess, LengthError, PrimitiveField};
+use crate::utils::data::Data;
+use crate::LayoutError;
+
+/// A view over an open ended array of integers or floats, e.g. a field of type `[u16]`, that reads and writes
+/// the elements in the endianness of the layout. This is returned when accessing such a field.
+///
+/// The array covers the storage from the offset of the field to its end. If the remaining number of bytes isn't a multiple
+/// of the element size, the trailing bytes don't belong to any element.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(samples, BigEndian, {
+///   channel: u8,
+///   data: [u16],
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0, 0x01, 0x02, 0x03, 0x04];
+/// let mut view = samples::View::new(&mut storage);
+/// assert_eq!(2, view.data().len());
+/// assert_eq!(Some(0x0102), view.data().get(0));
+/// view.data_mut().set(1, 0xFFFF);
+/// assert_eq!(vec![0x0102, 0xFFFF], view.data().iter().collect::<Vec<u16>>());
+/// # }
+/// ```
+///
+/// # Bulk access
+/// For bulk numeric processing, [copy_into](ElementsView::copy_into) and, with the `std` feature, `to_vec`
+/// decode all elements in one tight loop, independent of the alignment of the storage. If the layout uses [NativeEndian](crate::NativeEndian),
+/// this loop compiles down to plain copies.
+///
+/// Since this crate doesn't use `unsafe` code, it can't reinterpret the storage as a `&[T]` without copying.
+/// If you need that, [as_bytes](ElementsView::as_bytes) returns the bytes covering all elements, which a crate like
+/// [bytemuck](https://docs.rs/bytemuck) can cast to a `&[T]` if the layout uses [NativeEndian](crate::NativeEndian) and the bytes are suitably aligned.
+pub struct ElementsView<S, T, E> {
+    storage: S,
+    _p: PhantomData<(T, E)>,
+}
+
+impl<S: AsRef<[u8]>, T, E: Endianness> ElementsView<S, T, E>
+where
+    PrimitiveField<T, E, 0>: FieldCopyAccess<HighLevelType = T>,
+{
+    const ELEMENT_SIZE: usize = core::mem::size_of::<T>();
+
+    #[inline(always)]
+    fn new(storage: S) -> Self {
+        Self {
+            storage,
+            _p: PhantomData,
+        }
+    }
+
+    /// Return the number of elements
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.storage.as_ref().len() / Self::ELEMENT_SIZE
+    }
+
+    /// Return true if there are no elements
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read the element at `index`, or return `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index < self.len() {
+            let start = index * Self::ELEMENT_SIZE;
+            Some(PrimitiveField::<T, E, 0>::read(
+                &self.storage.as_ref()[start..(start + Self::ELEMENT_SIZE)],
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over all elements
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.storage
+            .as_ref()
+            .chunks_exact(Self::ELEMENT_SIZE)
+            .map(PrimitiveField::<T, E, 0>::read)
+    }
+
+    /// Decode all elements into the beginning of `dst` and return the number of elements,
+    /// or return a [LayoutError::DestinationTooShort] error if `dst` can't hold all elements. The sizes in the error are in bytes.
+    #[inline]
+    pub fn copy_into(&self, dst: &mut [T]) -> Result<usize, LayoutError> {
+        let len = self.len();
+        if dst.len() < len {
+            return Err(LengthError {
+                required: len * Self::ELEMENT_SIZE,
+                actual: dst.len() * Self::ELEMENT_SIZE,
+            }
+            .into());
+        }
+        for (dst, src) in dst
+            .iter_mut()
+            .zip(self.storage.as_ref().chunks_exact(Self::ELEMENT_SIZE))
+        {
+            *dst = PrimitiveField::<T, E, 0>::read(src);
+        }
+        Ok(len)
+    }
+
+    /// Decode all elements into a new [Vec]
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Return the bytes covering all elements, without any trailing bytes that don't belong to an element
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.storage.as_ref()[..(self.len() * Self::ELEMENT_SIZE)]
+    }
+
+    /// This destroys the view and returns the underlying storage back to you.
+    #[inline(always)]
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    /// Consume the view and return an iterator that decodes the elements one by one and owns the storage.
+    /// This is useful to keep the decoded values of an owning view without keeping the view itself around,
+    /// the storage is dropped once the iterator is.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(samples, BigEndian, {
+    ///   channel: u8,
+    ///   data: [u16],
+    /// });
+    ///
+    /// # fn main() {
+    /// let storage = vec![0, 0x01, 0x02, 0x03, 0x04];
+    /// let data: Vec<u16> = samples::View::new(storage).into_data().into_elements().collect();
+    /// assert_eq!(vec![0x0102, 0x0304], data);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn into_elements(self) -> ElementsIntoIter<S, T, E> {
+        ElementsIntoIter {
+            view: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator that owns the storage of an [ElementsView] and decodes its elements one by one.
+/// This is returned by [ElementsView::into_elements].
+pub struct ElementsIntoIter<S, T, E> {
+    view: ElementsView<S, T, E>,
+    index: usize,
+}
+
+impl<S: AsRef<[u8]>, T, E: Endianness> ElementsIntoIter<S, T, E>
+where
+    PrimitiveField<T, E, 0>: FieldCopyAccess<HighLevelType = T>,
+{
+    /// This destroys the iterator and returns the underlying storage of the elements back to you.
+    #[inline(always)]
+    pub fn into_storage(self) -> S {
+        self.view.into_storage()
+    }
+}
+
+impl<S: AsRef<[u8]>, T, E: Endianness> Iterator for ElementsIntoIter<S, T, E>
+where
+    PrimitiveField<T, E, 0>: FieldCopyAccess<HighLevelType = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let element = self.view.get(self.index)?;
+        self.index += 1;
+        Some(element)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.view.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<S: AsRef<[u8]>, T, E: Endianness> ExactSizeIterator for ElementsIntoIter<S, T, E> where
+    PrimitiveField<T, E, 0>: FieldCopyAccess<HighLevelType = T>
+{
+}
+
+impl<S: AsRef<[u8]> + AsMut<[u8]>, T, E: Endianness> ElementsView<S, T, E>
+where
+    PrimitiveField<T, E, 0>: FieldCopyAccess<HighLevelType = T>,
+{
+    /// Write the element at `index`. This panics if `index` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "Error: Index {} is out of bounds for {} elements",
+            index,
+            len
+        );
+        let start = index * Self::ELEMENT_SIZE;
+        PrimitiveField::<T, E, 0>::write(
+            &mut self.storage.as_mut()[start..(start + Self::ELEMENT_SIZE)],
+            value,
+        );
+    }
+
+    /// Write the elements yielded by `iter`, each in the endianness of the layout.
+    /// This returns a [LayoutError::IterLength] error if `iter` doesn't yield exactly [len](ElementsView::len) elements.
+    /// If `iter` yields too few or too many elements, the elements before the error was detected are already written.
+    #[inline]
+    pub fn write_from_iter(
+        &mut self,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<(), LayoutError> {
+        let len = self.len();
+        let storage = self.storage.as_mut();
+        write_from_iter(len, iter, |index, value| {
+            let start = index * Self::ELEMENT_SIZE;
+            PrimitiveField::<T, E, 0>::write(
+                &mut storage[start..(start + Self::ELEMENT_SIZE)],
+                value,
+            )
+        })
+    }
+}
+
+macro_rules! elements_field {
+    ($type: ty) => {
+        /// Field type `[T]` for an integer or float type `T`:
+        /// This field represents an [open ended byte array](crate#open-ended-byte-arrays-u8) holding elements of type `T`.
+        impl<E: Endianness, const OFFSET_: usize> Field for PrimitiveField<[$type], E, OFFSET_> {
+            /// See [Field::Endian]
+            type Endian = E;
+            /// See [Field::OFFSET]
+            const OFFSET: usize = OFFSET_;
+            /// See [Field::SIZE]
+            const SIZE: Option<usize> = None;
+        }
+
+        impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+            for PrimitiveField<[$type], E, OFFSET_>
+        {
+            type View = ElementsView<&'a [u8], $type, E>;
+
+            #[inline(always)]
+            fn view(storage: &'a [u8]) -> Self::View {
+                ElementsView::<_, $type, E>::new(&storage[Self::OFFSET..])
+            }
+        }
+
+        impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+            for PrimitiveField<[$type], E, OFFSET_>
+        {
+            type View = ElementsView<&'a mut [u8], $type, E>;
+
+            #[inline(always)]
+            fn view(storage: &'a mut [u8]) -> Self::View {
+                ElementsView::<_, $type, E>::new(&mut storage[Self::OFFSET..])
+            }
+        }
+
+        impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+            for PrimitiveField<[$type], E, OFFSET_>
+        {
+            type View = ElementsView<Data<S>, $type, E>;
+
+            #[inline(always)]
+            fn into_view(storage: S) -> Self::View {
+                ElementsView::<_, $type, E>::new(Data::from(storage).into_subregion(Self::OFFSET..))
+            }
+        }
+    };
+}
+
+elements_field!(i8);
+elements_field!(i16);
+elements_field!(i32);
+elements_field!(i64);
+elements_field!(i128);
+elements_field!(u16);
+elements_field!(u32);
+elements_field!(u64);
+elements_field!(u128);
+elements_field!(f32);
+elements_field!(f64);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_elements() {
+        let mut storage = vec![0; 9];
+
+        type Field1 = PrimitiveField<[u16], LittleEndian, 2>;
+
+        let mut view = <Field1 as StorageToFieldView<&mut [u8]>>::view(&mut storage);
+        assert_eq!(3, view.len());
+        view.set(0, 0x0102);
+        view.set(2, 0x0304);
+        assert_eq!(&[0x02, 0x01, 0, 0, 0x04, 0x03, 0], &storage[2..]);
+
+        let view = <Field1 as StorageToFieldView<&[u8]>>::view(&storage);
+        assert_eq!(Some(0x0304), view.get(2));
+        assert_eq!(None, view.get(3));
+        assert_eq!(None, Field1::SIZE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Index 2 is out of bounds for 2 elements")]
+    fn test_set_out_of_bounds() {
+        let mut storage = vec![0; 8];
+        type Field1 = PrimitiveField<[f32], BigEndian, 0>;
+        <Field1 as StorageToFieldView<&mut [u8]>>::view(&mut storage).set(2, 1.0);
+    }
+}
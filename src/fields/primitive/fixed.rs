@@ -0,0 +1,224 @@
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::{EndianKind, Endianness};
+
+/// Use this as a field type to store a fixed-point number with `I` integer bits and `F` fraction bits,
+/// e.g. `Fixed<16, 16>` for the Q16.16 format. The number is stored as a signed two's complement integer
+/// of `I + F` bits in the endianness of the layout, and `I` includes the sign bit. `I + F` must be 8, 16, 32 or 64.
+///
+/// Reading the field returns an [f64], which is the stored integer divided by `2^F`. Writing it takes an [f64],
+/// which is multiplied by `2^F` and rounded to the nearest integer. Writing panics if the value is NaN or
+/// doesn't fit into the field.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(sensor_reading, BigEndian, {
+///   temperature: Fixed<16, 16>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 4];
+/// let mut view = sensor_reading::View::new(&mut storage);
+/// view.temperature_mut().write(-1.5);
+/// assert_eq!(-1.5, view.temperature().read());
+/// assert_eq!((-0x1_8000i32).to_be_bytes(), storage);
+/// # }
+/// ```
+pub struct Fixed<const I: usize, const F: usize>;
+
+impl<const I: usize, const F: usize> Fixed<I, F> {
+    const BITS: usize = {
+        assert!(
+            I + F == 8 || I + F == 16 || I + F == 32 || I + F == 64,
+            "Error: Fixed-point fields must have 8, 16, 32 or 64 bits in total"
+        );
+        I + F
+    };
+    const SIZE: usize = Self::BITS / 8;
+    const MIN: i64 = i64::MIN >> (64 - Self::BITS);
+    const MAX: i64 = i64::MAX >> (64 - Self::BITS);
+
+    #[inline(always)]
+    fn scale() -> f64 {
+        (1u128 << F) as f64
+    }
+}
+
+impl<E: Endianness, const I: usize, const F: usize, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = f64;
+
+    /// Read the fixed-point field from a given data region, assuming the defined layout, using the [Field] API.
+    #[inline(always)]
+    fn read(storage: &[u8]) -> f64 {
+        let bytes = &storage[Self::OFFSET..(Self::OFFSET + Fixed::<I, F>::SIZE)];
+        let raw = match E::KIND {
+            EndianKind::Big => bytes.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b)),
+            EndianKind::Little => bytes
+                .iter()
+                .rev()
+                .fold(0u64, |acc, b| (acc << 8) | u64::from(*b)),
+        };
+        // Shift the sign bit of the stored integer into the sign bit of the i64 and back to sign-extend it
+        let shift = 64 - Fixed::<I, F>::BITS;
+        let raw = ((raw << shift) as i64) >> shift;
+        raw as f64 / Fixed::<I, F>::scale()
+    }
+
+    /// Write the fixed-point field to a given data region, assuming the defined layout, using the [Field] API.
+    /// This panics if the value is NaN or doesn't fit into the field.
+    #[inline(always)]
+    fn write(storage: &mut [u8], value: f64) {
+        let scaled = value * Fixed::<I, F>::scale();
+        // Round half away from zero, `f64::round` isn't available in no_std
+        let rounded = if scaled >= 0.0 {
+            scaled + 0.5
+        } else {
+            scaled - 0.5
+        };
+        assert!(
+            rounded > Fixed::<I, F>::MIN as f64 - 1.0 && rounded < Fixed::<I, F>::MAX as f64 + 1.0,
+            "Error: Value {} is out of range for a fixed-point field with {} integer bits and {} fraction bits",
+            value,
+            I,
+            F
+        );
+        // The `as` cast truncates towards zero and saturates at the bounds of i64
+        let raw = (rounded as i64).clamp(Fixed::<I, F>::MIN, Fixed::<I, F>::MAX) as u64;
+        let bytes = &mut storage[Self::OFFSET..(Self::OFFSET + Fixed::<I, F>::SIZE)];
+        let size = bytes.len();
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            let shift = match E::KIND {
+                EndianKind::Big => 8 * (size - 1 - index),
+                EndianKind::Little => 8 * index,
+            };
+            *byte = (raw >> shift) as u8;
+        }
+    }
+}
+
+impl<E: Endianness, const I: usize, const F: usize, const OFFSET_: usize> Field
+    for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(Fixed::<I, F>::SIZE);
+}
+
+impl<'a, E: Endianness, const I: usize, const F: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, E: Endianness, const I: usize, const F: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, E: Endianness, const I: usize, const F: usize, const OFFSET_: usize>
+    StorageIntoFieldView<S> for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use core::convert::TryInto;
+
+    #[test]
+    fn test_q16_16() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<Fixed<16, 16>, LittleEndian, 5>;
+        type Field2 = PrimitiveField<Fixed<16, 16>, BigEndian, 20>;
+
+        assert_eq!(Some(4), Field1::SIZE);
+
+        Field1::write(&mut storage, 3.25);
+        Field2::write(&mut storage, -3.25);
+
+        assert_eq!(3.25, Field1::read(&storage));
+        assert_eq!(-3.25, Field2::read(&storage));
+        assert_eq!(
+            0x3_4000,
+            i32::from_le_bytes((&storage[5..9]).try_into().unwrap())
+        );
+        assert_eq!(
+            -0x3_4000,
+            i32::from_be_bytes((&storage[20..24]).try_into().unwrap())
+        );
+
+        // Values that can't be represented exactly round trip within the resolution of the format
+        Field1::write(&mut storage, core::f64::consts::PI);
+        assert!((core::f64::consts::PI - Field1::read(&storage)).abs() <= 0.5 / 65536.0);
+        assert_eq!(
+            205_887,
+            i32::from_le_bytes((&storage[5..9]).try_into().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_sizes() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<Fixed<4, 4>, BigEndian, 0>;
+        type Field2 = PrimitiveField<Fixed<8, 8>, LittleEndian, 1>;
+        type Field3 = PrimitiveField<Fixed<32, 32>, BigEndian, 3>;
+
+        assert_eq!(Some(1), Field1::SIZE);
+        assert_eq!(Some(2), Field2::SIZE);
+        assert_eq!(Some(8), Field3::SIZE);
+
+        Field1::write(&mut storage, -8.0);
+        Field2::write(&mut storage, 127.99609375);
+        Field3::write(&mut storage, -123456.5);
+
+        assert_eq!(-8.0, Field1::read(&storage));
+        assert_eq!(127.99609375, Field2::read(&storage));
+        assert_eq!(-123456.5, Field3::read(&storage));
+        assert_eq!(&[0x80, 0xFF, 0x7F], &storage[0..3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Error: Value 8 is out of range for a fixed-point field with 4 integer bits and 4 fraction bits"
+    )]
+    fn test_out_of_range() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<Fixed<4, 4>, BigEndian, 0>::write(&mut storage, 8.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Value NaN is out of range")]
+    fn test_nan() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<Fixed<16, 16>, BigEndian, 0>::write(&mut storage, f64::NAN);
+    }
+}
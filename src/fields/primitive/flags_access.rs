@@ -0,0 +1,282 @@
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::PrimitiveField;
+use crate::endianness::Endianness;
+
+/// Use this as a field type to store `N` boolean flags packed into bits, 8 flags per byte.
+/// Flag `i` is stored in bit `i % 8` (counted from the least significant bit) of byte `i / 8`.
+/// The field takes `N / 8` bytes, rounded up, and unused bits in the last byte are left untouched.
+///
+/// Use `get(i)` and `set(i, value)` to access the flags. To store booleans as one byte each, use `[bool; N]` instead.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   flags: BitFlags<10>,
+///   other_field: u8,
+/// });
+///
+/// # fn main() {
+/// assert_eq!(Some(2), my_layout::flags::SIZE);
+///
+/// let mut storage = [0; 3];
+/// let mut view = my_layout::View::new(&mut storage);
+/// view.flags_mut().set(9, true);
+/// assert!(view.flags().get(9));
+/// assert!(!view.flags().get(8));
+/// assert_eq!([0, 0b10, 0], storage);
+/// # }
+/// ```
+pub struct BitFlags<const N: usize>;
+
+impl<const N: usize> BitFlags<N> {
+    const SIZE: usize = N.div_ceil(8);
+}
+
+/// Panics with a helpful message if the flag index is out of bounds
+#[inline(always)]
+fn check_index<const N: usize>(index: usize) {
+    assert!(
+        index < N,
+        "Error: Flag index {} is out of bounds for {} flags",
+        index,
+        N
+    );
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> Field
+    for PrimitiveField<BitFlags<N>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(BitFlags::<N>::SIZE);
+}
+
+/// Field type [BitFlags]:
+/// This field represents `N` flags packed into bits. In this impl, we define accessors for single flags.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> PrimitiveField<BitFlags<N>, E, OFFSET_> {
+    /// Read the flag with the given index using the [Field] API. This panics if `index >= N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_flags: BitFlags<10>,
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///     let flag: bool = my_layout::some_flags::get(storage_data, 9);
+    /// }
+    ///
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    pub fn get(storage: &[u8], index: usize) -> bool {
+        check_index::<N>(index);
+        storage[Self::OFFSET + index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Write the flag with the given index using the [Field] API. This panics if `index >= N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_flags: BitFlags<10>,
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///     my_layout::some_flags::set(storage_data, 9, true);
+    /// }
+    ///
+    /// # fn main() {}
+    /// ```
+    #[inline(always)]
+    pub fn set(storage: &mut [u8], index: usize, value: bool) {
+        check_index::<N>(index);
+        let byte = &mut storage[Self::OFFSET + index / 8];
+        if value {
+            *byte |= 1 << (index % 8);
+        } else {
+            *byte &= !(1 << (index % 8));
+        }
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> Field
+    for PrimitiveField<[bool; N], E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(N);
+    #[doc(hidden)]
+    const IS_ZERO_LENGTH_ARRAY: bool = N == 0;
+}
+
+/// Field type `[bool; N]`:
+/// This field represents `N` flags stored as one byte each. In this impl, we define accessors for single flags.
+/// Any non-zero byte is read as `true`, and `true` is written as `1`.
+impl<E: Endianness, const N: usize, const OFFSET_: usize> PrimitiveField<[bool; N], E, OFFSET_> {
+    /// Read the flag with the given index using the [Field] API. This panics if `index >= N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_flags: [bool; 4],
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///     let flag: bool = my_layout::some_flags::get(storage_data, 3);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn get(storage: &[u8], index: usize) -> bool {
+        check_index::<N>(index);
+        storage[Self::OFFSET + index] != 0
+    }
+
+    /// Write the flag with the given index using the [Field] API. This panics if `index >= N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_flags: [bool; 4],
+    ///     //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///     my_layout::some_flags::set(storage_data, 3, true);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn set(storage: &mut [u8], index: usize, value: bool) {
+        check_index::<N>(index);
+        storage[Self::OFFSET + index] = u8::from(value);
+    }
+}
+
+macro_rules! impl_flags_views {
+    ($type: ty) => {
+        impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            type View = FieldView<&'a [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<'a, E: Endianness, const N: usize, const OFFSET_: usize>
+            StorageToFieldView<&'a mut [u8]> for PrimitiveField<$type, E, OFFSET_>
+        {
+            type View = FieldView<&'a mut [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a mut [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<S: AsRef<[u8]>, E: Endianness, const N: usize, const OFFSET_: usize>
+            StorageIntoFieldView<S> for PrimitiveField<$type, E, OFFSET_>
+        {
+            type View = FieldView<S, Self>;
+
+            #[inline(always)]
+            fn into_view(storage: S) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+    };
+}
+
+impl_flags_views!(BitFlags<N>);
+impl_flags_views!([bool; N]);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bitflags() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<BitFlags<10>, LittleEndian, 5>;
+
+        assert_eq!(Some(2), Field1::SIZE);
+        assert_eq!(
+            Some(1),
+            PrimitiveField::<BitFlags<8>, LittleEndian, 5>::SIZE
+        );
+        assert_eq!(
+            Some(0),
+            PrimitiveField::<BitFlags<0>, LittleEndian, 5>::SIZE
+        );
+
+        Field1::set(&mut storage, 0, true);
+        Field1::set(&mut storage, 7, true);
+        Field1::set(&mut storage, 9, true);
+        assert_eq!(&[0, 0b1000_0001, 0b10, 0], &storage[4..8]);
+
+        for index in 0..10 {
+            assert_eq!(
+                index == 0 || index == 7 || index == 9,
+                Field1::get(&storage, index)
+            );
+        }
+
+        Field1::set(&mut storage, 7, false);
+        assert_eq!(&[0, 0b1, 0b10, 0], &storage[4..8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Flag index 10 is out of bounds for 10 flags")]
+    fn test_bitflags_out_of_bounds() {
+        let storage = vec![0xFF; 1024];
+        PrimitiveField::<BitFlags<10>, LittleEndian, 5>::get(&storage, 10);
+    }
+
+    #[test]
+    fn test_bool_array() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<[bool; 3], BigEndian, 5>;
+
+        assert_eq!(Some(3), Field1::SIZE);
+
+        Field1::set(&mut storage, 1, true);
+        assert_eq!(&[0, 0, 1, 0, 0], &storage[4..9]);
+        assert!(!Field1::get(&storage, 0));
+        assert!(Field1::get(&storage, 1));
+
+        storage[7] = 5;
+        assert!(Field1::get(&storage, 2));
+
+        Field1::set(&mut storage, 1, false);
+        assert_eq!(&[0, 0, 0, 5, 0], &storage[4..9]);
+    }
+}
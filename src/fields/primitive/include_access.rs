@@ -0,0 +1,92 @@
+use core::marker::PhantomData;
+
+use super::super::{StorageIntoFieldView, StorageToFieldView};
+use super::{Endianness, FieldCopyAccess, FieldSliceAccess, PrimitiveField};
+use crate::Field;
+
+// Including works by having the define_layout! macro re-declare each field of the included layout
+// in the including layout, with [Included] wrapping the field of the included layout as its type.
+// The code in this module then forwards all accessors to the field of the included layout.
+// Since the included layout is always at the start of the including layout, the offsets are the same.
+
+/// Internal type. Don't use this in user code.
+/// Marker type for a field that was taken over from an included layout.
+pub struct Included<F: Field> {
+    _p: PhantomData<F>,
+}
+
+impl<F: Field, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = F::Endian;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = F::OFFSET;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = F::SIZE;
+
+    #[inline(always)]
+    fn byte_range(storage_len: usize) -> core::ops::Range<usize> {
+        F::byte_range(storage_len)
+    }
+}
+
+impl<F: FieldCopyAccess, E: Endianness, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = F::HighLevelType;
+
+    /// See [FieldCopyAccess::read]
+    #[inline(always)]
+    fn read(storage: &[u8]) -> Self::HighLevelType {
+        F::read(storage)
+    }
+
+    /// See [FieldCopyAccess::write]
+    #[inline(always)]
+    fn write(storage: &mut [u8], v: Self::HighLevelType) {
+        F::write(storage, v)
+    }
+}
+
+impl<'a, F: FieldSliceAccess<'a>, E: Endianness, const OFFSET_: usize> FieldSliceAccess<'a>
+    for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    type SliceType = F::SliceType;
+    type MutSliceType = F::MutSliceType;
+
+    /// See [FieldSliceAccess::data]
+    #[inline(always)]
+    fn data(storage: &'a [u8]) -> Self::SliceType {
+        F::data(storage)
+    }
+
+    /// See [FieldSliceAccess::data_mut]
+    #[inline(always)]
+    fn data_mut(storage: &'a mut [u8]) -> Self::MutSliceType {
+        F::data_mut(storage)
+    }
+}
+
+impl<S, F: Field + StorageToFieldView<S>, E: Endianness, const OFFSET_: usize> StorageToFieldView<S>
+    for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    type View = F::View;
+
+    #[inline(always)]
+    fn view(storage: S) -> Self::View {
+        F::view(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, F: Field + StorageIntoFieldView<S>, E: Endianness, const OFFSET_: usize>
+    StorageIntoFieldView<S> for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    type View = F::View;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        F::into_view(storage)
+    }
+}
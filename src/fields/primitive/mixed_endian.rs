@@ -0,0 +1,130 @@
+use core::marker::PhantomData;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+/// Use this as a field type to store a 32-bit integer in the mixed ("middle") endian byte order of the PDP-11.
+/// The value is split into two 16-bit words that are stored with the most significant word first, but the bytes
+/// within each word are little endian. For example, `0x0A0B0C0D` is stored as `[0x0B, 0x0A, 0x0D, 0x0C]`.
+/// `U` is the integer type the field is read and written as, i.e. `u32` or `i32`.
+///
+/// The byte order is fixed and doesn't depend on the endianness of the layout.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(pdp_record, LittleEndian, {
+///   block_number: MixedEndian<u32>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 4];
+/// let mut view = pdp_record::View::new(&mut storage);
+/// view.block_number_mut().write(0x0A0B_0C0D);
+/// assert_eq!(0x0A0B_0C0D, view.block_number().read());
+/// assert_eq!([0x0B, 0x0A, 0x0D, 0x0C], storage);
+/// # }
+/// ```
+pub struct MixedEndian<U> {
+    _p: PhantomData<U>,
+}
+
+macro_rules! impl_mixed_endian {
+    ($type: ty) => {
+        impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess
+            for PrimitiveField<MixedEndian<$type>, E, OFFSET_>
+        {
+            /// See [FieldCopyAccess::HighLevelType]
+            type HighLevelType = $type;
+
+            /// Read the mixed endian field from a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn read(storage: &[u8]) -> $type {
+                let bytes = &storage[OFFSET_..(OFFSET_ + 4)];
+                u32::from_be_bytes([bytes[1], bytes[0], bytes[3], bytes[2]]) as $type
+            }
+
+            /// Write the mixed endian field to a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn write(storage: &mut [u8], value: $type) {
+                let [b0, b1, b2, b3] = (value as u32).to_be_bytes();
+                storage[OFFSET_..(OFFSET_ + 4)].copy_from_slice(&[b1, b0, b3, b2]);
+            }
+        }
+
+        impl<E: Endianness, const OFFSET_: usize> Field
+            for PrimitiveField<MixedEndian<$type>, E, OFFSET_>
+        {
+            /// See [Field::Endian]
+            type Endian = E;
+            /// See [Field::OFFSET]
+            const OFFSET: usize = OFFSET_;
+            /// See [Field::SIZE]
+            const SIZE: Option<usize> = Some(4);
+        }
+
+        impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+            for PrimitiveField<MixedEndian<$type>, E, OFFSET_>
+        {
+            type View = FieldView<&'a [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+            for PrimitiveField<MixedEndian<$type>, E, OFFSET_>
+        {
+            type View = FieldView<&'a mut [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a mut [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+            for PrimitiveField<MixedEndian<$type>, E, OFFSET_>
+        {
+            type View = FieldView<S, Self>;
+
+            #[inline(always)]
+            fn into_view(storage: S) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+    };
+}
+
+impl_mixed_endian!(u32);
+impl_mixed_endian!(i32);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_mixed_endian() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<MixedEndian<u32>, BigEndian, 5>;
+        type Field2 = PrimitiveField<MixedEndian<i32>, LittleEndian, 20>;
+
+        Field1::write(&mut storage, 0x0102_0304);
+        Field2::write(&mut storage, -2);
+
+        assert_eq!(0x0102_0304, Field1::read(&storage));
+        assert_eq!(-2, Field2::read(&storage));
+        assert_eq!(&[0x02, 0x01, 0x04, 0x03], &storage[5..9]);
+        assert_eq!(&[0xFF, 0xFF, 0xFE, 0xFF], &storage[20..24]);
+
+        assert_eq!(Some(4), Field1::SIZE);
+        assert_eq!(Some(4), Field2::SIZE);
+    }
+}
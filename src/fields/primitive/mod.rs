@@ -2,14 +2,57 @@ use core::marker::PhantomData;
 
 use crate::endianness::Endianness;
 
+mod align;
+mod bitfield_access;
+#[cfg(feature = "bitflags")]
+mod bitflags_access;
+mod bounded_access;
+mod char_access;
 mod copy_access;
+#[cfg(feature = "time")]
+mod duration;
+mod elements_access;
+mod fixed;
+mod flags_access;
+mod include_access;
+mod mixed_endian;
+mod nan_boxed;
 mod nested_access;
+mod repeated_access;
+mod scaled;
+#[cfg(feature = "serde")]
+mod serde_codec;
+mod signed_encoding;
 mod slice_access;
+mod str_access;
+mod trailer_access;
 mod view;
 
-pub use copy_access::FieldCopyAccess;
+pub use align::AlignTo;
+pub use bitfield_access::{BitFields, BitOrder, LsbFirst, MsbFirst};
+#[cfg(feature = "bitflags")]
+pub use bitflags_access::{FlagsAs, UnknownFlags};
+pub use bounded_access::BoundedBytes;
+pub use char_access::InvalidChar;
+pub use copy_access::{FieldCopyAccess, RangeError, WideInt};
+#[cfg(feature = "time")]
+pub use duration::{DurationAs, Microseconds, Milliseconds, Nanoseconds, Seconds, TimeUnit};
+pub use elements_access::{ElementsIntoIter, ElementsView};
+pub use fixed::Fixed;
+pub use flags_access::BitFlags;
+pub use include_access::Included;
+pub use mixed_endian::MixedEndian;
+pub use nan_boxed::{DefaultNanBoxing, NanBoxed, NanBoxedValue, NanBoxing};
 pub use nested_access::{BorrowingNestedView, NestedViewInfo, OwningNestedView};
-pub use slice_access::FieldSliceAccess;
+pub use repeated_access::{RepeatedLayout, RepeatedView};
+pub use scaled::Scaled;
+#[cfg(feature = "serde")]
+pub use serde_codec::SerdeCodec;
+pub use signed_encoding::{ExcessK, OnesComplement, SignMagnitude};
+pub(crate) use slice_access::{copy_into, count_ones, write_from_iter};
+pub use slice_access::{FieldSliceAccess, IterLengthError, LengthError, TooShort};
+pub use str_access::BytesAsStr;
+pub use trailer_access::{FromEnd, Trailed};
 pub use view::FieldView;
 
 /// A [PrimitiveField] is a [Field](crate::Field) that directly represents a primitive type like [u8], [i16], ...
@@ -0,0 +1,293 @@
+use core::marker::PhantomData;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+/// A NaN-boxing scheme used by [NanBoxed] fields to define how the stored `u64` is classified into a tagged value.
+/// NaN-boxing stores values that aren't floats in the payload bits of NaNs, so a single `u64` can hold either a float
+/// or a tagged value. The exact tags differ between formats, so implement this trait for the convention of your format
+/// or use [DefaultNanBoxing].
+pub trait NanBoxing {
+    /// The tagged value a stored `u64` is classified into
+    type Value;
+
+    /// Classify the bits of a stored `u64` into a tagged value
+    fn unbox(bits: u64) -> Self::Value;
+
+    /// Encode a tagged value into the bits of a `u64`
+    fn box_value(value: Self::Value) -> u64;
+}
+
+/// A tagged value as classified by [DefaultNanBoxing]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NanBoxedValue {
+    /// A float, which is stored as is
+    Float(f64),
+    /// An integer, which is stored in the lower 32 bits of the payload
+    Int(i32),
+    /// A pointer, which is stored in the 48 bit payload
+    Pointer(u64),
+}
+
+/// The [NanBoxing] scheme used by [NanBoxed] fields unless specified otherwise.
+///
+/// Values that aren't floats are stored as negative quiet NaNs, i.e. with the upper 13 bits set. Bits 48 to 50 hold the tag,
+/// which is `1` for [NanBoxedValue::Int] and `2` for [NanBoxedValue::Pointer], and the lower 48 bits hold the payload.
+/// All other bit patterns are floats. Floats that are NaN are written as the canonical [f64::NAN], so they are never mistaken for a tagged value.
+pub struct DefaultNanBoxing;
+
+impl DefaultNanBoxing {
+    const BOX_MASK: u64 = 0xFFF8_0000_0000_0000;
+    const TAG_SHIFT: u32 = 48;
+    const TAG_MASK: u64 = 0x7;
+    const PAYLOAD_MASK: u64 = 0x0000_FFFF_FFFF_FFFF;
+    const TAG_INT: u64 = 1;
+    const TAG_POINTER: u64 = 2;
+
+    #[inline(always)]
+    const fn boxed(tag: u64, payload: u64) -> u64 {
+        Self::BOX_MASK | (tag << Self::TAG_SHIFT) | payload
+    }
+}
+
+impl NanBoxing for DefaultNanBoxing {
+    type Value = NanBoxedValue;
+
+    #[inline(always)]
+    fn unbox(bits: u64) -> NanBoxedValue {
+        if bits & Self::BOX_MASK != Self::BOX_MASK {
+            return NanBoxedValue::Float(f64::from_bits(bits));
+        }
+        match (bits >> Self::TAG_SHIFT) & Self::TAG_MASK {
+            Self::TAG_INT => NanBoxedValue::Int(bits as u32 as i32),
+            Self::TAG_POINTER => NanBoxedValue::Pointer(bits & Self::PAYLOAD_MASK),
+            _ => NanBoxedValue::Float(f64::from_bits(bits)),
+        }
+    }
+
+    /// This panics if a pointer doesn't fit into the 48 bit payload.
+    #[inline(always)]
+    fn box_value(value: NanBoxedValue) -> u64 {
+        match value {
+            NanBoxedValue::Float(value) if value.is_nan() => f64::NAN.to_bits(),
+            NanBoxedValue::Float(value) => value.to_bits(),
+            NanBoxedValue::Int(value) => Self::boxed(Self::TAG_INT, value as u32 as u64),
+            NanBoxedValue::Pointer(value) => {
+                assert!(
+                    value <= Self::PAYLOAD_MASK,
+                    "Error: Pointer {:#x} doesn't fit into the 48 bit payload of a NaN-boxed value",
+                    value
+                );
+                Self::boxed(Self::TAG_POINTER, value)
+            }
+        }
+    }
+}
+
+/// Use this as a field type to store a NaN-boxed value in a `u64` in the endianness of the layout, e.g. for the tagged
+/// values of a scripting VM. The type parameter defines how the `u64` is classified into a tagged value, see [NanBoxing].
+/// It defaults to [DefaultNanBoxing], which distinguishes floats, integers and pointers.
+///
+/// Reading the field returns the tagged value and writing it takes a tagged value.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::NanBoxedValue;
+///
+/// define_layout!(vm_slot, LittleEndian, {
+///   value: NanBoxed,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 8];
+/// let mut view = vm_slot::View::new(&mut storage);
+/// view.value_mut().write(NanBoxedValue::Int(-5));
+/// assert_eq!(NanBoxedValue::Int(-5), view.value().read());
+/// view.value_mut().write(NanBoxedValue::Float(1.5));
+/// assert_eq!(NanBoxedValue::Float(1.5), view.value().read());
+/// assert_eq!(1.5f64.to_le_bytes(), storage);
+/// # }
+/// ```
+pub struct NanBoxed<C: NanBoxing = DefaultNanBoxing> {
+    _p: PhantomData<C>,
+}
+
+impl<C: NanBoxing, E: Endianness, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = C::Value;
+
+    /// Read the NaN-boxed field from a given data region, assuming the defined layout, using the [Field] API.
+    #[inline(always)]
+    fn read(storage: &[u8]) -> C::Value {
+        C::unbox(PrimitiveField::<u64, E, OFFSET_>::read(storage))
+    }
+
+    /// Write the NaN-boxed field to a given data region, assuming the defined layout, using the [Field] API.
+    #[inline(always)]
+    fn write(storage: &mut [u8], value: C::Value) {
+        PrimitiveField::<u64, E, OFFSET_>::write(storage, C::box_value(value))
+    }
+}
+
+impl<C: NanBoxing, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(core::mem::size_of::<u64>());
+}
+
+impl<'a, C: NanBoxing, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, C: NanBoxing, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, C: NanBoxing, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use core::convert::TryInto;
+
+    #[test]
+    fn test_int_vs_float() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<NanBoxed, LittleEndian, 0>;
+        type Field2 = PrimitiveField<NanBoxed, BigEndian, 8>;
+
+        assert_eq!(Some(8), Field1::SIZE);
+
+        Field1::write(&mut storage, NanBoxedValue::Int(-2));
+        Field2::write(&mut storage, NanBoxedValue::Float(-2.0));
+        assert_eq!(NanBoxedValue::Int(-2), Field1::read(&storage));
+        assert_eq!(NanBoxedValue::Float(-2.0), Field2::read(&storage));
+        assert_eq!(
+            0xFFF9_0000_FFFF_FFFE,
+            u64::from_le_bytes((&storage[0..8]).try_into().unwrap())
+        );
+        assert_eq!(
+            (-2.0f64).to_bits(),
+            u64::from_be_bytes((&storage[8..16]).try_into().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_pointer() {
+        let mut storage = vec![0; 1024];
+        type Field1 = PrimitiveField<NanBoxed, BigEndian, 0>;
+
+        Field1::write(&mut storage, NanBoxedValue::Pointer(0x7FFF_1234_5678));
+        assert_eq!(
+            NanBoxedValue::Pointer(0x7FFF_1234_5678),
+            Field1::read(&storage)
+        );
+        assert_eq!(
+            0xFFFA_7FFF_1234_5678,
+            u64::from_be_bytes((&storage[0..8]).try_into().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_nan_float_is_canonicalized() {
+        let mut storage = vec![0; 1024];
+        type Field1 = PrimitiveField<NanBoxed, LittleEndian, 0>;
+
+        // A NaN with the bits of a boxed integer is written as the canonical NaN
+        Field1::write(
+            &mut storage,
+            NanBoxedValue::Float(f64::from_bits(0xFFF9_0000_0000_0001)),
+        );
+        match Field1::read(&storage) {
+            NanBoxedValue::Float(value) => assert!(value.is_nan()),
+            value => panic!("Expected a float, got {:?}", value),
+        }
+
+        // Negative infinity and NaNs with an unknown tag are floats
+        Field1::write(&mut storage, NanBoxedValue::Float(f64::NEG_INFINITY));
+        assert_eq!(
+            NanBoxedValue::Float(f64::NEG_INFINITY),
+            Field1::read(&storage)
+        );
+        storage[0..8].copy_from_slice(&0xFFFF_0000_0000_0000u64.to_le_bytes());
+        assert!(matches!(Field1::read(&storage), NanBoxedValue::Float(value) if value.is_nan()));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Error: Pointer 0x1000000000000 doesn't fit into the 48 bit payload of a NaN-boxed value"
+    )]
+    fn test_pointer_too_large() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<NanBoxed, BigEndian, 0>::write(
+            &mut storage,
+            NanBoxedValue::Pointer(0x1_0000_0000_0000),
+        );
+    }
+
+    struct Tagged;
+    impl NanBoxing for Tagged {
+        type Value = Result<f64, u32>;
+
+        fn unbox(bits: u64) -> Self::Value {
+            if bits >> 32 == 0x7FF8_0001 {
+                Err(bits as u32)
+            } else {
+                Ok(f64::from_bits(bits))
+            }
+        }
+
+        fn box_value(value: Self::Value) -> u64 {
+            match value {
+                Ok(value) => value.to_bits(),
+                Err(value) => 0x7FF8_0001_0000_0000 | value as u64,
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_scheme() {
+        let mut storage = vec![0; 1024];
+        type Field1 = PrimitiveField<NanBoxed<Tagged>, LittleEndian, 0>;
+
+        Field1::write(&mut storage, Err(7));
+        assert_eq!(Err(7), Field1::read(&storage));
+        Field1::write(&mut storage, Ok(0.25));
+        assert_eq!(Ok(0.25), Field1::read(&storage));
+    }
+}
@@ -9,7 +9,13 @@ use crate::Field;
 // and [StorageIntoFieldView] for it so that it can be used as a field in other layouts.
 
 /// Internal type. Don't use this in user code.
-/// S is expected to be a non-reference type that can own things, e.g. Data<S>
+/// S is expected to be a non-reference type that can own things, e.g. `Data<S>`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a supported field type for `define_layout!`",
+    label = "unsupported field type",
+    note = "supported field types are integers, floats, `char`, `()`, byte arrays like `[u8; N]` or `[u8]`, `[bool; N]`, the other field types of this crate and the `NestedView` of another layout",
+    note = "to use a custom type, wrap a supported type with `{Self} as <supported type>` and implement `LayoutAs` for it"
+)]
 pub trait OwningNestedView<S>
 where
     S: AsRef<[u8]>,
@@ -23,6 +29,12 @@ where
 
 /// Internal type. Don't use this in user code.
 /// S is expected to be a reference type, e.g. &[u8] or &mut [u8]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a supported field type for `define_layout!`",
+    label = "unsupported field type",
+    note = "supported field types are integers, floats, `char`, `()`, byte arrays like `[u8; N]` or `[u8]`, `[bool; N]`, the other field types of this crate and the `NestedView` of another layout",
+    note = "to use a custom type, wrap a supported type with `{Self} as <supported type>` and implement `LayoutAs` for it"
+)]
 pub trait BorrowingNestedView<S> {
     /// A type representing a borrowing view of the nested field.
     type View;
@@ -32,6 +44,12 @@ pub trait BorrowingNestedView<S> {
 }
 
 /// Internal trait. Don't use this in user code.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a supported field type for `define_layout!`",
+    label = "unsupported field type",
+    note = "supported field types are integers, floats, `char`, `()`, byte arrays like `[u8; N]` or `[u8]`, `[bool; N]`, the other field types of this crate and the `NestedView` of another layout",
+    note = "to use a custom type, wrap a supported type with `{Self} as <supported type>` and implement `LayoutAs` for it"
+)]
 pub trait NestedViewInfo {
     /// Size of the nested field
     const SIZE: Option<usize>;
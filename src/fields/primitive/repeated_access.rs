@@ -0,0 +1,343 @@
+use core::convert::TryInto;
+use core::marker::PhantomData;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::{BorrowingNestedView, Endianness, FieldCopyAccess, NestedViewInfo, PrimitiveField};
+use crate::macro_define_layout::unwrap_field_size;
+use crate::utils::data::Data;
+
+/// Use this as a field type to store a number of repetitions of a nested layout that is given by another field of the layout.
+/// `N` is the `NestedView` marker of the repeated layout and `C` is the field holding the number of repetitions,
+/// which has to be declared before this field. The repeated layout must have a static size.
+///
+/// Since its size depends on the storage, this field can only be used at the end of a layout.
+/// For a number of repetitions that is known at compile time, use `[N; COUNT]` as field type instead,
+/// which can be used anywhere in a layout. To repeat the nested layout until the end of the storage, use `[N]`.
+///
+/// Accessing the field returns a [RepeatedView] that gives access to a `View` for each repetition.
+/// Accessing it panics if the storage is too small to hold the number of repetitions given by the count field.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(entry, LittleEndian, {
+///   id: u16,
+///   value: u32,
+/// });
+///
+/// define_layout!(table, LittleEndian, {
+///   count: u8,
+///   fixed_entries: [entry::NestedView; 2],
+///   entries: RepeatedLayout<entry::NestedView, count>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = vec![0; 1 + 2 * 6 + 3 * 6];
+/// let mut view = table::View::new(&mut storage);
+/// view.count_mut().write(3);
+/// view.entries_mut().get_mut(2).unwrap().value_mut().write(10);
+/// assert_eq!(10, view.entries().get(2).unwrap().value().read());
+/// assert!(view.entries().get(3).is_none());
+/// assert_eq!(2, view.fixed_entries().len());
+/// # }
+/// ```
+pub struct RepeatedLayout<N, C> {
+    _p: PhantomData<(N, C)>,
+}
+
+/// A view over a number of consecutive repetitions of a nested layout. This is returned when accessing
+/// a field of type [RepeatedLayout], `[N; COUNT]` or `[N]` where `N` is the `NestedView` marker of a layout.
+pub struct RepeatedView<S, N> {
+    storage: S,
+    len: usize,
+    _p: PhantomData<N>,
+}
+
+impl<S: AsRef<[u8]>, N: NestedViewInfo> RepeatedView<S, N> {
+    const ELEMENT_SIZE: usize = unwrap_field_size(N::SIZE);
+
+    #[inline(always)]
+    fn new(storage: S, len: usize) -> Self {
+        Self {
+            storage,
+            len,
+            _p: PhantomData,
+        }
+    }
+
+    /// Return the number of repetitions
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return true if there are no repetitions
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return a view with read access to the repetition at `index`, or `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get<'a>(&'a self, index: usize) -> Option<<N as BorrowingNestedView<&'a [u8]>>::View>
+    where
+        N: BorrowingNestedView<&'a [u8]>,
+    {
+        if index < self.len {
+            let start = index * Self::ELEMENT_SIZE;
+            Some(N::view(
+                &self.storage.as_ref()[start..(start + Self::ELEMENT_SIZE)],
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over views with read access to all repetitions
+    #[inline]
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = <N as BorrowingNestedView<&'a [u8]>>::View>
+    where
+        N: BorrowingNestedView<&'a [u8]>,
+    {
+        self.storage.as_ref()[..(self.len * Self::ELEMENT_SIZE)]
+            .chunks_exact(Self::ELEMENT_SIZE)
+            .map(N::view)
+    }
+
+    /// This destroys the view and returns the underlying storage back to you.
+    #[inline(always)]
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+}
+
+impl<S: AsRef<[u8]> + AsMut<[u8]>, N: NestedViewInfo> RepeatedView<S, N> {
+    /// Return a view with write access to the repetition at `index`, or `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get_mut<'a>(
+        &'a mut self,
+        index: usize,
+    ) -> Option<<N as BorrowingNestedView<&'a mut [u8]>>::View>
+    where
+        N: BorrowingNestedView<&'a mut [u8]>,
+    {
+        if index < self.len {
+            let start = index * Self::ELEMENT_SIZE;
+            Some(N::view(
+                &mut self.storage.as_mut()[start..(start + Self::ELEMENT_SIZE)],
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Field type `[N; COUNT]` where `N` is the `NestedView` marker of a layout:
+/// This field represents `COUNT` consecutive repetitions of the nested layout.
+impl<N: NestedViewInfo, E: Endianness, const COUNT: usize, const OFFSET_: usize> Field
+    for PrimitiveField<[N; COUNT], E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = Some(unwrap_field_size(N::SIZE) * COUNT);
+    #[doc(hidden)]
+    const IS_ZERO_LENGTH_ARRAY: bool = COUNT == 0;
+}
+
+impl<'a, N: NestedViewInfo, E: Endianness, const COUNT: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<[N; COUNT], E, OFFSET_>
+{
+    type View = RepeatedView<&'a [u8], N>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        let size = unwrap_field_size(Self::SIZE);
+        RepeatedView::new(&storage[Self::OFFSET..(Self::OFFSET + size)], COUNT)
+    }
+}
+
+impl<'a, N: NestedViewInfo, E: Endianness, const COUNT: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<[N; COUNT], E, OFFSET_>
+{
+    type View = RepeatedView<&'a mut [u8], N>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        let size = unwrap_field_size(Self::SIZE);
+        RepeatedView::new(&mut storage[Self::OFFSET..(Self::OFFSET + size)], COUNT)
+    }
+}
+
+impl<
+        S: AsRef<[u8]>,
+        N: NestedViewInfo,
+        E: Endianness,
+        const COUNT: usize,
+        const OFFSET_: usize,
+    > StorageIntoFieldView<S> for PrimitiveField<[N; COUNT], E, OFFSET_>
+{
+    type View = RepeatedView<Data<S>, N>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        let size = unwrap_field_size(Self::SIZE);
+        RepeatedView::new(
+            Data::from(storage).into_subregion(Self::OFFSET..(Self::OFFSET + size)),
+            COUNT,
+        )
+    }
+}
+
+impl<N: NestedViewInfo, E: Endianness, const OFFSET_: usize> PrimitiveField<[N], E, OFFSET_> {
+    /// Return the number of whole repetitions between the offset of the field and the end of the storage,
+    /// and the region of the storage covering them. Trailing bytes that don't make up a whole repetition aren't part of the region.
+    #[inline(always)]
+    pub(crate) fn region(storage_len: usize) -> (usize, core::ops::Range<usize>) {
+        let size = unwrap_field_size(N::SIZE);
+        assert!(
+            size > 0,
+            "Error: Layouts with a size of zero can't be repeated until the end of the storage"
+        );
+        let len = storage_len.saturating_sub(Self::OFFSET) / size;
+        (len, Self::OFFSET..(Self::OFFSET + len * size))
+    }
+}
+
+/// Field type `[N]` where `N` is the `NestedView` marker of a layout:
+/// This field represents as many repetitions of the nested layout as fit into the storage until its end.
+/// Trailing bytes that don't make up a whole repetition are ignored.
+/// Like other open ended fields, it can only be used at the end of a layout.
+impl<N: NestedViewInfo, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<[N], E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = None;
+}
+
+impl<'a, N: NestedViewInfo, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+    for PrimitiveField<[N], E, OFFSET_>
+{
+    type View = RepeatedView<&'a [u8], N>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        let (len, region) = Self::region(storage.len());
+        RepeatedView::new(&storage[region], len)
+    }
+}
+
+impl<'a, N: NestedViewInfo, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+    for PrimitiveField<[N], E, OFFSET_>
+{
+    type View = RepeatedView<&'a mut [u8], N>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        let (len, region) = Self::region(storage.len());
+        RepeatedView::new(&mut storage[region], len)
+    }
+}
+
+impl<S: AsRef<[u8]>, N: NestedViewInfo, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+    for PrimitiveField<[N], E, OFFSET_>
+{
+    type View = RepeatedView<Data<S>, N>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        let (len, region) = Self::region(storage.as_ref().len());
+        RepeatedView::new(Data::from(storage).into_subregion(region), len)
+    }
+}
+
+impl<N: NestedViewInfo, C: FieldCopyAccess, E: Endianness, const OFFSET_: usize>
+    PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+where
+    C::HighLevelType: TryInto<usize>,
+{
+    /// Read the number of repetitions from the count field and return the region of the storage covering all of them.
+    /// This panics if the storage is too small to hold that many repetitions.
+    #[inline(always)]
+    pub(crate) fn region(storage: &[u8]) -> (usize, core::ops::Range<usize>) {
+        let len = C::read(storage)
+            .try_into()
+            .unwrap_or_else(|_| panic!("Error: The number of repetitions doesn't fit into usize"));
+        let end = Self::OFFSET + len * unwrap_field_size(N::SIZE);
+        assert!(
+            end <= storage.len(),
+            "Error: Storage too small for {} repetitions: required {} bytes but only got {}",
+            len,
+            end,
+            storage.len()
+        );
+        (len, Self::OFFSET..end)
+    }
+}
+
+/// Field type [RepeatedLayout]:
+/// This field represents a number of repetitions of a nested layout, given by the count field.
+impl<N, C, E: Endianness, const OFFSET_: usize> Field
+    for PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = None;
+}
+
+impl<'a, N: NestedViewInfo, C: FieldCopyAccess, E: Endianness, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+where
+    C::HighLevelType: TryInto<usize>,
+{
+    type View = RepeatedView<&'a [u8], N>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        let (len, region) = Self::region(storage);
+        RepeatedView::new(&storage[region], len)
+    }
+}
+
+impl<'a, N: NestedViewInfo, C: FieldCopyAccess, E: Endianness, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+where
+    C::HighLevelType: TryInto<usize>,
+{
+    type View = RepeatedView<&'a mut [u8], N>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        let (len, region) = Self::region(storage);
+        RepeatedView::new(&mut storage[region], len)
+    }
+}
+
+impl<
+        S: AsRef<[u8]>,
+        N: NestedViewInfo,
+        C: FieldCopyAccess,
+        E: Endianness,
+        const OFFSET_: usize,
+    > StorageIntoFieldView<S> for PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+where
+    C::HighLevelType: TryInto<usize>,
+{
+    type View = RepeatedView<Data<S>, N>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        let (len, region) = Self::region(storage.as_ref());
+        RepeatedView::new(Data::from(storage).into_subregion(region), len)
+    }
+}
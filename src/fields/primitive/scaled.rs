@@ -0,0 +1,222 @@
+use core::marker::PhantomData;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+/// Use this as a field type to store a physical quantity as a raw integer `U` that is scaled by the factor `NUM / DEN`,
+/// e.g. `Scaled<u16, 1, 100>` for a value in steps of 0.01. `U` can be any integer type of up to 64 bits and
+/// is stored in the endianness of the layout. `NUM` and `DEN` must not be zero.
+///
+/// Reading the field returns an [f64], which is the stored integer multiplied by `NUM / DEN`. Writing it takes an [f64],
+/// which is divided by `NUM / DEN` and rounded to the nearest integer. Writing panics if the value is NaN or
+/// doesn't fit into `U` after scaling.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(sensor_reading, LittleEndian, {
+///   temperature: Scaled<i16, 1, 100>,
+///   pressure: Scaled<u16, 5, 1>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 4];
+/// let mut view = sensor_reading::View::new(&mut storage);
+/// view.temperature_mut().write(-12.34);
+/// view.pressure_mut().write(1013.0);
+/// assert!((-12.34 - view.temperature().read()).abs() < 1e-9);
+/// assert_eq!(1015.0, view.pressure().read());
+/// assert_eq!([(-1234i16).to_le_bytes(), 203u16.to_le_bytes()].concat(), storage);
+/// # }
+/// ```
+pub struct Scaled<U, const NUM: i64, const DEN: i64> {
+    _p: PhantomData<U>,
+}
+
+impl<U, const NUM: i64, const DEN: i64> Scaled<U, NUM, DEN> {
+    const VALID: () = assert!(
+        NUM != 0 && DEN != 0,
+        "Error: The scale factor of a scaled field must not be zero or infinite"
+    );
+
+    #[inline(always)]
+    fn factor() -> f64 {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        NUM as f64 / DEN as f64
+    }
+}
+
+macro_rules! impl_scaled {
+    ($type: ty) => {
+        impl<E: Endianness, const NUM: i64, const DEN: i64, const OFFSET_: usize> FieldCopyAccess
+            for PrimitiveField<Scaled<$type, NUM, DEN>, E, OFFSET_>
+        {
+            /// See [FieldCopyAccess::HighLevelType]
+            type HighLevelType = f64;
+
+            /// Read the scaled field from a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn read(storage: &[u8]) -> f64 {
+                let raw = PrimitiveField::<$type, E, OFFSET_>::read(storage);
+                raw as f64 * Scaled::<$type, NUM, DEN>::factor()
+            }
+
+            /// Write the scaled field to a given data region, assuming the defined layout, using the [Field] API.
+            /// This panics if the value is NaN or doesn't fit into the field after scaling.
+            #[inline(always)]
+            fn write(storage: &mut [u8], value: f64) {
+                let scaled = value / Scaled::<$type, NUM, DEN>::factor();
+                // Round half away from zero, `f64::round` isn't available in no_std
+                let rounded = if scaled >= 0.0 {
+                    scaled + 0.5
+                } else {
+                    scaled - 0.5
+                };
+                assert!(
+                    rounded > <$type>::MIN as f64 - 1.0 && rounded < <$type>::MAX as f64 + 1.0,
+                    "Error: Value {} is out of range for a {} field scaled by {}/{}",
+                    value,
+                    stringify!($type),
+                    NUM,
+                    DEN
+                );
+                // The `as` cast truncates towards zero and saturates at the bounds of the integer type
+                PrimitiveField::<$type, E, OFFSET_>::write(storage, rounded as $type);
+            }
+        }
+
+        impl<E: Endianness, const NUM: i64, const DEN: i64, const OFFSET_: usize> Field
+            for PrimitiveField<Scaled<$type, NUM, DEN>, E, OFFSET_>
+        {
+            /// See [Field::Endian]
+            type Endian = E;
+            /// See [Field::OFFSET]
+            const OFFSET: usize = OFFSET_;
+            /// See [Field::SIZE]
+            const SIZE: Option<usize> = Some(core::mem::size_of::<$type>());
+        }
+
+        impl<'a, E: Endianness, const NUM: i64, const DEN: i64, const OFFSET_: usize>
+            StorageToFieldView<&'a [u8]> for PrimitiveField<Scaled<$type, NUM, DEN>, E, OFFSET_>
+        {
+            type View = FieldView<&'a [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<'a, E: Endianness, const NUM: i64, const DEN: i64, const OFFSET_: usize>
+            StorageToFieldView<&'a mut [u8]>
+            for PrimitiveField<Scaled<$type, NUM, DEN>, E, OFFSET_>
+        {
+            type View = FieldView<&'a mut [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a mut [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<
+                S: AsRef<[u8]>,
+                E: Endianness,
+                const NUM: i64,
+                const DEN: i64,
+                const OFFSET_: usize,
+            > StorageIntoFieldView<S> for PrimitiveField<Scaled<$type, NUM, DEN>, E, OFFSET_>
+        {
+            type View = FieldView<S, Self>;
+
+            #[inline(always)]
+            fn into_view(storage: S) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+    };
+}
+
+impl_scaled!(i8);
+impl_scaled!(i16);
+impl_scaled!(i32);
+impl_scaled!(i64);
+impl_scaled!(u8);
+impl_scaled!(u16);
+impl_scaled!(u32);
+impl_scaled!(u64);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use core::convert::TryInto;
+
+    #[test]
+    fn test_hundredths() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<Scaled<u16, 1, 100>, LittleEndian, 5>;
+        type Field2 = PrimitiveField<Scaled<i32, 1, 100>, BigEndian, 20>;
+
+        assert_eq!(Some(2), Field1::SIZE);
+        assert_eq!(Some(4), Field2::SIZE);
+
+        for value in [0.0, 0.01, 1.5, 123.45, 655.35] {
+            Field1::write(&mut storage, value);
+            assert!((value - Field1::read(&storage)).abs() <= 0.005);
+        }
+        assert_eq!(
+            65535,
+            u16::from_le_bytes((&storage[5..7]).try_into().unwrap())
+        );
+
+        Field2::write(&mut storage, -273.15);
+        assert!((-273.15 - Field2::read(&storage)).abs() <= 0.005);
+        assert_eq!(
+            -27315,
+            i32::from_be_bytes((&storage[20..24]).try_into().unwrap())
+        );
+
+        // Values between two steps are rounded to the nearest step
+        Field1::write(&mut storage, 0.126);
+        assert_eq!(13, u16::from_le_bytes((&storage[5..7]).try_into().unwrap()));
+    }
+
+    #[test]
+    fn test_factor_larger_than_one() {
+        let mut storage = vec![0; 1024];
+        type Field1 = PrimitiveField<Scaled<i8, 25, 2>, LittleEndian, 0>;
+
+        Field1::write(&mut storage, -100.0);
+        assert_eq!(-100.0, Field1::read(&storage));
+        assert_eq!(-8, storage[0] as i8);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Error: Value 655.36 is out of range for a u16 field scaled by 1/100"
+    )]
+    fn test_out_of_range() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<Scaled<u16, 1, 100>, BigEndian, 0>::write(&mut storage, 655.36);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Value -0.01 is out of range for a u16 field scaled by 1/100")]
+    fn test_negative_unsigned() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<Scaled<u16, 1, 100>, BigEndian, 0>::write(&mut storage, -0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Value NaN is out of range")]
+    fn test_nan() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<Scaled<i16, 1, 10>, BigEndian, 0>::write(&mut storage, f64::NAN);
+    }
+}
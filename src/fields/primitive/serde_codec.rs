@@ -0,0 +1,74 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{Endianness, FieldSliceAccess, PrimitiveField};
+
+/// A serde based binary format, e.g. [bincode](https://docs.rs/bincode) or [postcard](https://docs.rs/postcard), that can be
+/// used to store a serde serializable value in a byte array field. This crate doesn't depend on any such format,
+/// so implement this trait for the format you use and pass it to `read_decoded` and `write_encoded`.
+/// This trait is only available if the `serde` feature is enabled.
+///
+/// # Example
+/// ```ignore
+/// struct Bincode;
+///
+/// impl SerdeCodec for Bincode {
+///     type Error = bincode::Error;
+///
+///     fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error> {
+///         bincode::deserialize(bytes)
+///     }
+///
+///     fn encode<T: Serialize + ?Sized>(value: &T, dst: &mut [u8]) -> Result<(), Self::Error> {
+///         bincode::serialize_into(dst, value)
+///     }
+/// }
+/// ```
+pub trait SerdeCodec {
+    /// The error returned if a value can't be decoded or encoded
+    type Error;
+
+    /// Decode a value from the beginning of `bytes`. Trailing bytes that don't belong to the encoded value should be ignored,
+    /// since the encoded value usually doesn't fill the whole byte array field.
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error>;
+
+    /// Encode a value into the beginning of `dst`. This should return an error if `dst` is too small for the encoded value.
+    fn encode<T: Serialize + ?Sized>(value: &T, dst: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> PrimitiveField<[u8; N], E, OFFSET_> {
+    /// Decode a value stored in the byte array with the codec `C` using the [Field](crate::Field) API,
+    /// e.g. `my_layout::some_field::read_decoded::<Bincode, Config>(storage)`. See [SerdeCodec].
+    #[inline]
+    pub fn read_decoded<C: SerdeCodec, T: DeserializeOwned>(storage: &[u8]) -> Result<T, C::Error> {
+        C::decode(Self::data(storage))
+    }
+
+    /// Encode a value into the byte array with the codec `C` using the [Field](crate::Field) API. See [SerdeCodec].
+    /// Bytes of the byte array after the encoded value keep their previous content.
+    #[inline]
+    pub fn write_encoded<C: SerdeCodec, T: Serialize + ?Sized>(
+        storage: &mut [u8],
+        value: &T,
+    ) -> Result<(), C::Error> {
+        C::encode(value, Self::data_mut(storage))
+    }
+}
+
+impl<E: Endianness, const OFFSET_: usize> PrimitiveField<[u8], E, OFFSET_> {
+    /// Decode a value stored in the open ended byte array with the codec `C` using the [Field](crate::Field) API,
+    /// e.g. `my_layout::tail::read_decoded::<Bincode, Config>(storage)`. See [SerdeCodec].
+    #[inline]
+    pub fn read_decoded<C: SerdeCodec, T: DeserializeOwned>(storage: &[u8]) -> Result<T, C::Error> {
+        C::decode(Self::data(storage))
+    }
+
+    /// Encode a value into the open ended byte array with the codec `C` using the [Field](crate::Field) API. See [SerdeCodec].
+    /// Bytes of the byte array after the encoded value keep their previous content.
+    #[inline]
+    pub fn write_encoded<C: SerdeCodec, T: Serialize + ?Sized>(
+        storage: &mut [u8],
+        value: &T,
+    ) -> Result<(), C::Error> {
+        C::encode(value, Self::data_mut(storage))
+    }
+}
@@ -0,0 +1,483 @@
+use core::marker::PhantomData;
+
+use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
+use super::view::FieldView;
+use super::{FieldCopyAccess, PrimitiveField};
+use crate::endianness::Endianness;
+
+/// Use this as a field type to store a signed integer in sign-magnitude encoding, where the most significant bit
+/// is the sign and the remaining bits are the absolute value. `U` is the unsigned integer type the value is stored as,
+/// in the endianness of the layout, e.g. `SignMagnitude<u16>` is read and written as an `i16`.
+///
+/// Both positive and negative zero are read as `0`, and `0` is written as positive zero.
+/// Since sign-magnitude can't represent the smallest value of the signed type, e.g. `i16::MIN`, writing it panics.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(legacy_record, BigEndian, {
+///   temperature: SignMagnitude<u16>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 2];
+/// let mut view = legacy_record::View::new(&mut storage);
+/// view.temperature_mut().write(-5);
+/// assert_eq!(-5, view.temperature().read());
+/// assert_eq!([0x80, 0x05], storage);
+/// # }
+/// ```
+pub struct SignMagnitude<U> {
+    _p: PhantomData<U>,
+}
+
+/// Use this as a field type to store a signed integer in one's complement encoding, where negative values are
+/// stored with all bits of their absolute value inverted. `U` is the unsigned integer type the value is stored as,
+/// in the endianness of the layout, e.g. `OnesComplement<u16>` is read and written as an `i16`.
+///
+/// Both positive and negative zero (all bits set) are read as `0`, and `0` is written as positive zero.
+/// Since one's complement can't represent the smallest value of the signed type, e.g. `i16::MIN`, writing it panics.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(legacy_record, BigEndian, {
+///   temperature: OnesComplement<u16>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 2];
+/// let mut view = legacy_record::View::new(&mut storage);
+/// view.temperature_mut().write(-5);
+/// assert_eq!(-5, view.temperature().read());
+/// assert_eq!([0xFF, 0xFA], storage);
+/// # }
+/// ```
+pub struct OnesComplement<U> {
+    _p: PhantomData<U>,
+}
+
+/// Use this as a field type to store a signed integer in excess-K encoding, also called biased or offset binary encoding,
+/// where the value `v` is stored as the unsigned integer `v + K`, e.g. the exponent of an IEEE 754 float is stored with a bias of 127.
+/// `U` is the unsigned integer type the value is stored as, in the endianness of the layout. The field is read and written as the
+/// next wider signed integer type, e.g. `ExcessK<u16, K>` is read and written as an `i32`, so every stored value can be decoded.
+///
+/// `K` must be chosen so that the decoded values of all stored values fit into the signed type, otherwise the layout doesn't compile.
+/// Writing panics if `v + K` doesn't fit into `U`.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(float_parts, BigEndian, {
+///   exponent: ExcessK<u16, 1023>,
+/// });
+///
+/// # fn main() {
+/// let mut storage = [0; 2];
+/// let mut view = float_parts::View::new(&mut storage);
+/// view.exponent_mut().write(-3);
+/// assert_eq!(-3, view.exponent().read());
+/// assert_eq!(1020u16.to_be_bytes(), storage);
+/// # }
+/// ```
+pub struct ExcessK<U, const K: i64> {
+    _p: PhantomData<U>,
+}
+
+macro_rules! impl_excess_k {
+    ($unsigned: ty, $signed: ty) => {
+        impl<const K: i64> ExcessK<$unsigned, K> {
+            const VALID: () = assert!(
+                (0 - K as i128) >= <$signed>::MIN as i128
+                    && (<$unsigned>::MAX as i128 - K as i128) <= <$signed>::MAX as i128,
+                "Error: The bias of an excess-K field must be chosen so that all stored values can be decoded"
+            );
+
+            #[inline(always)]
+            fn bias() -> i128 {
+                #[allow(clippy::let_unit_value)]
+                let () = Self::VALID;
+                K as i128
+            }
+        }
+
+        impl<E: Endianness, const K: i64, const OFFSET_: usize> FieldCopyAccess
+            for PrimitiveField<ExcessK<$unsigned, K>, E, OFFSET_>
+        {
+            /// See [FieldCopyAccess::HighLevelType]
+            type HighLevelType = $signed;
+
+            /// Read the excess-K field from a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn read(storage: &[u8]) -> $signed {
+                let raw = PrimitiveField::<$unsigned, E, OFFSET_>::read(storage);
+                // The bias was checked at compile time, so the decoded value always fits
+                (raw as i128 - ExcessK::<$unsigned, K>::bias()) as $signed
+            }
+
+            /// Write the excess-K field to a given data region, assuming the defined layout, using the [Field] API.
+            /// This panics if the biased value doesn't fit into the field.
+            #[inline(always)]
+            fn write(storage: &mut [u8], value: $signed) {
+                let raw = value as i128 + ExcessK::<$unsigned, K>::bias();
+                assert!(
+                    raw >= 0 && raw <= <$unsigned>::MAX as i128,
+                    "Error: Value {} is out of range for a {} field with a bias of {}",
+                    value,
+                    stringify!($unsigned),
+                    K
+                );
+                PrimitiveField::<$unsigned, E, OFFSET_>::write(storage, raw as $unsigned)
+            }
+        }
+
+        impl<E: Endianness, const K: i64, const OFFSET_: usize> Field
+            for PrimitiveField<ExcessK<$unsigned, K>, E, OFFSET_>
+        {
+            /// See [Field::Endian]
+            type Endian = E;
+            /// See [Field::OFFSET]
+            const OFFSET: usize = OFFSET_;
+            /// See [Field::SIZE]
+            const SIZE: Option<usize> = Some(core::mem::size_of::<$unsigned>());
+        }
+
+        impl<'a, E: Endianness, const K: i64, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+            for PrimitiveField<ExcessK<$unsigned, K>, E, OFFSET_>
+        {
+            type View = FieldView<&'a [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<'a, E: Endianness, const K: i64, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+            for PrimitiveField<ExcessK<$unsigned, K>, E, OFFSET_>
+        {
+            type View = FieldView<&'a mut [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a mut [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<S: AsRef<[u8]>, E: Endianness, const K: i64, const OFFSET_: usize> StorageIntoFieldView<S>
+            for PrimitiveField<ExcessK<$unsigned, K>, E, OFFSET_>
+        {
+            type View = FieldView<S, Self>;
+
+            #[inline(always)]
+            fn into_view(storage: S) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+    };
+}
+
+impl_excess_k!(u8, i16);
+impl_excess_k!(u16, i32);
+impl_excess_k!(u32, i64);
+impl_excess_k!(u64, i128);
+
+macro_rules! impl_signed_encoding {
+    ($unsigned: ty, $signed: ty) => {
+        impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess
+            for PrimitiveField<SignMagnitude<$unsigned>, E, OFFSET_>
+        {
+            /// See [FieldCopyAccess::HighLevelType]
+            type HighLevelType = $signed;
+
+            /// Read the sign-magnitude field from a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn read(storage: &[u8]) -> $signed {
+                const SIGN_BIT: $unsigned = 1 << (<$unsigned>::BITS - 1);
+                let raw = PrimitiveField::<$unsigned, E, OFFSET_>::read(storage);
+                // The magnitude has one bit less than the signed type, so it always fits
+                let magnitude = (raw & !SIGN_BIT) as $signed;
+                if raw & SIGN_BIT != 0 {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+
+            /// Write the sign-magnitude field to a given data region, assuming the defined layout, using the [Field] API.
+            /// This panics if the value is the smallest value of the signed type, which can't be represented.
+            #[inline(always)]
+            fn write(storage: &mut [u8], value: $signed) {
+                const SIGN_BIT: $unsigned = 1 << (<$unsigned>::BITS - 1);
+                assert!(
+                    value != <$signed>::MIN,
+                    "Error: {} can't be represented in sign-magnitude encoding",
+                    value
+                );
+                let magnitude = value.unsigned_abs();
+                let raw = if value < 0 {
+                    magnitude | SIGN_BIT
+                } else {
+                    magnitude
+                };
+                PrimitiveField::<$unsigned, E, OFFSET_>::write(storage, raw)
+            }
+        }
+
+        impl<E: Endianness, const OFFSET_: usize> FieldCopyAccess
+            for PrimitiveField<OnesComplement<$unsigned>, E, OFFSET_>
+        {
+            /// See [FieldCopyAccess::HighLevelType]
+            type HighLevelType = $signed;
+
+            /// Read the one's complement field from a given data region, assuming the defined layout, using the [Field] API.
+            #[inline(always)]
+            fn read(storage: &[u8]) -> $signed {
+                let raw = PrimitiveField::<$unsigned, E, OFFSET_>::read(storage) as $signed;
+                if raw < 0 {
+                    // Inverting the bits gives the magnitude, which has one bit less than the signed type
+                    -(!raw)
+                } else {
+                    raw
+                }
+            }
+
+            /// Write the one's complement field to a given data region, assuming the defined layout, using the [Field] API.
+            /// This panics if the value is the smallest value of the signed type, which can't be represented.
+            #[inline(always)]
+            fn write(storage: &mut [u8], value: $signed) {
+                assert!(
+                    value != <$signed>::MIN,
+                    "Error: {} can't be represented in one's complement encoding",
+                    value
+                );
+                let raw = if value < 0 { !(-value) } else { value };
+                PrimitiveField::<$unsigned, E, OFFSET_>::write(storage, raw as $unsigned)
+            }
+        }
+
+        impl_signed_encoding!(@field_impls SignMagnitude<$unsigned>, $unsigned);
+        impl_signed_encoding!(@field_impls OnesComplement<$unsigned>, $unsigned);
+    };
+    (@field_impls $type: ty, $unsigned: ty) => {
+        impl<E: Endianness, const OFFSET_: usize> Field for PrimitiveField<$type, E, OFFSET_> {
+            /// See [Field::Endian]
+            type Endian = E;
+            /// See [Field::OFFSET]
+            const OFFSET: usize = OFFSET_;
+            /// See [Field::SIZE]
+            const SIZE: Option<usize> = Some(core::mem::size_of::<$unsigned>());
+        }
+
+        impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            type View = FieldView<&'a [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<'a, E: Endianness, const OFFSET_: usize> StorageToFieldView<&'a mut [u8]>
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            type View = FieldView<&'a mut [u8], Self>;
+
+            #[inline(always)]
+            fn view(storage: &'a mut [u8]) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+
+        impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize> StorageIntoFieldView<S>
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            type View = FieldView<S, Self>;
+
+            #[inline(always)]
+            fn into_view(storage: S) -> Self::View {
+                Self::View::new(storage)
+            }
+        }
+    };
+}
+
+impl_signed_encoding!(u8, i8);
+impl_signed_encoding!(u16, i16);
+impl_signed_encoding!(u32, i32);
+impl_signed_encoding!(u64, i64);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use core::convert::TryInto;
+
+    #[test]
+    fn test_sign_magnitude() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<SignMagnitude<u16>, BigEndian, 5>;
+        type Field2 = PrimitiveField<SignMagnitude<u32>, LittleEndian, 20>;
+
+        Field1::write(&mut storage, -300);
+        Field2::write(&mut storage, 300);
+
+        assert_eq!(-300, Field1::read(&storage));
+        assert_eq!(300, Field2::read(&storage));
+        assert_eq!(&[0x81, 0x2C], &storage[5..7]);
+        assert_eq!(
+            300,
+            u32::from_le_bytes((&storage[20..24]).try_into().unwrap())
+        );
+
+        Field1::write(&mut storage, i16::MAX);
+        assert_eq!(&[0x7F, 0xFF], &storage[5..7]);
+        Field1::write(&mut storage, -i16::MAX);
+        assert_eq!(&[0xFF, 0xFF], &storage[5..7]);
+        assert_eq!(-i16::MAX, Field1::read(&storage));
+
+        assert_eq!(Some(2), Field1::SIZE);
+        assert_eq!(Some(4), Field2::SIZE);
+    }
+
+    #[test]
+    fn test_sign_magnitude_zero() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<SignMagnitude<u8>, BigEndian, 5>;
+
+        // Negative zero
+        storage[5] = 0x80;
+        assert_eq!(0, Field1::read(&storage));
+
+        Field1::write(&mut storage, 0);
+        assert_eq!(0x00, storage[5]);
+        assert_eq!(0, Field1::read(&storage));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: -128 can't be represented in sign-magnitude encoding")]
+    fn test_sign_magnitude_min() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<SignMagnitude<u8>, BigEndian, 5>::write(&mut storage, i8::MIN);
+    }
+
+    #[test]
+    fn test_ones_complement() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<OnesComplement<u16>, BigEndian, 5>;
+        type Field2 = PrimitiveField<OnesComplement<u64>, LittleEndian, 20>;
+
+        Field1::write(&mut storage, -300);
+        Field2::write(&mut storage, -1);
+
+        assert_eq!(-300, Field1::read(&storage));
+        assert_eq!(-1, Field2::read(&storage));
+        assert_eq!(&[0xFE, 0xD3], &storage[5..7]);
+        assert_eq!(
+            0xFFFF_FFFF_FFFF_FFFE,
+            u64::from_le_bytes((&storage[20..28]).try_into().unwrap())
+        );
+
+        Field1::write(&mut storage, 300);
+        assert_eq!(&[0x01, 0x2C], &storage[5..7]);
+        Field1::write(&mut storage, -i16::MAX);
+        assert_eq!(&[0x80, 0x00], &storage[5..7]);
+        assert_eq!(-i16::MAX, Field1::read(&storage));
+    }
+
+    #[test]
+    fn test_ones_complement_zero() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<OnesComplement<u8>, BigEndian, 5>;
+
+        // Negative zero
+        storage[5] = 0xFF;
+        assert_eq!(0, Field1::read(&storage));
+
+        Field1::write(&mut storage, 0);
+        assert_eq!(0x00, storage[5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: -32768 can't be represented in one's complement encoding")]
+    fn test_ones_complement_min() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<OnesComplement<u16>, BigEndian, 5>::write(&mut storage, i16::MIN);
+    }
+
+    #[test]
+    fn test_excess_k() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<ExcessK<u16, 1023>, BigEndian, 5>;
+        type Field2 = PrimitiveField<ExcessK<u8, 127>, LittleEndian, 20>;
+        type Field3 = PrimitiveField<ExcessK<u64, { i64::MAX }>, LittleEndian, 30>;
+
+        assert_eq!(Some(2), Field1::SIZE);
+        assert_eq!(Some(1), Field2::SIZE);
+
+        Field1::write(&mut storage, -1023);
+        assert_eq!(&[0x00, 0x00], &storage[5..7]);
+        assert_eq!(-1023, Field1::read(&storage));
+        Field1::write(&mut storage, 0);
+        assert_eq!(&[0x03, 0xFF], &storage[5..7]);
+        Field1::write(&mut storage, 1024);
+        assert_eq!(&[0x07, 0xFF], &storage[5..7]);
+        storage[5..7].copy_from_slice(&[0xFF, 0xFF]);
+        assert_eq!(65535 - 1023, Field1::read(&storage));
+
+        Field2::write(&mut storage, -126);
+        assert_eq!(1, storage[20]);
+        assert_eq!(-126, Field2::read(&storage));
+        Field2::write(&mut storage, 128);
+        assert_eq!(255, storage[20]);
+
+        Field3::write(&mut storage, -i64::MAX as i128);
+        assert_eq!(
+            0,
+            u64::from_le_bytes((&storage[30..38]).try_into().unwrap())
+        );
+        Field3::write(&mut storage, u64::MAX as i128 - i64::MAX as i128);
+        assert_eq!(
+            u64::MAX,
+            u64::from_le_bytes((&storage[30..38]).try_into().unwrap())
+        );
+        assert_eq!(u64::MAX as i128 - i64::MAX as i128, Field3::read(&storage));
+    }
+
+    #[test]
+    fn test_excess_k_negative_bias() {
+        let mut storage = vec![0; 1024];
+        type Field1 = PrimitiveField<ExcessK<u8, -10>, BigEndian, 5>;
+
+        Field1::write(&mut storage, 10);
+        assert_eq!(0, storage[5]);
+        storage[5] = 255;
+        assert_eq!(265, Field1::read(&storage));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Error: Value -1024 is out of range for a u16 field with a bias of 1023"
+    )]
+    fn test_excess_k_below_range() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<ExcessK<u16, 1023>, BigEndian, 5>::write(&mut storage, -1024);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: Value 129 is out of range for a u8 field with a bias of 127")]
+    fn test_excess_k_above_range() {
+        let mut storage = vec![0; 1024];
+        PrimitiveField::<ExcessK<u8, 127>, BigEndian, 5>::write(&mut storage, 129);
+    }
+}
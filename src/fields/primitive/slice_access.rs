@@ -1,9 +1,11 @@
 use core::convert::TryFrom;
+use core::fmt;
 
 use super::super::{Field, StorageIntoFieldView, StorageToFieldView};
 use super::PrimitiveField;
 use crate::endianness::Endianness;
 use crate::utils::data::Data;
+use crate::LayoutError;
 
 /// This trait is implemented for fields with "slice access",
 /// i.e. fields that are read/write directly without a copy
@@ -98,6 +100,234 @@ impl<'a, E: Endianness, const OFFSET_: usize> FieldSliceAccess<'a>
         &mut storage[Self::OFFSET..]
     }
 }
+/// Describes an open ended byte array that is shorter than required. [PrimitiveField::data_checked] reports it as
+/// [LayoutError::TooShort], which this converts into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooShort {
+    /// The minimal length that was required
+    pub min_len: usize,
+    /// The actual length of the byte array
+    pub actual_len: usize,
+}
+
+impl fmt::Display for TooShort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Byte array too short: required at least {} bytes but only got {}",
+            self.min_len, self.actual_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TooShort {}
+
+/// Describes a destination buffer that is too small to hold the data of a byte array field. `copy_into` reports it as
+/// [LayoutError::DestinationTooShort], which this converts into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthError {
+    /// Number of bytes the destination buffer would need to have
+    pub required: usize,
+    /// Number of bytes the destination buffer actually has
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Destination too short: required {} bytes but only got {}",
+            self.required, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthError {}
+
+/// Describes an iterator that doesn't yield exactly as many elements as a field holds. `write_from_iter` reports it as
+/// [LayoutError::IterLength], which this converts into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IterLengthError {
+    /// Number of elements the field holds
+    pub expected: usize,
+    /// Number of elements the iterator yielded. If it yielded too many, this is `expected + 1`
+    /// since the iterator isn't advanced any further.
+    pub actual: usize,
+}
+
+impl fmt::Display for IterLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.actual > self.expected {
+            write!(
+                f,
+                "Iterator too long: expected {} elements but got more",
+                self.expected
+            )
+        } else {
+            write!(
+                f,
+                "Iterator too short: expected {} elements but only got {}",
+                self.expected, self.actual
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IterLengthError {}
+
+impl<E: Endianness, const OFFSET_: usize> PrimitiveField<[u8], E, OFFSET_> {
+    /// Borrow the data in the byte array with read access using the [Field] API,
+    /// but return a [LayoutError::TooShort] error if it has less than `min_len` bytes.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     tail_data: [u8],
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) -> Result<(), binary_layout::LayoutError> {
+    ///     let tail_data: &[u8] = my_layout::tail_data::data_checked(storage_data, 4)?;
+    ///     assert!(tail_data.len() >= 4);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn data_checked(storage: &[u8], min_len: usize) -> Result<&[u8], LayoutError> {
+        check_len(Self::data(storage), min_len)
+    }
+
+    /// Borrow the data in the byte array with write access using the [Field] API,
+    /// but return a [LayoutError::TooShort] error if it has less than `min_len` bytes.
+    /// See [PrimitiveField::data_checked] for an example.
+    #[inline]
+    pub fn data_mut_checked(storage: &mut [u8], min_len: usize) -> Result<&mut [u8], LayoutError> {
+        let data = Self::data_mut(storage);
+        check_len(&*data, min_len)?;
+        Ok(data)
+    }
+
+    /// Copy the data in the byte array into the beginning of `dst` using the [Field] API and return the number of bytes copied.
+    /// This returns a [LayoutError::DestinationTooShort] error and leaves `dst` untouched if `dst` is shorter than the byte array.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     tail_data: [u8],
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) -> Result<(), binary_layout::LayoutError> {
+    ///     let mut buffer = [0; 64];
+    ///     let len = my_layout::tail_data::copy_into(storage_data, &mut buffer)?;
+    ///     let tail_data: &[u8] = &buffer[..len];
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn copy_into(storage: &[u8], dst: &mut [u8]) -> Result<usize, LayoutError> {
+        copy_into(Self::data(storage), dst)
+    }
+
+    /// Fill the byte array with the bytes yielded by `iter` using the [Field] API.
+    /// This returns a [LayoutError::IterLength] error if `iter` doesn't yield exactly as many bytes as the byte array has until the end of the storage.
+    /// If `iter` yields too few or too many bytes, the bytes before the error was detected are already written.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     tail_data: [u8],
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) -> Result<(), binary_layout::LayoutError> {
+    ///     let len = my_layout::tail_data::data(storage_data).len();
+    ///     my_layout::tail_data::write_from_iter(storage_data, (0..len).map(|i| i as u8))
+    /// }
+    /// ```
+    #[inline]
+    pub fn write_from_iter(
+        storage: &mut [u8],
+        iter: impl IntoIterator<Item = u8>,
+    ) -> Result<(), LayoutError> {
+        let data = Self::data_mut(storage);
+        write_from_iter(data.len(), iter, |index, byte| data[index] = byte)
+    }
+}
+
+/// Call `write(index, element)` for the first `len` elements of `iter`, or return a [LayoutError::IterLength] error
+/// if `iter` doesn't yield exactly `len` elements. Elements before the error was detected are already written.
+#[inline]
+pub(crate) fn write_from_iter<T>(
+    len: usize,
+    iter: impl IntoIterator<Item = T>,
+    mut write: impl FnMut(usize, T),
+) -> Result<(), LayoutError> {
+    let mut iter = iter.into_iter();
+    for index in 0..len {
+        match iter.next() {
+            Some(element) => write(index, element),
+            None => {
+                return Err(IterLengthError {
+                    expected: len,
+                    actual: index,
+                }
+                .into())
+            }
+        }
+    }
+    if iter.next().is_some() {
+        return Err(IterLengthError {
+            expected: len,
+            actual: len + 1,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Copy `src` into the beginning of `dst`, or return a [LayoutError::DestinationTooShort] error if `dst` is too short.
+#[inline(always)]
+pub(crate) fn copy_into(src: &[u8], dst: &mut [u8]) -> Result<usize, LayoutError> {
+    if dst.len() < src.len() {
+        return Err(LengthError {
+            required: src.len(),
+            actual: dst.len(),
+        }
+        .into());
+    }
+    dst[..src.len()].copy_from_slice(src);
+    Ok(src.len())
+}
+
+/// Count the set bits over all bytes of `data`.
+#[inline(always)]
+pub(crate) fn count_ones(data: &[u8]) -> u32 {
+    data.iter().map(|byte| byte.count_ones()).sum()
+}
+
+#[inline(always)]
+fn check_len(data: &[u8], min_len: usize) -> Result<&[u8], LayoutError> {
+    if data.len() < min_len {
+        Err(TooShort {
+            min_len,
+            actual_len: data.len(),
+        }
+        .into())
+    } else {
+        Ok(data)
+    }
+}
+
 impl<E: Endianness, const OFFSET_: usize> Field for PrimitiveField<[u8], E, OFFSET_> {
     /// See [Field::Endian]
     type Endian = E;
@@ -194,6 +424,106 @@ impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> FieldSliceAccess<'
         <&mut [u8; N]>::try_from(&mut storage[Self::OFFSET..(Self::OFFSET + N)]).unwrap()
     }
 }
+impl<E: Endianness, const N: usize, const OFFSET_: usize> PrimitiveField<[u8; N], E, OFFSET_> {
+    /// Copy the data in the byte array into an owned array using the [Field] API.
+    /// In contrast to [FieldSliceAccess::data], the returned array doesn't borrow the storage,
+    /// so it can be kept after the storage is gone. With the [FieldView](crate::FieldView) API,
+    /// you can get the same by dereferencing the returned array reference, e.g. `*view.some_field()`.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [u8; 5],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: Vec<u8>) -> [u8; 5] {
+    ///     my_layout::some_field::read_array(&storage_data)
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn read_array(storage: &[u8]) -> [u8; N] {
+        *Self::data(storage)
+    }
+
+    /// Copy the data in the byte array into the beginning of `dst` using the [Field] API and return the number of bytes copied, i.e. `N`.
+    /// This returns a [LayoutError::DestinationTooShort] error and leaves `dst` untouched if `dst` is shorter than `N` bytes.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [u8; 5],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) -> Result<[u8; 8], binary_layout::LayoutError> {
+    ///     let mut buffer = [0; 8];
+    ///     my_layout::some_field::copy_into(storage_data, &mut buffer)?;
+    ///     Ok(buffer)
+    /// }
+    /// ```
+    #[inline]
+    pub fn copy_into(storage: &[u8], dst: &mut [u8]) -> Result<usize, LayoutError> {
+        copy_into(Self::data(storage), dst)
+    }
+
+    /// Count the set bits over all bytes of the byte array using the [Field] API, e.g. for presence bitmaps or capability masks.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     presence: [u8; 2],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) -> u32 {
+    ///     my_layout::presence::count_ones(storage_data)
+    /// }
+    ///
+    /// assert_eq!(5, func(&[0b1011_0000, 0b0000_0011]));
+    /// ```
+    #[inline]
+    pub fn count_ones(storage: &[u8]) -> u32 {
+        count_ones(Self::data(storage))
+    }
+
+    /// Fill the byte array with the bytes yielded by `iter` using the [Field] API.
+    /// This returns a [LayoutError::IterLength] error if `iter` doesn't yield exactly `N` bytes.
+    /// If `iter` yields too few or too many bytes, the bytes before the error was detected are already written.
+    ///
+    /// # Example:
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///     //... other fields ...
+    ///     some_field: [u8; 5],
+    ///     //... other fields
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) -> Result<(), binary_layout::LayoutError> {
+    ///     my_layout::some_field::write_from_iter(storage_data, b"hello".iter().copied())
+    /// }
+    /// ```
+    #[inline]
+    pub fn write_from_iter(
+        storage: &mut [u8],
+        iter: impl IntoIterator<Item = u8>,
+    ) -> Result<(), LayoutError> {
+        let data = Self::data_mut(storage);
+        write_from_iter(N, iter, |index, byte| data[index] = byte)
+    }
+}
+
 impl<E: Endianness, const N: usize, const OFFSET_: usize> Field
     for PrimitiveField<[u8; N], E, OFFSET_>
 {
@@ -203,6 +533,8 @@ impl<E: Endianness, const N: usize, const OFFSET_: usize> Field
     const OFFSET: usize = OFFSET_;
     /// See [Field::SIZE]
     const SIZE: Option<usize> = Some(N);
+    #[doc(hidden)]
+    const IS_ZERO_LENGTH_ARRAY: bool = N == 0;
 }
 impl<'a, E: Endianness, const N: usize, const OFFSET_: usize> StorageToFieldView<&'a [u8]>
     for PrimitiveField<[u8; N], E, OFFSET_>
@@ -237,11 +569,11 @@ impl<S: AsRef<[u8]>, E: Endianness, const N: usize, const OFFSET_: usize> Storag
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     #![allow(clippy::float_cmp)]
     use crate::prelude::*;
-    use crate::PrimitiveField;
+    use crate::{LayoutError, PrimitiveField};
 
     #[test]
     fn test_slice() {
@@ -261,6 +593,27 @@ mod tests {
         let _b: &mut [u8] = Field1::data_mut(&mut storage);
     }
 
+    #[test]
+    fn test_slice_checked() {
+        let mut storage = vec![0; 10];
+
+        type Field1 = PrimitiveField<[u8], LittleEndian, 5>;
+
+        assert_eq!(Ok(&[0; 5][..]), Field1::data_checked(&storage, 5));
+        assert_eq!(Ok(&[0; 5][..]), Field1::data_checked(&storage, 0));
+        assert_eq!(
+            Err(LayoutError::TooShort {
+                min_len: 6,
+                actual_len: 5
+            }),
+            Field1::data_checked(&storage, 6)
+        );
+
+        Field1::data_mut_checked(&mut storage, 5).unwrap()[0] = 1;
+        assert_eq!(1, storage[5]);
+        assert!(Field1::data_mut_checked(&mut storage, 6).is_err());
+    }
+
     #[test]
     fn test_array() {
         let mut storage = vec![0; 1024];
@@ -281,4 +634,18 @@ mod tests {
         let _a: &[u8; 2] = Field1::data(&storage);
         let _b: &mut [u8; 2] = Field1::data_mut(&mut storage);
     }
+
+    #[test]
+    fn test_array_count_ones() {
+        let mut storage = vec![0xFF; 1024];
+
+        type Field1 = PrimitiveField<[u8; 4], LittleEndian, 5>;
+
+        Field1::data_mut(&mut storage).copy_from_slice(&[0b1010_1010, 0, 0xFF, 0b0001_0000]);
+        assert_eq!(13, Field1::count_ones(&storage));
+        assert_eq!(
+            0,
+            PrimitiveField::<[u8; 0], LittleEndian, 5>::count_ones(&storage)
+        );
+    }
 }
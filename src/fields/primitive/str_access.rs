@@ -0,0 +1,44 @@
+use crate::LayoutError;
+
+/// Read a byte array field as text. This is implemented for `[u8]`, so after importing it, e.g. with the prelude,
+/// you can call these methods on the slices returned by the [FieldView](crate::FieldView) API for
+/// open ended byte arrays like `[u8]`, and on fixed size byte arrays `[u8; N]` as well.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(log_entry, LittleEndian, {
+///   level: u8,
+///   message: [u8],
+/// });
+///
+/// # fn main() {
+/// let storage = b"\x02disk full";
+/// let view = log_entry::View::new(&storage[..]);
+/// assert_eq!(Ok("disk full"), view.message().as_str());
+/// # }
+/// ```
+pub trait BytesAsStr {
+    /// Borrow the bytes as a `&str` after validating that all of them are UTF-8.
+    /// This returns a [LayoutError::Utf8Error] if they aren't, whose [Utf8Error](core::str::Utf8Error) tells where the first invalid byte is.
+    fn as_str(&self) -> Result<&str, LayoutError>;
+
+    /// Decode the bytes as UTF-8, replacing invalid sequences with `U+FFFD REPLACEMENT CHARACTER`.
+    /// This only allocates if there are invalid sequences. This is only available if the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    fn to_str_lossy(&self) -> std::borrow::Cow<'_, str>;
+}
+
+impl BytesAsStr for [u8] {
+    #[inline]
+    fn as_str(&self) -> Result<&str, LayoutError> {
+        Ok(core::str::from_utf8(self)?)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn to_str_lossy(&self) -> std::borrow::Cow<'_, str> {
+        std::string::String::from_utf8_lossy(self)
+    }
+}
@@ -0,0 +1,272 @@
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use super::super::{StorageIntoFieldView, StorageToFieldView};
+use super::{Endianness, FieldCopyAccess, FieldSliceAccess, PrimitiveField};
+use crate::utils::data::Data;
+use crate::Field;
+
+// Layouts can declare a `@trailer { ... }` section after their open ended field. The define_layout! macro then
+// declares the open ended field with [Trailed] wrapping its type, which makes it stop `TRAILER_SIZE` bytes before
+// the end of the storage, and each trailer field with [FromEnd] wrapping its type, which makes it start `FROM_END` bytes
+// before the end of the storage. The code in this module forwards all accessors to a field of the wrapped type,
+// passing it the part of the storage computed from the storage length at runtime.
+//
+// The static `OFFSET` of a trailer field is its offset in a storage where the open ended field is empty. This keeps
+// the `FIELDS` table and `MIN_SIZE` of the layout meaningful. [Field::byte_range] gives the actual position.
+
+/// Internal type. Don't use this in user code.
+/// Marker type for an open ended field that is followed by a trailer of `TRAILER_SIZE` bytes.
+pub struct Trailed<T: ?Sized, const TRAILER_SIZE: usize> {
+    _p: PhantomData<T>,
+}
+
+/// Internal type. Don't use this in user code.
+/// Marker type for a trailer field that starts `FROM_END` bytes before the end of the storage.
+pub struct FromEnd<T: ?Sized, const FROM_END: usize> {
+    _p: PhantomData<T>,
+}
+
+impl<T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize> Field
+    for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: Field,
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = <PrimitiveField<T, E, OFFSET_> as Field>::SIZE;
+    /// See [Field::MAX_SIZE]
+    const MAX_SIZE: Option<usize> = <PrimitiveField<T, E, OFFSET_> as Field>::MAX_SIZE;
+
+    #[inline(always)]
+    fn byte_range(storage_len: usize) -> Range<usize> {
+        OFFSET_..storage_len.saturating_sub(TRAILER_SIZE)
+    }
+}
+
+impl<T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize>
+    PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn without_trailer(storage: &[u8]) -> &[u8] {
+        &storage[..storage.len().saturating_sub(TRAILER_SIZE)]
+    }
+
+    #[inline(always)]
+    fn without_trailer_mut(storage: &mut [u8]) -> &mut [u8] {
+        let end = storage.len().saturating_sub(TRAILER_SIZE);
+        &mut storage[..end]
+    }
+}
+
+impl<'a, T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize>
+    FieldSliceAccess<'a> for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: FieldSliceAccess<'a>,
+{
+    type SliceType = <PrimitiveField<T, E, OFFSET_> as FieldSliceAccess<'a>>::SliceType;
+    type MutSliceType = <PrimitiveField<T, E, OFFSET_> as FieldSliceAccess<'a>>::MutSliceType;
+
+    /// See [FieldSliceAccess::data]
+    #[inline(always)]
+    fn data(storage: &'a [u8]) -> Self::SliceType {
+        PrimitiveField::<T, E, OFFSET_>::data(Self::without_trailer(storage))
+    }
+
+    /// See [FieldSliceAccess::data_mut]
+    #[inline(always)]
+    fn data_mut(storage: &'a mut [u8]) -> Self::MutSliceType {
+        PrimitiveField::<T, E, OFFSET_>::data_mut(Self::without_trailer_mut(storage))
+    }
+}
+
+impl<'a, T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: StorageToFieldView<&'a [u8]>,
+{
+    type View = <PrimitiveField<T, E, OFFSET_> as StorageToFieldView<&'a [u8]>>::View;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        PrimitiveField::<T, E, OFFSET_>::view(Self::without_trailer(storage))
+    }
+}
+
+impl<'a, T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: StorageToFieldView<&'a mut [u8]>,
+{
+    type View = <PrimitiveField<T, E, OFFSET_> as StorageToFieldView<&'a mut [u8]>>::View;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        PrimitiveField::<T, E, OFFSET_>::view(Self::without_trailer_mut(storage))
+    }
+}
+
+impl<S: AsRef<[u8]>, T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize>
+    StorageIntoFieldView<S> for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: StorageIntoFieldView<Data<S>>,
+{
+    type View = <PrimitiveField<T, E, OFFSET_> as StorageIntoFieldView<Data<S>>>::View;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        let end = storage.as_ref().len().saturating_sub(TRAILER_SIZE);
+        PrimitiveField::<T, E, OFFSET_>::into_view(Data::from(storage).into_subregion(..end))
+    }
+}
+
+impl<T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize> Field
+    for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: Field,
+{
+    /// See [Field::Endian]
+    type Endian = E;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = OFFSET_;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = <PrimitiveField<T, E, 0> as Field>::SIZE;
+
+    /// The field starts `FROM_END` bytes before the end of the storage, but never before its static offset,
+    /// so a storage that is too small to contain the trailer doesn't make it overlap the fields before the open ended field.
+    #[inline(always)]
+    fn byte_range(storage_len: usize) -> Range<usize> {
+        let start = storage_len.saturating_sub(FROM_END).max(OFFSET_);
+        match Self::SIZE {
+            Some(size) => start..(start + size),
+            None => start..storage_len,
+        }
+    }
+}
+
+impl<T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize>
+    PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: Field,
+{
+    #[inline(always)]
+    fn start(storage: &[u8]) -> usize {
+        <Self as Field>::byte_range(storage.len()).start
+    }
+}
+
+impl<T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize> FieldCopyAccess
+    for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: FieldCopyAccess,
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = <PrimitiveField<T, E, 0> as FieldCopyAccess>::HighLevelType;
+
+    /// See [FieldCopyAccess::read]
+    #[inline(always)]
+    fn read(storage: &[u8]) -> Self::HighLevelType {
+        PrimitiveField::<T, E, 0>::read(&storage[Self::start(storage)..])
+    }
+
+    /// See [FieldCopyAccess::write]
+    #[inline(always)]
+    fn write(storage: &mut [u8], v: Self::HighLevelType) {
+        let start = Self::start(storage);
+        PrimitiveField::<T, E, 0>::write(&mut storage[start..], v)
+    }
+}
+
+impl<'a, T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize> FieldSliceAccess<'a>
+    for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: FieldSliceAccess<'a>,
+{
+    type SliceType = <PrimitiveField<T, E, 0> as FieldSliceAccess<'a>>::SliceType;
+    type MutSliceType = <PrimitiveField<T, E, 0> as FieldSliceAccess<'a>>::MutSliceType;
+
+    /// See [FieldSliceAccess::data]
+    #[inline(always)]
+    fn data(storage: &'a [u8]) -> Self::SliceType {
+        PrimitiveField::<T, E, 0>::data(&storage[Self::start(storage)..])
+    }
+
+    /// See [FieldSliceAccess::data_mut]
+    #[inline(always)]
+    fn data_mut(storage: &'a mut [u8]) -> Self::MutSliceType {
+        let start = Self::start(storage);
+        PrimitiveField::<T, E, 0>::data_mut(&mut storage[start..])
+    }
+}
+
+impl<'a, T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a [u8]> for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: Field + StorageToFieldView<&'a [u8]>,
+{
+    type View = <PrimitiveField<T, E, 0> as StorageToFieldView<&'a [u8]>>::View;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        PrimitiveField::<T, E, 0>::view(&storage[Self::start(storage)..])
+    }
+}
+
+impl<'a, T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize>
+    StorageToFieldView<&'a mut [u8]> for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: Field + StorageToFieldView<&'a mut [u8]>,
+{
+    type View = <PrimitiveField<T, E, 0> as StorageToFieldView<&'a mut [u8]>>::View;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        let start = Self::start(storage);
+        PrimitiveField::<T, E, 0>::view(&mut storage[start..])
+    }
+}
+
+impl<S: AsRef<[u8]>, T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize>
+    StorageIntoFieldView<S> for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: Field + StorageIntoFieldView<Data<S>>,
+{
+    type View = <PrimitiveField<T, E, 0> as StorageIntoFieldView<Data<S>>>::View;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        let start = Self::start(storage.as_ref());
+        PrimitiveField::<T, E, 0>::into_view(Data::from(storage).into_subregion(start..))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    type Body = PrimitiveField<Trailed<[u8], 4>, LittleEndian, 2>;
+    type Crc = PrimitiveField<FromEnd<u32, 4>, LittleEndian, 2>;
+
+    #[test]
+    fn test_trailer() {
+        let mut storage = vec![0; 9];
+        Crc::write(&mut storage, 0x04030201);
+        Body::data_mut(&mut storage).copy_from_slice(&[7, 8, 9]);
+        assert_eq!(&[0, 0, 7, 8, 9, 1, 2, 3, 4], &*storage);
+        assert_eq!(0x04030201, Crc::read(&storage));
+        assert_eq!(&[7, 8, 9], Body::data(&storage));
+        assert_eq!(5..9, Crc::byte_range(storage.len()));
+        assert_eq!(2..5, Body::byte_range(storage.len()));
+    }
+
+    #[test]
+    fn test_empty_body() {
+        let storage = [0, 0, 1, 0, 0, 0];
+        assert_eq!(1, Crc::read(&storage));
+        assert!(Body::data(&storage).is_empty());
+    }
+}
@@ -1,10 +1,14 @@
+use core::convert::TryInto;
 use core::marker::PhantomData;
+use core::ops::Range;
 
+use super::{BitFields, BitFlags, BitOrder, PrimitiveField};
+use crate::endianness::{BigEndian, Endianness, LittleEndian};
 use crate::{Field, FieldCopyAccess};
 
 /// A field view represents the field metadata stored in a [Field] plus it stores the underlying
 /// storage data it operates on, either as a reference to a slice `&[u8]`, `&mut [u8]`, or as
-/// an owning [Vec<u8>].
+/// an owning `Vec<u8>`.
 ///
 /// Since this API remembers the underlying storage data in a view object, you don't have to pass it
 /// in each time you're accessing a field. If you rather prefer an API that does not do that,
@@ -50,7 +54,7 @@ impl<S, F: Field> FieldView<S, F> {
     /// Create a new view for a field over a given storage.
     /// You probably shouldn't call this directly but should instead call
     /// `your_layout::View::new()`, which is generated by the
-    /// [define_layout!] macro for you.
+    /// [crate::define_layout!] macro for you.
     #[inline(always)]
     pub fn new(storage: S) -> Self {
         Self {
@@ -81,6 +85,29 @@ impl<S: AsRef<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     pub fn read(&self) -> F::HighLevelType {
         F::read(self.storage.as_ref())
     }
+
+    /// Read the field from a given data region, assuming the defined layout, using the [FieldView] API,
+    /// and convert it into a wider type `U`. For signed integers, this sign-extends the value.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: i8
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///   let view = my_layout::View::new(storage_data);
+    ///   let read: i32 = view.some_integer_field().read_as::<i32>();
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn read_as<U: From<F::HighLevelType>>(&self) -> U {
+        F::read_as(self.storage.as_ref())
+    }
 }
 impl<S: AsMut<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     /// Write the field to a given data region, assuming the defined layout, using the [FieldView] API.
@@ -104,4 +131,446 @@ impl<S: AsMut<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
     pub fn write(&mut self, v: F::HighLevelType) {
         F::write(self.storage.as_mut(), v)
     }
+
+    /// Write the field to a given data region, assuming the defined layout, using the [FieldView] API.
+    /// This accepts any value that can be converted into the type of the field and returns
+    /// [LayoutError::OutOfRange](crate::LayoutError::OutOfRange) without writing anything if the value doesn't fit.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_integer_field: u16
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   let value: u8 = 10;
+    ///   view.some_integer_field_mut().write_checked(value).unwrap();
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn write_checked<V: TryInto<F::HighLevelType>>(
+        &mut self,
+        v: V,
+    ) -> Result<(), crate::LayoutError> {
+        F::write_checked(self.storage.as_mut(), v)
+    }
+}
+impl<S: AsRef<[u8]> + AsMut<[u8]>, F: FieldCopyAccess> FieldView<S, F> {
+    /// Write the field to a given data region, assuming the defined layout, using the [FieldView] API,
+    /// and return the value the field held before.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   counter: u32
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   let previous: u32 = view.counter_mut().swap(0);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn swap(&mut self, v: F::HighLevelType) -> F::HighLevelType {
+        F::swap(self.storage.as_mut(), v)
+    }
+
+    /// Read the field from a given data region, assuming the defined layout, using the [FieldView] API,
+    /// and write back the value returned by `f` for it.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   counter: u32
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   view.counter_mut().update(|counter| counter + 1);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn update(&mut self, f: impl FnOnce(F::HighLevelType) -> F::HighLevelType) {
+        F::update(self.storage.as_mut(), f)
+    }
+}
+impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize>
+    FieldView<S, PrimitiveField<char, E, OFFSET_>>
+{
+    /// Read the char field from a given data region, assuming the defined layout, using the [FieldView] API.
+    /// Returns a [LayoutError::InvalidChar](crate::LayoutError::InvalidChar) error if the stored code point isn't a valid `char`.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_char_field: char
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &[u8]) {
+    ///   let view = my_layout::View::new(storage_data);
+    ///   let read: Result<char, binary_layout::LayoutError> = view.some_char_field().read();
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn read(&self) -> Result<char, crate::LayoutError> {
+        PrimitiveField::<char, E, OFFSET_>::read(self.storage.as_ref())
+    }
+}
+impl<S: AsMut<[u8]>, E: Endianness, const OFFSET_: usize>
+    FieldView<S, PrimitiveField<char, E, OFFSET_>>
+{
+    /// Write the char field to a given data region, assuming the defined layout, using the [FieldView] API.
+    ///
+    /// # Example
+    /// ```
+    /// use binary_layout::prelude::*;
+    ///
+    /// define_layout!(my_layout, LittleEndian, {
+    ///   //... other fields ...
+    ///   some_char_field: char
+    ///   //... other fields ...
+    /// });
+    ///
+    /// fn func(storage_data: &mut [u8]) {
+    ///   let mut view = my_layout::View::new(storage_data);
+    ///   view.some_char_field_mut().write('ß');
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn write(&mut self, v: char) {
+        PrimitiveField::<char, E, OFFSET_>::write(self.storage.as_mut(), v)
+    }
+}
+
+macro_rules! impl_flags_view {
+    ($type: ty, $example_type: literal) => {
+        impl<S: AsRef<[u8]>, E: Endianness, const N: usize, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<$type, E, OFFSET_>>
+        {
+            doc_comment::doc_comment! {
+                concat! {"
+                Read the flag with the given index, assuming the defined layout, using the [FieldView] API.
+                This panics if `index >= N`.
+
+                # Example
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                  //... other fields ...
+                  some_flags: ", $example_type, "
+                  //... other fields ...
+                });
+
+                fn func(storage_data: &[u8]) {
+                  let view = my_layout::View::new(storage_data);
+                  let flag: bool = view.some_flags().get(3);
+                }
+
+                # fn main() {}
+                ```
+                "},
+                #[inline(always)]
+                pub fn get(&self, index: usize) -> bool {
+                    PrimitiveField::<$type, E, OFFSET_>::get(self.storage.as_ref(), index)
+                }
+            }
+        }
+        impl<S: AsMut<[u8]>, E: Endianness, const N: usize, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<$type, E, OFFSET_>>
+        {
+            doc_comment::doc_comment! {
+                concat! {"
+                Write the flag with the given index, assuming the defined layout, using the [FieldView] API.
+                This panics if `index >= N`.
+
+                # Example
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                  //... other fields ...
+                  some_flags: ", $example_type, "
+                  //... other fields ...
+                });
+
+                fn func(storage_data: &mut [u8]) {
+                  let mut view = my_layout::View::new(storage_data);
+                  view.some_flags_mut().set(3, true);
+                }
+
+                # fn main() {}
+                ```
+                "},
+                #[inline(always)]
+                pub fn set(&mut self, index: usize, value: bool) {
+                    PrimitiveField::<$type, E, OFFSET_>::set(self.storage.as_mut(), index, value)
+                }
+            }
+        }
+    };
+}
+
+impl_flags_view!(BitFlags<N>, "BitFlags<10>");
+impl_flags_view!([bool; N], "[bool; 4]");
+
+macro_rules! impl_int_byte_order_view {
+    ($type: ty) => {
+        impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<$type, E, OFFSET_>>
+        {
+            doc_comment::doc_comment! {
+                concat! {"
+                Read the field as little endian, regardless of the endianness of the layout, using the [FieldView] API.
+                This is useful for debugging or to interpret a single value written by a peer with a different byte order.
+
+                # Example
+
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, BigEndian, {
+                    field: ", stringify!($type), ",
+                });
+
+                let mut storage = [0; core::mem::size_of::<", stringify!($type), ">()];
+                storage[0] = 1;
+                let view = my_layout::View::new(storage);
+                assert_eq!(", stringify!($type), "::from_be_bytes(storage), view.field().read());
+                assert_eq!(", stringify!($type), "::from_le_bytes(storage), view.field().read_le());
+                ```
+                "},
+                #[inline(always)]
+                pub fn read_le(&self) -> $type {
+                    PrimitiveField::<$type, LittleEndian, OFFSET_>::read(self.storage.as_ref())
+                }
+            }
+
+            doc_comment::doc_comment! {
+                concat! {"
+                Read the field as big endian, regardless of the endianness of the layout, using the [FieldView] API.
+                This is useful for debugging or to interpret a single value written by a peer with a different byte order.
+
+                # Example
+
+                ```
+                use binary_layout::prelude::*;
+
+                define_layout!(my_layout, LittleEndian, {
+                    field: ", stringify!($type), ",
+                });
+
+                let mut storage = [0; core::mem::size_of::<", stringify!($type), ">()];
+                storage[0] = 1;
+                let view = my_layout::View::new(storage);
+                assert_eq!(", stringify!($type), "::from_le_bytes(storage), view.field().read());
+                assert_eq!(", stringify!($type), "::from_be_bytes(storage), view.field().read_be());
+                ```
+                "},
+                #[inline(always)]
+                pub fn read_be(&self) -> $type {
+                    PrimitiveField::<$type, BigEndian, OFFSET_>::read(self.storage.as_ref())
+                }
+            }
+        }
+    };
+}
+
+impl_int_byte_order_view!(i8);
+impl_int_byte_order_view!(i16);
+impl_int_byte_order_view!(i32);
+impl_int_byte_order_view!(i64);
+impl_int_byte_order_view!(i128);
+impl_int_byte_order_view!(u8);
+impl_int_byte_order_view!(u16);
+impl_int_byte_order_view!(u32);
+impl_int_byte_order_view!(u64);
+impl_int_byte_order_view!(u128);
+
+macro_rules! impl_int_overflow_view {
+    ($type: ty) => {
+        impl<S: AsMut<[u8]>, E: Endianness, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<$type, E, OFFSET_>>
+        {
+            /// Write a value of any integer type, including `i128` and `u128`, to the field, assuming the defined layout, using the [FieldView] API.
+            /// Values that don't fit into the field are clamped to the minimum or maximum value of the field type.
+            ///
+            /// # Example:
+            /// See [PrimitiveField::write_saturating] for an example
+            #[inline(always)]
+            pub fn write_saturating<V: super::WideInt>(&mut self, value: V) {
+                PrimitiveField::<$type, E, OFFSET_>::write_saturating(self.storage.as_mut(), value)
+            }
+
+            /// Write a value of any integer type, including `i128` and `u128`, to the field, assuming the defined layout, using the [FieldView] API.
+            /// Values that don't fit into the field wrap around like an `as` cast.
+            ///
+            /// # Example:
+            /// See [PrimitiveField::write_wrapping] for an example
+            #[inline(always)]
+            pub fn write_wrapping<V: super::WideInt>(&mut self, value: V) {
+                PrimitiveField::<$type, E, OFFSET_>::write_wrapping(self.storage.as_mut(), value)
+            }
+        }
+    };
+}
+
+impl_int_overflow_view!(i8);
+impl_int_overflow_view!(i16);
+impl_int_overflow_view!(i32);
+impl_int_overflow_view!(i64);
+impl_int_overflow_view!(i128);
+impl_int_overflow_view!(u8);
+impl_int_overflow_view!(u16);
+impl_int_overflow_view!(u32);
+impl_int_overflow_view!(u64);
+impl_int_overflow_view!(u128);
+
+macro_rules! impl_int_normalized_view {
+    ($type: ty) => {
+        impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<$type, E, OFFSET_>>
+        {
+            /// Read the field as a ratio in the range `0.0..=1.0`, assuming the defined layout, using the [FieldView] API.
+            /// The stored value is divided by the maximum value of the field type.
+            ///
+            /// # Example:
+            /// See [PrimitiveField::read_normalized] for an example
+            #[inline(always)]
+            pub fn read_normalized(&self) -> f32 {
+                PrimitiveField::<$type, E, OFFSET_>::read_normalized(self.storage.as_ref())
+            }
+        }
+
+        impl<S: AsMut<[u8]>, E: Endianness, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<$type, E, OFFSET_>>
+        {
+            /// Write a ratio in the range `0.0..=1.0` to the field, assuming the defined layout, using the [FieldView] API.
+            /// The ratio is multiplied by the maximum value of the field type and rounded to the nearest integer.
+            /// Ratios outside of the range are clamped to it and NaN is written as `0`.
+            ///
+            /// # Example:
+            /// See [PrimitiveField::write_normalized] for an example
+            #[inline(always)]
+            pub fn write_normalized(&mut self, value: f32) {
+                PrimitiveField::<$type, E, OFFSET_>::write_normalized(self.storage.as_mut(), value)
+            }
+        }
+    };
+}
+
+impl_int_normalized_view!(u8);
+impl_int_normalized_view!(u16);
+impl_int_normalized_view!(u32);
+impl_int_normalized_view!(u64);
+
+macro_rules! impl_int_count_ones_view {
+    ($type: ty) => {
+        impl<S: AsRef<[u8]>, E: Endianness, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<$type, E, OFFSET_>>
+        {
+            /// Count the set bits of the field, assuming the defined layout, using the [FieldView] API.
+            ///
+            /// # Example:
+            /// See [PrimitiveField::count_ones] for an example
+            #[inline(always)]
+            pub fn count_ones(&self) -> u32 {
+                PrimitiveField::<$type, E, OFFSET_>::count_ones(self.storage.as_ref())
+            }
+        }
+    };
+}
+
+impl_int_count_ones_view!(i8);
+impl_int_count_ones_view!(i16);
+impl_int_count_ones_view!(i32);
+impl_int_count_ones_view!(i64);
+impl_int_count_ones_view!(i128);
+impl_int_count_ones_view!(u8);
+impl_int_count_ones_view!(u16);
+impl_int_count_ones_view!(u32);
+impl_int_count_ones_view!(u64);
+impl_int_count_ones_view!(u128);
+
+macro_rules! impl_bitfields_view {
+    ($type: ty) => {
+        impl<S: AsRef<[u8]>, O: BitOrder, E: Endianness, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<BitFields<$type, O>, E, OFFSET_>>
+        {
+            /// Read the value stored in the given range of bits, assuming the defined layout, using the [FieldView] API.
+            /// This panics if the range is empty or outside of the bits of the field.
+            ///
+            /// # Example:
+            /// See [BitFields](super::BitFields) for an example
+            #[inline(always)]
+            pub fn read_bits(&self, bits: Range<u32>) -> $type {
+                PrimitiveField::<BitFields<$type, O>, E, OFFSET_>::read_bits(
+                    self.storage.as_ref(),
+                    bits,
+                )
+            }
+        }
+
+        impl<S: AsMut<[u8]>, O: BitOrder, E: Endianness, const OFFSET_: usize>
+            FieldView<S, PrimitiveField<BitFields<$type, O>, E, OFFSET_>>
+        {
+            /// Write the value into the given range of bits, assuming the defined layout, using the [FieldView] API.
+            /// The other bits of the field keep their value. This panics if the range is empty or outside of the bits of the field,
+            /// or if the value doesn't fit into the range.
+            ///
+            /// # Example:
+            /// See [BitFields](super::BitFields) for an example
+            #[inline(always)]
+            pub fn write_bits(&mut self, bits: Range<u32>, value: $type) {
+                PrimitiveField::<BitFields<$type, O>, E, OFFSET_>::write_bits(
+                    self.storage.as_mut(),
+                    bits,
+                    value,
+                )
+            }
+        }
+    };
+}
+
+impl_bitfields_view!(u8);
+impl_bitfields_view!(u16);
+impl_bitfields_view!(u32);
+impl_bitfields_view!(u64);
+
+#[cfg(feature = "bitflags")]
+impl<S: AsRef<[u8]>, T: bitflags::Flags, E: Endianness, const OFFSET_: usize>
+    FieldView<S, PrimitiveField<super::FlagsAs<T>, E, OFFSET_>>
+where
+    PrimitiveField<T::Bits, E, OFFSET_>: FieldCopyAccess<HighLevelType = T::Bits>,
+{
+    /// Read the flags field from a given data region, assuming the defined layout, using the [FieldView] API.
+    /// In contrast to [read](FieldView::read), this returns [LayoutError::UnknownFlags](crate::LayoutError::UnknownFlags) if the stored
+    /// value has bits set that don't correspond to any defined flag.
+    ///
+    /// # Example
+    /// See [FlagsAs](super::FlagsAs) for an example
+    #[inline(always)]
+    pub fn try_read(&self) -> Result<T, crate::LayoutError>
+    where
+        T::Bits: Into<u128>,
+    {
+        PrimitiveField::<super::FlagsAs<T>, E, OFFSET_>::try_read(self.storage.as_ref())
+    }
 }
@@ -0,0 +1,156 @@
+use core::marker::PhantomData;
+
+use super::{
+    primitive::{FieldCopyAccess, FieldView},
+    Field, StorageIntoFieldView, StorageToFieldView,
+};
+
+/// Defines the sentinel value for a [SentinelField]. The [define_layout!](crate::define_layout!) macro
+/// generates an implementation of this trait for each field declared with a sentinel, you usually don't
+/// have to implement it yourself.
+pub trait SentinelValue<T> {
+    /// The value that is stored in the field to mark it as absent.
+    const VALUE: T;
+}
+
+/// A [SentinelField] is a [Field] that reads and writes an [Option] of the type of the wrapped field.
+/// A special sentinel value stored in the field, defined by `V`, marks the field as absent.
+/// Reading the sentinel value returns `None` and writing `None` stores the sentinel value.
+///
+/// Use this by declaring a field with a sentinel in the [define_layout!](crate::define_layout!) macro.
+///
+/// # Example:
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   // ... other fields ...
+///   id: u32 = sentinel(0xFFFF_FFFF),
+///   // ... other fields ...
+/// });
+///
+/// fn func(storage_data: &mut [u8]) {
+///   my_layout::id::write(storage_data, Some(5));
+///   assert_eq!(Some(5), my_layout::id::read(storage_data));
+///
+///   my_layout::id::write(storage_data, None);
+///   assert_eq!(None, my_layout::id::read(storage_data));
+///   assert_eq!(0xFFFF_FFFF, u32::from_le_bytes(storage_data[..4].try_into().unwrap()));
+/// }
+///
+/// # fn main() {
+/// #   let mut storage = [0; 1024];
+/// #   func(&mut storage);
+/// # }
+/// ```
+pub struct SentinelField<V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> {
+    _p1: PhantomData<V>,
+    _p2: PhantomData<F>,
+}
+
+impl<V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> Field for SentinelField<V, F> {
+    /// See [Field::Endian]
+    type Endian = F::Endian;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = F::OFFSET;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = F::SIZE;
+}
+
+impl<'a, V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> StorageToFieldView<&'a [u8]>
+    for SentinelField<V, F>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> StorageToFieldView<&'a mut [u8]>
+    for SentinelField<V, F>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> StorageIntoFieldView<S>
+    for SentinelField<V, F>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess> FieldCopyAccess for SentinelField<V, F>
+where
+    F::HighLevelType: PartialEq,
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = Option<F::HighLevelType>;
+
+    /// Read the field from a given data region, assuming the defined layout, using the [Field] API.
+    /// Returns `None` if the field holds the sentinel value.
+    ///
+    /// # Example:
+    /// See [SentinelField] for an example
+    #[inline(always)]
+    fn read(storage: &[u8]) -> Self::HighLevelType {
+        let v = F::read(storage);
+        if v == V::VALUE {
+            None
+        } else {
+            Some(v)
+        }
+    }
+
+    /// Write the field to a given data region, assuming the defined layout, using the [Field] API.
+    /// Writing `None` stores the sentinel value.
+    ///
+    /// # Example:
+    /// See [SentinelField] for an example
+    #[inline(always)]
+    fn write(storage: &mut [u8], v: Self::HighLevelType) {
+        F::write(storage, v.unwrap_or(V::VALUE))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::PrimitiveField;
+
+    struct Sentinel;
+    impl SentinelValue<i16> for Sentinel {
+        const VALUE: i16 = -1;
+    }
+
+    #[test]
+    fn test_sentinel() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = SentinelField<Sentinel, PrimitiveField<i16, BigEndian, 5>>;
+
+        assert_eq!(Some(0), Field1::read(&storage));
+
+        Field1::write(&mut storage, Some(300));
+        assert_eq!(Some(300), Field1::read(&storage));
+        assert_eq!(&300i16.to_be_bytes(), &storage[5..7]);
+
+        Field1::write(&mut storage, None);
+        assert_eq!(None, Field1::read(&storage));
+        assert_eq!(&[0xFF, 0xFF], &storage[5..7]);
+
+        assert_eq!(5, Field1::OFFSET);
+        assert_eq!(Some(2), Field1::SIZE);
+    }
+}
@@ -0,0 +1,235 @@
+use super::error::LayoutError;
+use super::info::FieldInfo;
+
+/// Internal function, don't use!
+/// Checks that a storage of length `actual` matches the size of a layout exactly. This backs `View::new_exact` generated by the define_layout! macro.
+/// Layouts ending with an open ended field don't have an exact size, for them this only checks that the storage contains all fields before it.
+pub fn check_exact_size(
+    size: Option<usize>,
+    fields: &[FieldInfo],
+    actual: usize,
+) -> Result<(), LayoutError> {
+    match size {
+        Some(expected) if actual > expected => {
+            Err(LayoutError::BufferTooLarge { expected, actual })
+        }
+        _ => check_min_size(size, fields, actual),
+    }
+}
+
+/// Internal function, don't use!
+/// Computes the number of bytes a storage needs to contain all fields of a layout. This backs the `MIN_SIZE` constant generated by the define_layout! macro.
+/// For layouts ending with an open ended field, this is the offset of that field.
+pub const fn min_size(size: Option<usize>, fields: &[FieldInfo]) -> usize {
+    if let Some(size) = size {
+        return size;
+    }
+    match fields.last() {
+        Some(FieldInfo {
+            offset,
+            size: Some(size),
+            ..
+        }) => *offset + *size,
+        Some(field) => field.offset,
+        None => 0,
+    }
+}
+
+/// Internal function, don't use!
+/// Computes the maximum number of bytes of a record of a layout from the maximum size of each field. This backs the `MAX_SIZE` constant
+/// generated by the define_layout! macro. For layouts with an open ended field, this is `None` unless that field has a declared maximum length.
+/// The open ended field is the last field, unless the layout has a `@trailer` section after it.
+pub const fn max_size(
+    size: Option<usize>,
+    fields: &[FieldInfo],
+    max_sizes: &[Option<usize>],
+) -> Option<usize> {
+    if size.is_some() {
+        return size;
+    }
+    let mut i = 0;
+    while i < fields.len() {
+        if fields[i].size.is_none() {
+            return match max_sizes[i] {
+                Some(max_size) => Some(min_size(size, fields) + max_size),
+                None => None,
+            };
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Internal function, don't use!
+/// Checks that a storage of length `actual` isn't longer than allowed by a layout ending with an open ended field with a declared maximum length.
+/// Storages of layouts with a fixed size aren't checked, since the generated constructors allow trailing bytes for them.
+pub fn check_max_size(
+    size: Option<usize>,
+    max_size: Option<usize>,
+    actual: usize,
+) -> Result<(), LayoutError> {
+    match (size, max_size) {
+        (None, Some(expected)) if actual > expected => {
+            Err(LayoutError::BufferTooLarge { expected, actual })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Internal function, don't use!
+/// Checks that a storage of length `actual` contains all fields of a layout. This backs `ValidatedView::try_new` generated by the define_layout! macro.
+/// For layouts ending with an open ended field, this checks that the storage contains all fields before it.
+pub fn check_min_size(
+    size: Option<usize>,
+    fields: &[FieldInfo],
+    actual: usize,
+) -> Result<(), LayoutError> {
+    let required = min_size(size, fields);
+    if actual < required {
+        Err(LayoutError::BufferTooSmall { required, actual })
+    } else {
+        Ok(())
+    }
+}
+
+/// Internal type, don't use!
+/// Carries the result of a check on the size of a layout in its type. Functions generated by the define_layout! macro that only work
+/// for some layouts require e.g. `SizeCheck<{ SIZE.is_some() }>: FixedSizeLayout`, so calling them for other layouts is a compile error.
+/// The bound is declared with `for<'a>`, so it is only checked where the function is called and not for every layout.
+pub struct SizeCheck<const OK: bool>;
+
+/// Internal trait, don't use!
+/// Implemented by [SizeCheck] if the layout has a fixed size.
+#[diagnostic::on_unimplemented(
+    message = "Error: Only layouts with a fixed size support this function",
+    label = "the layout ends with an open ended field"
+)]
+pub trait FixedSizeLayout {}
+impl FixedSizeLayout for SizeCheck<true> {}
+
+/// Internal trait, don't use!
+/// Implemented by [SizeCheck] if the layout ends with an open ended field.
+#[diagnostic::on_unimplemented(
+    message = "Error: Only layouts ending with an open ended field support this function",
+    label = "the layout has a fixed size"
+)]
+pub trait OpenEndedLayout {}
+impl OpenEndedLayout for SizeCheck<true> {}
+
+/// Internal trait, don't use!
+/// Implemented by [SizeCheck] if the layout ends with an open ended field and doesn't have a `@trailer` section.
+#[diagnostic::on_unimplemented(
+    message = "Error: Only layouts ending with an open ended field without a `@trailer` section can be split into a header and a body",
+    label = "the layout has a fixed size or a `@trailer` section"
+)]
+pub trait SplittableLayout {}
+impl SplittableLayout for SizeCheck<true> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_size() {
+        let fields = [
+            FieldInfo {
+                name: "a",
+                offset: 0,
+                size: Some(2),
+            },
+            FieldInfo {
+                name: "b",
+                offset: 2,
+                size: Some(4),
+            },
+        ];
+        assert_eq!(Ok(()), check_exact_size(Some(6), &fields, 6));
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 6,
+                actual: 5
+            }),
+            check_exact_size(Some(6), &fields, 5)
+        );
+        assert_eq!(
+            Err(LayoutError::BufferTooLarge {
+                expected: 6,
+                actual: 7
+            }),
+            check_exact_size(Some(6), &fields, 7)
+        );
+    }
+
+    #[test]
+    fn max_size() {
+        let fields = [
+            FieldInfo {
+                name: "a",
+                offset: 0,
+                size: Some(2),
+            },
+            FieldInfo {
+                name: "b",
+                offset: 2,
+                size: None,
+            },
+        ];
+        assert_eq!(Some(6), super::max_size(None, &fields, &[Some(2), Some(4)]));
+        assert_eq!(None, super::max_size(None, &fields, &[Some(2), None]));
+        assert_eq!(Some(2), super::max_size(Some(2), &fields[..1], &[Some(2)]));
+
+        assert_eq!(Ok(()), check_max_size(None, Some(6), 6));
+        assert_eq!(
+            Err(LayoutError::BufferTooLarge {
+                expected: 6,
+                actual: 7
+            }),
+            check_max_size(None, Some(6), 7)
+        );
+        assert_eq!(Ok(()), check_max_size(None, None, 100));
+        assert_eq!(Ok(()), check_max_size(Some(2), Some(2), 100));
+    }
+
+    #[test]
+    fn min_size() {
+        let fields = [FieldInfo {
+            name: "a",
+            offset: 0,
+            size: Some(2),
+        }];
+        assert_eq!(Ok(()), check_min_size(Some(2), &fields, 2));
+        assert_eq!(Ok(()), check_min_size(Some(2), &fields, 3));
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 2,
+                actual: 1
+            }),
+            check_min_size(Some(2), &fields, 1)
+        );
+    }
+
+    #[test]
+    fn open_ended() {
+        let fields = [
+            FieldInfo {
+                name: "a",
+                offset: 0,
+                size: Some(2),
+            },
+            FieldInfo {
+                name: "tail",
+                offset: 2,
+                size: None,
+            },
+        ];
+        assert_eq!(Ok(()), check_exact_size(None, &fields, 2));
+        assert_eq!(Ok(()), check_exact_size(None, &fields, 100));
+        assert_eq!(
+            Err(LayoutError::BufferTooSmall {
+                required: 2,
+                actual: 1
+            }),
+            check_exact_size(None, &fields, 1)
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use super::primitive::FieldCopyAccess;
+
+/// Implemented for tuples of fields with [copy access](crate::FieldCopyAccess), up to 8 fields.
+/// This allows reading several fields at once with a single bounds check covering all of them,
+/// see `View::read_fields` generated by the [define_layout!](crate::define_layout!) macro.
+///
+/// # Example:
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::FieldTuple;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: i64,
+/// });
+///
+/// fn func(storage_data: &[u8]) {
+///   let (field1, field2): (u16, i64) = <(my_layout::field1, my_layout::field2)>::read_fields(storage_data);
+///
+///   let view = my_layout::View::new(storage_data);
+///   let (field1, field2) = view.read_fields::<(my_layout::field1, my_layout::field2)>();
+/// }
+/// ```
+pub trait FieldTuple {
+    /// A tuple of the [HighLevelType](crate::FieldCopyAccess::HighLevelType)s of the fields
+    type Values;
+
+    /// Number of bytes the storage needs to have to contain all fields of the tuple
+    const END: usize;
+
+    /// Read all fields of the tuple from a given data region, assuming the defined layout.
+    /// This bounds checks the storage once and panics if it is too small to contain all fields.
+    fn read_fields(storage: &[u8]) -> Self::Values;
+}
+
+macro_rules! impl_field_tuple {
+    ($($field: ident),+) => {
+        impl<$($field: FieldCopyAccess),+> FieldTuple for ($($field,)+) {
+            type Values = ($($field::HighLevelType,)+);
+
+            const END: usize = {
+                let mut end = 0;
+                $(
+                    // Fields with copy access always have a static size
+                    let field_end = $field::OFFSET + crate::macro_define_layout::unwrap_field_size($field::SIZE);
+                    if field_end > end {
+                        end = field_end;
+                    }
+                )+
+                end
+            };
+
+            #[inline(always)]
+            fn read_fields(storage: &[u8]) -> Self::Values {
+                let storage = &storage[..Self::END];
+                ($($field::read(storage),)+)
+            }
+        }
+    };
+}
+
+impl_field_tuple!(A);
+impl_field_tuple!(A, B);
+impl_field_tuple!(A, B, C);
+impl_field_tuple!(A, B, C, D);
+impl_field_tuple!(A, B, C, D, E);
+impl_field_tuple!(A, B, C, D, E, F);
+impl_field_tuple!(A, B, C, D, E, F, G);
+impl_field_tuple!(A, B, C, D, E, F, G, H);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::PrimitiveField;
+
+    #[test]
+    fn test_read_fields() {
+        let mut storage = vec![0; 1024];
+
+        type Field1 = PrimitiveField<u16, LittleEndian, 10>;
+        type Field2 = PrimitiveField<i64, BigEndian, 2>;
+        type Field3 = PrimitiveField<u8, BigEndian, 20>;
+
+        Field1::write(&mut storage, 1000);
+        Field2::write(&mut storage, -5);
+        Field3::write(&mut storage, 7);
+
+        assert_eq!(12, <(Field1, Field2)>::END);
+        assert_eq!(21, <(Field2, Field3, Field1)>::END);
+        assert_eq!((1000, -5), <(Field1, Field2)>::read_fields(&storage));
+        assert_eq!(
+            (-5, 7, 1000),
+            <(Field2, Field3, Field1)>::read_fields(&storage[..21])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_read_fields_too_small() {
+        let storage = vec![0; 20];
+        <(
+            PrimitiveField<u16, LittleEndian, 10>,
+            PrimitiveField<u8, BigEndian, 20>,
+        )>::read_fields(&storage);
+    }
+}
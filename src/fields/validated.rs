@@ -0,0 +1,162 @@
+use core::marker::PhantomData;
+
+use super::{
+    primitive::{FieldCopyAccess, FieldView},
+    Field, StorageIntoFieldView, StorageToFieldView,
+};
+
+/// Defines the invariant of a [ValidatedField]. The [define_layout!](crate::define_layout!) macro
+/// generates an implementation of this trait for each field declared with a `where` condition, you usually don't
+/// have to implement it yourself.
+pub trait FieldValidator<T> {
+    /// Source code of the condition, shown when a value fails validation.
+    const CONDITION: &'static str;
+
+    /// Return true if the value fulfills the invariant.
+    fn is_valid(value: &T) -> bool;
+}
+
+/// A [ValidatedField] is a [Field] that checks an invariant, defined by `V`, whenever it is read in debug builds.
+/// Reading a value that doesn't fulfill the invariant panics, which catches corrupt data early during development.
+/// In release builds, the check is compiled out and the field behaves exactly like the wrapped field.
+///
+/// Use this by declaring a field with a `where` condition in the [define_layout!](crate::define_layout!) macro.
+///
+/// # Example:
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   // ... other fields ...
+///   version: u8 where |v| v <= 3,
+///   // ... other fields ...
+/// });
+///
+/// fn func(storage_data: &mut [u8]) {
+///   my_layout::version::write(storage_data, 2);
+///   assert_eq!(2, my_layout::version::read(storage_data));
+/// }
+///
+/// # fn main() {
+/// #   let mut storage = [0; 1024];
+/// #   func(&mut storage);
+/// # }
+/// ```
+pub struct ValidatedField<V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess> {
+    _p1: PhantomData<V>,
+    _p2: PhantomData<F>,
+}
+
+impl<V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess> Field for ValidatedField<V, F> {
+    /// See [Field::Endian]
+    type Endian = F::Endian;
+    /// See [Field::OFFSET]
+    const OFFSET: usize = F::OFFSET;
+    /// See [Field::SIZE]
+    const SIZE: Option<usize> = F::SIZE;
+}
+
+impl<'a, V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess> StorageToFieldView<&'a [u8]>
+    for ValidatedField<V, F>
+{
+    type View = FieldView<&'a [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<'a, V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess> StorageToFieldView<&'a mut [u8]>
+    for ValidatedField<V, F>
+{
+    type View = FieldView<&'a mut [u8], Self>;
+
+    #[inline(always)]
+    fn view(storage: &'a mut [u8]) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<S: AsRef<[u8]>, V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess>
+    StorageIntoFieldView<S> for ValidatedField<V, F>
+{
+    type View = FieldView<S, Self>;
+
+    #[inline(always)]
+    fn into_view(storage: S) -> Self::View {
+        Self::View::new(storage)
+    }
+}
+
+impl<V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess> FieldCopyAccess
+    for ValidatedField<V, F>
+{
+    /// See [FieldCopyAccess::HighLevelType]
+    type HighLevelType = F::HighLevelType;
+
+    /// Read the field from a given data region, assuming the defined layout, using the [Field] API.
+    /// In debug builds, this panics if the value doesn't fulfill the invariant of the field.
+    ///
+    /// # Example:
+    /// See [ValidatedField] for an example
+    #[inline(always)]
+    fn read(storage: &[u8]) -> Self::HighLevelType {
+        let v = F::read(storage);
+        debug_assert!(
+            V::is_valid(&v),
+            "Error: Field value doesn't fulfill `{}`",
+            V::CONDITION
+        );
+        v
+    }
+
+    /// Write the field to a given data region, assuming the defined layout, using the [Field] API.
+    /// The value isn't validated.
+    ///
+    /// # Example:
+    /// See [ValidatedField] for an example
+    #[inline(always)]
+    fn write(storage: &mut [u8], v: Self::HighLevelType) {
+        F::write(storage, v)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::PrimitiveField;
+
+    struct AtMostThree;
+    impl FieldValidator<u16> for AtMostThree {
+        const CONDITION: &'static str = "|v| v <= 3";
+
+        fn is_valid(value: &u16) -> bool {
+            *value <= 3
+        }
+    }
+
+    type Field1 = ValidatedField<AtMostThree, PrimitiveField<u16, BigEndian, 5>>;
+
+    #[test]
+    fn test_validated() {
+        let mut storage = vec![0; 1024];
+
+        Field1::write(&mut storage, 3);
+        assert_eq!(3, Field1::read(&storage));
+        assert_eq!(&[0, 3], &storage[5..7]);
+
+        assert_eq!(5, Field1::OFFSET);
+        assert_eq!(Some(2), Field1::SIZE);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Error: Field value doesn't fulfill `|v| v <= 3`")]
+    fn test_invalid() {
+        let mut storage = vec![0; 1024];
+        Field1::write(&mut storage, 4);
+        Field1::read(&storage);
+    }
+}
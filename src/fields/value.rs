@@ -0,0 +1,388 @@
+#[cfg(feature = "bitflags")]
+use super::primitive::FlagsAs;
+use super::primitive::{
+    AlignTo, BitFields, BitFlags, BitOrder, BoundedBytes, ExcessK, FieldCopyAccess,
+    FieldSliceAccess, Fixed, FromEnd, Included, InvalidChar, MixedEndian, NanBoxed, NanBoxing,
+    NestedViewInfo, OnesComplement, PrimitiveField, RepeatedLayout, Scaled, SignMagnitude, Trailed,
+};
+#[cfg(feature = "time")]
+use super::primitive::{DurationAs, TimeUnit};
+use super::sentinel::{SentinelField, SentinelValue};
+use super::validated::{FieldValidator, ValidatedField};
+use super::wrapped::{LayoutAs, WrappedField};
+use super::Field;
+use crate::endianness::Endianness;
+use core::convert::TryInto;
+
+// `View::for_each_field` generated by the define_layout! macro passes each field as a [FieldValue] to a callback.
+// The [FieldToValue] implementations in this module define how each field type is represented there.
+
+/// The value of a field as passed to the callback of `View::for_each_field`,
+/// which is generated by the [define_layout!](crate::define_layout!) macro.
+///
+/// Wrapped fields (see [LayoutAs]) are represented by their underlying primitive value,
+/// and nested layouts and padding by the bytes they cover.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue<'a> {
+    /// An [i8] field, or a sign-magnitude or one's complement field stored in a [u8]
+    I8(i8),
+    /// An [i16] field, a sign-magnitude or one's complement field stored in a [u16], or an excess-K field stored in a [u8]
+    I16(i16),
+    /// An [i32] field, a sign-magnitude or one's complement field stored in a [u32], or an excess-K field stored in a [u16]
+    I32(i32),
+    /// An [i64] field, a sign-magnitude or one's complement field stored in a [u64], or an excess-K field stored in a [u32]
+    I64(i64),
+    /// An [i128] field, or an excess-K field stored in a [u64]
+    I128(i128),
+    /// A [u8] field
+    U8(u8),
+    /// A [u16] field
+    U16(u16),
+    /// A [u32] field
+    U32(u32),
+    /// A [u64] field, or the stored bits of a [NanBoxed](crate::NanBoxed) field
+    U64(u64),
+    /// A [u128] field
+    U128(u128),
+    /// An [f32] field
+    F32(f32),
+    /// An [f64] field, a [Fixed](crate::Fixed) point field or a [Scaled](crate::Scaled) field
+    F64(f64),
+    /// A [char] field, or the stored code point if it isn't a valid [char]
+    Char(Result<char, InvalidChar>),
+    /// A `()` field
+    Unit,
+    /// A [DurationAs](crate::time::DurationAs) field
+    #[cfg(feature = "time")]
+    Duration(core::time::Duration),
+    /// A byte array field, a nested layout, padding or an array of flags
+    Bytes(&'a [u8]),
+}
+
+/// Internal trait. Don't use this in user code.
+/// Implemented for fields that can be represented as a [FieldValue].
+pub trait FieldToValue: Field {
+    /// Read the field from the given storage as a [FieldValue]
+    fn read_value(storage: &[u8]) -> FieldValue<'_>;
+}
+
+macro_rules! copy_field_to_value {
+    ($type: ty, $variant: ident) => {
+        impl<E: Endianness, const OFFSET_: usize> FieldToValue
+            for PrimitiveField<$type, E, OFFSET_>
+        {
+            #[inline(always)]
+            fn read_value(storage: &[u8]) -> FieldValue<'_> {
+                FieldValue::$variant(Self::read(storage))
+            }
+        }
+    };
+}
+
+copy_field_to_value!(i8, I8);
+copy_field_to_value!(i16, I16);
+copy_field_to_value!(i32, I32);
+copy_field_to_value!(i64, I64);
+copy_field_to_value!(i128, I128);
+copy_field_to_value!(u8, U8);
+copy_field_to_value!(u16, U16);
+copy_field_to_value!(u32, U32);
+copy_field_to_value!(u64, U64);
+copy_field_to_value!(u128, U128);
+copy_field_to_value!(f32, F32);
+copy_field_to_value!(f64, F64);
+copy_field_to_value!(SignMagnitude<u8>, I8);
+copy_field_to_value!(SignMagnitude<u16>, I16);
+copy_field_to_value!(SignMagnitude<u32>, I32);
+copy_field_to_value!(SignMagnitude<u64>, I64);
+copy_field_to_value!(OnesComplement<u8>, I8);
+copy_field_to_value!(OnesComplement<u16>, I16);
+copy_field_to_value!(OnesComplement<u32>, I32);
+copy_field_to_value!(OnesComplement<u64>, I64);
+copy_field_to_value!(MixedEndian<u32>, U32);
+copy_field_to_value!(MixedEndian<i32>, I32);
+
+macro_rules! excess_k_to_value {
+    ($unsigned: ty, $variant: ident) => {
+        impl<E: Endianness, const K: i64, const OFFSET_: usize> FieldToValue
+            for PrimitiveField<ExcessK<$unsigned, K>, E, OFFSET_>
+        {
+            #[inline(always)]
+            fn read_value(storage: &[u8]) -> FieldValue<'_> {
+                FieldValue::$variant(Self::read(storage))
+            }
+        }
+    };
+}
+
+excess_k_to_value!(u8, I16);
+excess_k_to_value!(u16, I32);
+excess_k_to_value!(u32, I64);
+excess_k_to_value!(u64, I128);
+
+macro_rules! bitfields_to_value {
+    ($type: ty, $variant: ident) => {
+        impl<O: BitOrder, E: Endianness, const OFFSET_: usize> FieldToValue
+            for PrimitiveField<BitFields<$type, O>, E, OFFSET_>
+        {
+            #[inline(always)]
+            fn read_value(storage: &[u8]) -> FieldValue<'_> {
+                FieldValue::$variant(Self::read(storage))
+            }
+        }
+    };
+}
+
+bitfields_to_value!(u8, U8);
+bitfields_to_value!(u16, U16);
+bitfields_to_value!(u32, U32);
+bitfields_to_value!(u64, U64);
+
+impl<C: NanBoxing, E: Endianness, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<NanBoxed<C>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::U64(PrimitiveField::<u64, E, OFFSET_>::read(storage))
+    }
+}
+
+impl<U, E: Endianness, const NUM: i64, const DEN: i64, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<Scaled<U, NUM, DEN>, E, OFFSET_>
+where
+    Self: FieldCopyAccess<HighLevelType = f64>,
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::F64(Self::read(storage))
+    }
+}
+
+impl<E: Endianness, const I: usize, const F: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<Fixed<I, F>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::F64(Self::read(storage))
+    }
+}
+
+/// Flags fields are represented by their stored integer, including unknown bits.
+#[cfg(feature = "bitflags")]
+impl<T: bitflags::Flags, E: Endianness, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<FlagsAs<T>, E, OFFSET_>
+where
+    PrimitiveField<T::Bits, E, OFFSET_>: FieldToValue,
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        PrimitiveField::<T::Bits, E, OFFSET_>::read_value(storage)
+    }
+}
+
+#[cfg(feature = "time")]
+impl<U: TimeUnit, E: Endianness, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<DurationAs<U>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Duration(Self::read(storage))
+    }
+}
+
+impl<E: Endianness, const OFFSET_: usize> FieldToValue for PrimitiveField<char, E, OFFSET_> {
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Char(Self::read_code_point(storage))
+    }
+}
+
+impl<E: Endianness, const OFFSET_: usize> FieldToValue for PrimitiveField<(), E, OFFSET_> {
+    #[inline(always)]
+    fn read_value(_storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Unit
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<[u8; N], E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Bytes(Self::data(storage))
+    }
+}
+
+impl<E: Endianness, const OFFSET_: usize> FieldToValue for PrimitiveField<[u8], E, OFFSET_> {
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Bytes(Self::data(storage))
+    }
+}
+
+impl<E: Endianness, const MAX: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<BoundedBytes<MAX>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Bytes(Self::data(storage))
+    }
+}
+
+macro_rules! elements_field_to_value {
+    ($type: ty) => {
+        impl<E: Endianness, const OFFSET_: usize> FieldToValue
+            for PrimitiveField<[$type], E, OFFSET_>
+        {
+            #[inline(always)]
+            fn read_value(storage: &[u8]) -> FieldValue<'_> {
+                FieldValue::Bytes(&storage[Self::OFFSET..])
+            }
+        }
+    };
+}
+
+elements_field_to_value!(i8);
+elements_field_to_value!(i16);
+elements_field_to_value!(i32);
+elements_field_to_value!(i64);
+elements_field_to_value!(i128);
+elements_field_to_value!(u16);
+elements_field_to_value!(u32);
+elements_field_to_value!(u64);
+elements_field_to_value!(u128);
+elements_field_to_value!(f32);
+elements_field_to_value!(f64);
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<AlignTo<N>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Bytes(Self::data(storage))
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<[bool; N], E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        FieldValue::Bytes(&storage[Self::OFFSET..(Self::OFFSET + N)])
+    }
+}
+
+impl<E: Endianness, const N: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<BitFlags<N>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        let size = Self::SIZE.unwrap();
+        FieldValue::Bytes(&storage[Self::OFFSET..(Self::OFFSET + size)])
+    }
+}
+
+impl<N: NestedViewInfo, E: Endianness, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<N, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        match Self::SIZE {
+            Some(size) => FieldValue::Bytes(&storage[Self::OFFSET..(Self::OFFSET + size)]),
+            None => FieldValue::Bytes(&storage[Self::OFFSET..]),
+        }
+    }
+}
+
+impl<N: NestedViewInfo, E: Endianness, const COUNT: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<[N; COUNT], E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        // Repetitions of nested layouts always have a static size
+        let size = Self::SIZE.unwrap();
+        FieldValue::Bytes(&storage[Self::OFFSET..(Self::OFFSET + size)])
+    }
+}
+
+impl<N: NestedViewInfo, E: Endianness, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<[N], E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        let (_, region) = Self::region(storage.len());
+        FieldValue::Bytes(&storage[region])
+    }
+}
+
+impl<N: NestedViewInfo, C: FieldCopyAccess, E: Endianness, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<RepeatedLayout<N, C>, E, OFFSET_>
+where
+    C::HighLevelType: TryInto<usize>,
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        let (_, region) = Self::region(storage);
+        FieldValue::Bytes(&storage[region])
+    }
+}
+
+impl<F: FieldToValue, E: Endianness, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<Included<F>, E, OFFSET_>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        F::read_value(storage)
+    }
+}
+
+/// The open ended field before the trailer of a layout is represented like the open ended field, without the trailer bytes.
+impl<T: ?Sized, E: Endianness, const TRAILER_SIZE: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<Trailed<T, TRAILER_SIZE>, E, OFFSET_>
+where
+    PrimitiveField<T, E, OFFSET_>: FieldToValue,
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        PrimitiveField::<T, E, OFFSET_>::read_value(&storage[..Self::byte_range(storage.len()).end])
+    }
+}
+
+/// Fields in the trailer of a layout are represented like the field they wrap.
+impl<T: ?Sized, E: Endianness, const FROM_END: usize, const OFFSET_: usize> FieldToValue
+    for PrimitiveField<FromEnd<T, FROM_END>, E, OFFSET_>
+where
+    PrimitiveField<T, E, 0>: FieldToValue,
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        PrimitiveField::<T, E, 0>::read_value(&storage[Self::byte_range(storage.len()).start..])
+    }
+}
+
+impl<U, T: LayoutAs<U>, F: FieldToValue> FieldToValue for WrappedField<U, T, F> {
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        F::read_value(storage)
+    }
+}
+
+/// Sentinel fields are represented by their stored value, even if it is the sentinel value.
+impl<V: SentinelValue<F::HighLevelType>, F: FieldCopyAccess + FieldToValue> FieldToValue
+    for SentinelField<V, F>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        F::read_value(storage)
+    }
+}
+
+/// Validated fields are represented by their stored value without checking the invariant.
+impl<V: FieldValidator<F::HighLevelType>, F: FieldCopyAccess + FieldToValue> FieldToValue
+    for ValidatedField<V, F>
+{
+    #[inline(always)]
+    fn read_value(storage: &[u8]) -> FieldValue<'_> {
+        F::read_value(storage)
+    }
+}
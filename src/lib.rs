@@ -64,10 +64,11 @@
 //! without padding. But it has serious shortcomings that this library solves.
 //! - `#[repr(packed)]` uses the system byte order, which will be different depending on if you're running on a little endian or big endian system. `#[repr(packed)]` is not cross-platform compatible. This library is.
 //! - `#[repr(packed)]` [can cause undefined behavior on some CPUs when taking references to unaligned data](https://doc.rust-lang.org/nomicon/other-reprs.html#reprpacked).
-//!    This library avoids that by not offering any API that takes references to unaligned data. The only data type you can get a reference to is byte arrays, and they only require an alignment of 1 which is trivially always fulfilled.
+//!   This library avoids that by not offering any API that takes references to unaligned data. The only data type you can get a reference to is byte arrays, and they only require an alignment of 1 which is trivially always fulfilled.
 //!
 //! ## When not to use this library?
-//! - You need dynamic data structures, e.g. a list that can change size. This library only supports static data layouts (with the exception of open ended byte arrays at the end of a layout).
+//! - You need dynamic data structures, e.g. several lists that can change size or variable length data in the middle of a record. Fields in the middle of a layout have a fixed size,
+//!   only the end of a layout can hold data with a dynamic length, see [Data types with dynamic length](#data-types-with-dynamic-length).
 //! - Not all of your layout fits into the memory and you need to process streams of data.
 //!   Note that this crate can still be helpful if you have smaller layouted packages as part of a larger stream, as long as any one layouted packet fits into memory.
 //!
@@ -85,6 +86,8 @@
 //!
 //! ## Supported field types
 //!
+//! Using a field type that isn't supported fails to compile with an error that lists the supported field types.
+//!
 //! ### Primitive integer types
 //! - [u8](https://doc.rust-lang.org/stable/std/primitive.u8.html), [u16](https://doc.rust-lang.org/stable/std/primitive.u16.html), [u32](https://doc.rust-lang.org/stable/std/primitive.u32.html), [u64](https://doc.rust-lang.org/stable/std/primitive.u64.html), [u128](https://doc.rust-lang.org/stable/std/primitive.u128.html)
 //! - [i8](https://doc.rust-lang.org/stable/std/primitive.i8.html), [i16](https://doc.rust-lang.org/stable/std/primitive.i16.html), [i32](https://doc.rust-lang.org/stable/std/primitive.i32.html), [i64](https://doc.rust-lang.org/stable/std/primitive.i64.html), [i128](https://doc.rust-lang.org/stable/std/primitive.i128.html)
@@ -92,6 +95,11 @@
 //! ### Primitive float types
 //! - [f32](https://doc.rust-lang.org/std/primitive.f32.html), [f64](https://doc.rust-lang.org/std/primitive.f64.html)
 //!
+//! ### Characters
+//! - [char](https://doc.rust-lang.org/std/primitive.char.html), stored as its `u32` code point in the endianness of the layout.
+//!
+//! Since not every `u32` is a valid `char`, reading a `char` field returns a `Result<char, LayoutError>`, with a `LayoutError::InvalidChar` error for invalid code points.
+//!
 //! ### Primitive Zero-Sized Types (ZSTs)
 //!
 //! ZSTs neither read nor write to the underlying storage, but the appropriate traits are implemented for them to support derive macros which may require all members of a struct to implement or enum to also support the various traits.
@@ -101,41 +109,110 @@
 //! For these fields, the [trait@Field] API offers [FieldCopyAccess::read], [FieldCopyAccess::write] and the [struct@FieldView] API offers [FieldView::read] and [FieldView::write].
 //!
 //! ### Fixed size byte arrays: `[u8; N]`.
+//! `N` can be an integer literal or any const expression, e.g. a named `const` from your crate.
+//! Zero-length arrays like `[u8; 0]` fail to compile, use a `()` field for a field that doesn't take up any space.
 //! For these fields, the [trait@Field] API offers [FieldSliceAccess::data], [FieldSliceAccess::data_mut], and the [struct@FieldView] API returns a slice.
+//! To copy the data into a caller provided buffer instead of borrowing the storage, the [trait@Field] API offers `copy_into(storage, dst)`, which returns a [LengthError] if `dst` is too short.
+//! To fill the byte array from an iterator, the [trait@Field] API offers `write_from_iter(storage, iter)`, which returns an [IterLengthError] if `iter` doesn't yield exactly as many bytes as the byte array has.
 //!
 //! ### Open ended byte arrays: `[u8]`.
 //! This field type can only occur as the last field of a layout and will mach the remaining data until the end of the storage.
+//! The only fields that can follow it are fields anchored to the end of the storage, e.g. a checksum, declared in a `@trailer { ... }` section, see [define_layout!]. The byte array then stops where these fields start.
 //! This field has a dynamic size, depending on how large the package data is.
 //! For these fields, the [trait@Field] API offers [FieldSliceAccess::data], [FieldSliceAccess::data_mut] and the [struct@FieldView] API returns a slice.
+//! To copy the data into a caller provided buffer instead of borrowing the storage, the [trait@Field] API offers `copy_into(storage, dst)`, which returns a [LengthError] if `dst` is too short.
+//! To fill the byte array from an iterator, the [trait@Field] API offers `write_from_iter(storage, iter)`, which returns an [IterLengthError] if `iter` doesn't yield exactly as many bytes as the byte array has.
+//! If the byte array holds text, e.g. the message of a log entry, the [BytesAsStr] trait from the prelude offers `view.message().as_str()`, which validates that the whole byte array is UTF-8, and `to_str_lossy()`.
+//!
+//! ### Open ended byte arrays with a maximum length: `[u8; ..=MAX]`.
+//! Like `[u8]`, but the open ended byte array may be at most `MAX` bytes long, e.g. `payload: [u8; ..=64]`. Accessing the field works like for `[u8]`,
+//! and `View::new_exact` returns a `LayoutError::BufferTooLarge`
+//! error if the storage is longer than the `MAX_SIZE` of the layout. This guards against oversized messages, e.g. in environments with fixed-size buffers.
+//!
+//! ### Open ended arrays of numbers: `[T]`.
+//! Like `[u8]`, an open ended array of any other integer or float type, e.g. `samples: [u16]`, can only occur as the last field of a layout.
+//! The [struct@FieldView] API returns an [ElementsView] with a `len()` in number of elements, `get(index)`, `set(index, value)` and `iter()`,
+//! which read and write the elements in the endianness of the layout.
+//! To write all elements from an iterator, it offers `write_from_iter(iter)`, which returns an [IterLengthError] if `iter` doesn't yield exactly `len()` elements.
+//! For bulk processing, `copy_into(dst)` and `to_vec()` decode all elements at once, independent of the alignment of the storage.
+//! To keep the decoded elements of an owning view without the view, `into_elements()` consumes it and returns an iterator that owns the storage.
+//! With [NativeEndian] as the endianness of the layout, decoding doesn't need to swap any bytes.
+//!
+//! ### Durations: `DurationAs<Unit>`.
+//! If the `time` feature is enabled, the `time` module offers a field type that stores a [Duration](https://doc.rust-lang.org/core/time/struct.Duration.html)
+//! as a `u64` number of ticks, e.g. `timestamp: DurationAs<Milliseconds>` for milliseconds since the unix epoch.
+//! The type parameter defines the unit: `Seconds`, `Milliseconds`, `Microseconds` or `Nanoseconds`.
+//!
+//! ### Flags: `FlagsAs<T>`.
+//! If the `bitflags` feature is enabled, a field of type `FlagsAs<T>` stores a flags type `T` generated by the [bitflags](https://docs.rs/bitflags) crate as its underlying integer.
+//! `read()` masks out unknown bits, while `try_read()` returns an error if any unknown bits are set.
+//!
+//! ### Serde encoded structs in byte arrays.
+//! If the `serde` feature is enabled, a byte array field `[u8; N]` or `[u8]` can hold a nested struct encoded with a serde based format like bincode.
+//! The [trait@Field] API offers `read_decoded::<C, T>(storage)` to decode a `T: DeserializeOwned` and `write_encoded::<C, T>(storage, &value)` to encode it,
+//! where `C` implements `SerdeCodec` for the format you use. This crate doesn't depend on any serde format itself.
+//!
+//! ### Alignment padding: `AlignTo<N>`.
+//! This field type inserts the minimal number of padding bytes needed to bring the offset of the next field to a multiple of `N`,
+//! e.g. to match the layout of a C struct with natural alignment. The padding bytes can be accessed like a byte array.
+//!
+//! ### Legacy signed integer encodings: `SignMagnitude<U>` and `OnesComplement<U>`.
+//! These field types store a signed integer in sign-magnitude or one's complement encoding instead of two's complement, e.g. `SignMagnitude<u16>` is read and written as an `i16`.
+//! Negative zero is read as `0`.
+//!
+//! ### Biased integers: `ExcessK<U, K>`.
+//! This field type stores a signed integer `v` as the unsigned integer `v + K` in excess-K (biased) encoding, e.g. `ExcessK<u16, 1023>` for the exponent of an `f64`.
+//! It is read and written as the next wider signed integer type, e.g. `i32` for `ExcessK<u16, K>`, and the raw unsigned integer uses the endianness of the layout.
+//!
+//! ### PDP-11 mixed endian integers: `MixedEndian<U>`.
+//! This field type stores a `u32` or `i32` in the byte order of the PDP-11, i.e. as two little endian 16-bit words with the most significant word first,
+//! e.g. `0x0A0B0C0D` is stored as `[0x0B, 0x0A, 0x0D, 0x0C]`. The byte order doesn't depend on the endianness of the layout.
+//!
+//! ### Fixed-point numbers: `Fixed<I, F>`.
+//! This field type stores a signed fixed-point number with `I` integer bits (including the sign bit) and `F` fraction bits, e.g. `Fixed<16, 16>` for Q16.16.
+//! It is read and written as an `f64`, and the underlying integer of `I + F` bits uses the endianness of the layout.
+//!
+//! ### Scaled integers: `Scaled<U, NUM, DEN>`.
+//! This field type stores a physical quantity as a raw integer `U` scaled by the factor `NUM / DEN`, e.g. `Scaled<u16, 1, 100>` for a value in steps of 0.01.
+//! It is read as an `f64` of the raw integer times the factor, and writing divides by the factor and rounds to the nearest integer. The raw integer uses the endianness of the layout.
+//!
+//! ### NaN-boxed values: `NanBoxed<C>`.
+//! This field type stores a NaN-boxed `u64`, which holds either a float or a tagged value in the payload bits of a NaN, e.g. for the values of a scripting VM.
+//! It is read and written as the tagged value of the NaN-boxing scheme `C`. The default scheme `DefaultNanBoxing` distinguishes floats, integers and pointers, and other conventions can be plugged in by implementing `NanBoxing`.
+//!
+//! ### Boolean flags: `[bool; N]` and `BitFlags<N>`.
+//! A `[bool; N]` field stores `N` booleans as one byte each, a `BitFlags<N>` field packs `N` booleans into bits, taking `N / 8` bytes rounded up.
+//! Both field types provide `get(i)` and `set(i, value)` accessors for single flags.
+//!
+//! ### Bit fields: `BitFields<U, O>`.
+//! A `BitFields<U, O>` field stores an unsigned integer `U` that packs several values into ranges of its bits, and provides
+//! `read_bits(range)` and `write_bits(range, value)` accessors for them. The bit order `O` defines how the bits are numbered:
+//! `LsbFirst` (the default) numbers them starting at the least significant bit, `MsbFirst` starting at the most significant bit like many RFC diagrams do.
+//!
+//! ### Sentinel values
+//! Integer and float fields can be declared with a sentinel value that marks the field as absent, e.g. `id: u32 = sentinel(0xFFFF_FFFF)`.
+//! Reading such a field returns an `Option`, which is `None` if the field holds the sentinel value, and writing `None` stores the sentinel value.
+//!
+//! ### Validated fields
+//! Fields with copy access can be declared with an invariant that is checked on every read in debug builds, e.g. `version: u8 where |v| v <= 3`.
+//! Reading a value that doesn't fulfill it panics, which catches corrupt data early during development. Release builds don't check it.
 //!
 //! ### Custom field types
 //! You can define your own custom types as long as they implement the [trait@LayoutAs] trait to define how to convert them from/to a primitive type.
 //!
-//! # Data types maybe supported in the future
-//! These data types aren't supported yet, but they could be added in theory and might be added in future versions.
-//! - [bool](https://doc.rust-lang.org/stable/std/primitive.bool.html) stored as 1 byte
-//! - [bool](https://doc.rust-lang.org/stable/std/primitive.bool.html) stored as 1 bit
-//!
-//! ### Data types with dynamic length
-//! This crate relies on a static layout, it cannot support data types with dynamic length.
-//! In theory, types with dynamic length could be supported if they either
-//! - are the last field of a layout, an already implemented example of this are open ended byte arrays.
-//! - or they may be in the middle of the package but have a maximal size defined and will always reserve storage for their maximal size, even if smaller.
-//!   This way, the fields after it would still have a constant offset.
-//!
-//! Both of these, however, would be some effort to implement and it is unclear if that will ever happen (unless somebody opens a PR for it).
-//!
-//! ### Strings
-//! For strings, note that even fixed-size UTF-8 strings take a variable number of bytes because of the UTF-8 encoding and that brings all the issues of data types with dynamic length with it.
-//! This is why strings aren't supported yet.
+//! # Data types with dynamic length
+//! This crate relies on a static layout, so all fields in the middle of a layout have a fixed size and a constant offset. Data with a dynamic length is supported
+//! - at the end of a layout, as an open ended array like `[u8]`, `[u8; ..=MAX]` or `[T]`, optionally followed by a `@trailer` section,
+//!   or as repeated nested layouts like `RepeatedLayout<other_layout::NestedView, count_field>` or `[other_layout::NestedView]`,
+//! - or as optional fields whose presence is stored in a bitmap at the beginning of the layout, see [define_layout!].
 //!
-//! ### Fixed-size arrays other than `[u8; N]`
-//! Say we wanted to have a `[u32; N]` field. The API couldn't just return a zero-copy `&[u32; N]` to the caller because that would use the system byte order (i.e. endianness) which might be different from the byte order defined in the package layout.
-//! To make this cross-platform compatible, we'd have to wrap these slices into our own slice type that enforces the correct byte order and return that from the API.
-//! This complexity is why it wasn't implemented yet, but feel free to open a PR if you need this.
+//! Text stored in a byte array can be read with the [BytesAsStr] trait. For a fixed number of integers or floats, e.g. `[u32; 4]`, declare a layout with a single field
+//! and repeat it as `[other_layout::NestedView; 4]`, see [Nesting](#nesting). Each element is then read and written in the endianness of that layout.
 //!
 //! # Nesting
 //! Layouts can be nested within each other by using the `NestedView` type created by the [define_layout!] macro for one layout as a field type in another layout.
+//! The accessors of a nested field return a `View` of the nested layout over the bytes of that field, e.g. `header_mut()` returns an `icmp_header::View<&mut [u8]>`
+//! that mutably borrows the outer view, so `view.header_mut().checksum_mut().write(value)` writes the checksum at its absolute offset in the storage of the outer view.
 //!
 //! Example:
 //! ```
@@ -186,6 +263,61 @@
 //! });
 //! # fn main() {}
 //! ```
+//!
+//! Repeated nested layouts can be declared as `[other_layout::NestedView; N]` for a number of repetitions known at compile time,
+//! or as `RepeatedLayout<other_layout::NestedView, count_field>` at the end of a layout, with the number of repetitions read from another field.
+//! To repeat a nested layout until the end of the storage, declare the last field as `[other_layout::NestedView]`. The number of repetitions is then the number of whole records
+//! that fit into the remaining storage, and trailing bytes that don't make up a whole record are ignored.
+//! Accessing such a field returns a [RepeatedView] with bounds checked `get(index)` and `get_mut(index)` accessors and an `iter()` over a `View` for each repetition.
+//!
+//! # Tagged layouts
+//! If the first field of a format selects among several layouts, e.g. a message type, the [define_layout_enum!] macro generates a `View` enum
+//! with a variant for each layout and a `parse(storage)` function that reads the tag and returns the variant holding a view of the selected layout.
+//!
+//! # Migrating between layouts
+//! To migrate a record between two versions of a format, [migrate()] copies each field of one view into the field of another view
+//! that has the same name and type, leaving the other fields of the destination untouched.
+//!
+//! # Writing records in order
+//! To write a record field by field, the `cursor(storage)` function of the layout returns a [LayoutCursor] that writes the fields in the order they were declared.
+//! It panics if a field is written out of order, so forgotten fields are caught while writing the record instead of leaving stale bytes behind.
+//!
+//! # Storage behind a lock
+//! Guards like `MutexGuard<Vec<u8>>`, `RwLockWriteGuard<Vec<u8>>` or `RefMut<[u8]>` don't implement `AsRef<[u8]>` themselves,
+//! but they dereference to a storage that does. Create the view from a reborrow like `&mut *guard` or `&mut guard[..]`,
+//! and it can read and write the locked storage for as long as the guard is held.
+//!
+//! # Non-contiguous storage
+//! If the data of a layout arrives in several buffers and the `std` feature is enabled, a `ChunkedReader` reads fields with copy access from the ordered chunks
+//! without reassembling them first. Only the bytes of fields spanning a boundary between chunks are copied.
+//!
+//! # Framed streams
+//! To send records over a stream like a socket, where the receiver needs to know where each record ends, `View::write_framed(w)`
+//! writes the record to an `std::io::Write` prefixed with its length as a `u32` in the endianness of the layout.
+//! `View::read_framed(r)` reads such a record back from an `std::io::Read` into an owning view. Both require the `std` feature.
+//!
+//! # Copy-on-write storage
+//! To read from a borrowed buffer and only copy it once a field is written, `View::from_cow(cow)` creates a view over a `Cow<[u8]>`.
+//! Since `Cow<[u8]>` doesn't implement `AsMut<[u8]>`, the view wraps it in a `CowStorage`, whose mutable accessors clone borrowed data into an owned buffer on the first write.
+//! `view.storage.is_owned()` tells whether that happened and `view.into_storage().into_cow()` returns the `Cow`. This requires the `std` feature.
+//!
+//! # Split header and body
+//! If the fields before the open ended field of a layout and the open ended field itself live in different allocations, `View::new_split(header, body)`
+//! creates a `SplitView` without concatenating them. It dereferences to a `View` over the header for all other fields, and `body()` and `body_mut()` access the open ended field.
+//!
+//! # Memory mapped files
+//! Since views work with any storage that implements `AsRef<[u8]>`, you can create views directly over a memory mapped file.
+//! If the `memmap2` feature is enabled, `View::from_mmap(mmap)` creates a read-only view over a whole [memmap2](https://docs.rs/memmap2) `Mmap` after checking
+//! that it is large enough for the layout. For files holding a sequence of records, the `records(storage)` function generated for each layout
+//! iterates over a view for each record.
+//!
+//! # Error handling
+//! Fallible operations return the common `LayoutError`, e.g. `View::new_exact()`, `ValidatedView::try_new()`, the `peek_${field_name}()` functions,
+//! `data_checked()`, `copy_into()`, `write_from_iter()`, `write_checked()`, `try_read()`, `as_str()` and reading a `char` field.
+//! Only `Builder::build()` returns a `MissingFields` error listing all missing fields, which converts into `LayoutError` as well.
+//! `LayoutError` implements `std::error::Error` if the `std` feature is enabled, so you can propagate it with the `?` operator
+//! into a `Box<dyn Error>` or your application's error type. The `error` module also contains types like `BufferTooSmall`, `InvalidChar`
+//! or `TooShort` that describe a single kind of failure and convert into `LayoutError`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
@@ -193,38 +325,172 @@
 
 mod endianness;
 mod fields;
+mod macro_assert_layout;
 mod macro_define_layout;
+mod macro_define_layout_enum;
 mod utils;
 
 pub mod example;
 
-pub use endianness::{BigEndian, Endianness, LittleEndian};
+/// Field types for storing durations and timestamps. This module is only available if the `time` feature is enabled.
+#[cfg(feature = "time")]
+pub mod time {
+    pub use crate::fields::primitive::{
+        DurationAs, Microseconds, Milliseconds, Nanoseconds, Seconds, TimeUnit,
+    };
+}
+
+/// Error types of this crate. Fallible operations return [LayoutError], which implements `std::error::Error`
+/// if the `std` feature is enabled. The other types describe a single kind of failure and convert into it.
+pub mod error {
+    #[cfg(feature = "bitflags")]
+    pub use crate::fields::primitive::UnknownFlags;
+    pub use crate::fields::{
+        error::LayoutError,
+        peek::BufferTooSmall,
+        primitive::{InvalidChar, IterLengthError, LengthError, RangeError, TooShort},
+    };
+}
+
+pub use endianness::{BigEndian, Endianness, LittleEndian, NativeEndian};
+#[cfg(feature = "std")]
+pub use fields::chunked::ChunkedReader;
+#[cfg(feature = "std")]
+pub use fields::owned::MissingFields;
+#[cfg(feature = "serde")]
+pub use fields::primitive::SerdeCodec;
+#[cfg(feature = "bitflags")]
+pub use fields::primitive::{FlagsAs, UnknownFlags};
 pub use fields::{
-    primitive::{FieldCopyAccess, FieldSliceAccess, FieldView, PrimitiveField},
+    absolute_offset,
+    access::{ReadField, WriteField},
+    check_field_bounds,
+    cursor::LayoutCursor,
+    error::LayoutError,
+    info::{FieldInfo, FieldMeta},
+    migrate::{migrate, LayoutView, LayoutViewMut},
+    peek::BufferTooSmall,
+    primitive::{
+        AlignTo, BitFields, BitFlags, BitOrder, BoundedBytes, BytesAsStr, DefaultNanBoxing,
+        ElementsIntoIter, ElementsView, ExcessK, FieldCopyAccess, FieldSliceAccess, FieldView,
+        Fixed, InvalidChar, IterLengthError, LengthError, LsbFirst, MixedEndian, MsbFirst,
+        NanBoxed, NanBoxedValue, NanBoxing, OnesComplement, PrimitiveField, RangeError,
+        RepeatedLayout, RepeatedView, Scaled, SignMagnitude, TooShort,
+    },
+    sentinel::{SentinelField, SentinelValue},
+    tuple::FieldTuple,
+    validated::{FieldValidator, ValidatedField},
+    value::FieldValue,
     wrapped::{LayoutAs, WrappedField},
     Field,
 };
+#[cfg(feature = "std")]
+pub use utils::cow::CowStorage;
 pub use utils::data::Data;
 
 /// Import this to get everything into scope that you need for defining and using layouts.
+/// This includes the field types and traits you reference in layout definitions, the types
+/// returned by the generated accessors like [FieldValue] and [LayoutError], and the [LayoutView]
+/// and [LayoutViewMut] traits offering helpers on the whole storage of a view.
 ///
 /// # Example
 /// ```
 /// use binary_layout::prelude::*;
+///
+/// struct Celsius(i16);
+/// impl LayoutAs<i16> for Celsius {
+///   fn read(v: i16) -> Celsius {
+///     Celsius(v)
+///   }
+///   fn write(v: Celsius) -> i16 {
+///     v.0
+///   }
+/// }
+///
+/// define_layout!(sensor_record, LittleEndian, {
+///   flags: BitFields<u8, MsbFirst>,
+///   offset: SignMagnitude<u8>,
+///   _padding: AlignTo<4>,
+///   position: Fixed<16, 16>,
+///   temperature: Celsius as i16,
+///   unit: char,
+/// });
+///
+/// fn parse(storage: &[u8]) -> Result<(u8, f64), LayoutError> {
+///   let view = sensor_record::View::new_exact(storage)?;
+///   let mut unit = None;
+///   view.for_each_field(|name, _offset, value| {
+///     if let ("unit", FieldValue::Char(value)) = (name, value) {
+///       unit = Some(value);
+///     }
+///   });
+///   assert_eq!(Some(Ok('C')), unit);
+///   Ok((view.flags().read_bits(0..4), view.position().read()))
+/// }
+///
+/// # fn main() {
+/// let mut storage = [0; 14];
+/// let mut view = sensor_record::View::new(&mut storage[..]);
+/// view.flags_mut().write_bits(0..4, 0b1010);
+/// view.position_mut().write(1.5);
+/// view.temperature_mut().write(Celsius(-5));
+/// view.unit_mut().write('C');
+/// assert_eq!(Ok((0b1010, 1.5)), parse(&storage));
+/// # }
 /// ```
 pub mod prelude {
-    pub use super::{BigEndian, Field, FieldCopyAccess, FieldSliceAccess, LittleEndian};
+    #[cfg(feature = "time")]
+    pub use super::time::{DurationAs, Microseconds, Milliseconds, Nanoseconds, Seconds};
+    #[cfg(feature = "bitflags")]
+    pub use super::FlagsAs;
+    pub use super::{
+        AlignTo, BigEndian, BitFields, BitFlags, BytesAsStr, ExcessK, Field, FieldCopyAccess,
+        FieldInfo, FieldSliceAccess, FieldValue, Fixed, LayoutAs, LayoutError, LayoutView,
+        LayoutViewMut, LittleEndian, LsbFirst, MixedEndian, MsbFirst, NanBoxed, NativeEndian,
+        OnesComplement, ReadField, RepeatedLayout, Scaled, SignMagnitude, WriteField,
+    };
     pub use crate::define_layout;
 }
 
 /// Internal things that need to be exported so our macros can use them. Don't use directly!
 #[doc(hidden)]
 pub mod internal {
+    #[cfg(feature = "std")]
+    pub use crate::fields::framed::{read_framed, write_framed};
+    #[cfg(feature = "std")]
+    pub use crate::fields::owned::{
+        FieldFromOwned, FieldToOwned, OwnedNestedView, OwnedNestedViewMut,
+    };
     pub use crate::fields::{
-        primitive::{BorrowingNestedView, NestedViewInfo, OwningNestedView},
+        check_field_region, copy_record, field_bytes_eq, field_region,
+        info::{
+            check_ascending_offsets, check_no_overlap, field_info, fields_prefix_compatible,
+            tail_offset,
+        },
+        matches_template,
+        optional::{check_optional_field_count, optional_field_offset, PresenceBitmap},
+        peek::peek,
+        primitive::{
+            BorrowingNestedView, FromEnd, Included, NestedViewInfo, OwningNestedView, Trailed,
+            WideInt,
+        },
+        records,
+        size::{
+            check_exact_size, check_max_size, check_min_size, max_size, min_size, FixedSizeLayout,
+            OpenEndedLayout, SizeCheck, SplittableLayout,
+        },
+        value::FieldToValue,
         StorageIntoFieldView, StorageToFieldView,
     };
-    pub use crate::macro_define_layout::{option_usize_add, unwrap_field_size};
+    pub use crate::macro_define_layout::{
+        check_array_len, check_repr_c, check_size_budget, declared_size_padding, option_usize_add,
+        trailed_field_offset, unwrap_field_size,
+    };
+    pub use crate::{if_memmap2, if_std};
     pub use doc_comment::doc_comment;
+    #[cfg(feature = "memmap2")]
+    pub use memmap2::Mmap;
     pub use paste::paste;
+    #[cfg(feature = "std")]
+    pub use std::{borrow::Cow, boxed::Box, io, string::String, vec::Vec};
 }
@@ -0,0 +1,59 @@
+/// This macro checks at compile time that two layouts defined with [crate::define_layout!] have a compatible prefix,
+/// i.e. that the fields of the shorter layout have the same offsets and sizes as the first fields of the longer layout.
+/// Field names and types aren't compared. Compilation fails if the layouts aren't compatible.
+///
+/// This is useful to guard versioned formats that share a common prefix before reinterpreting data of one version as another.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::assert_layout_prefix_compatible;
+///
+/// define_layout!(header_v1, LittleEndian, {
+///   version: u16,
+///   length: u32,
+/// });
+///
+/// define_layout!(header_v2, LittleEndian, {
+///   version: u16,
+///   size: u32,
+///   flags: u8,
+/// });
+///
+/// assert_layout_prefix_compatible!(header_v1, header_v2);
+/// # fn main() {}
+/// ```
+///
+/// Compilation fails for incompatible layouts:
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+/// use binary_layout::assert_layout_prefix_compatible;
+///
+/// define_layout!(header_v1, LittleEndian, {
+///   version: u16,
+///   length: u32,
+/// });
+///
+/// define_layout!(header_v2, LittleEndian, {
+///   version: u16,
+///   length: u64,
+/// });
+///
+/// assert_layout_prefix_compatible!(header_v1, header_v2);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_layout_prefix_compatible {
+    ($($lhs: ident)::+, $($rhs: ident)::+ $(,)?) => {
+        const _: () = assert!(
+            $crate::internal::fields_prefix_compatible($($lhs)::+::FIELDS, $($rhs)::+::FIELDS),
+            concat!(
+                "Layouts `",
+                stringify!($($lhs)::+),
+                "` and `",
+                stringify!($($rhs)::+),
+                "` don't have a compatible prefix"
+            ),
+        );
+    };
+}
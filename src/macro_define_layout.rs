@@ -9,7 +9,7 @@
 ///
 /// # API
 /// ```text
-/// define_layout!(<<Name>>, <<Endianness>>, {
+/// define_layout!(<<Visibility>>? <<Name>>, <<Endianness>>, {
 ///   <<FieldName>>: <<FieldType>>,
 ///   <<FieldName>>: <<FieldType>>,
 ///   ...
@@ -18,7 +18,9 @@
 ///
 /// ## Field names
 /// Field names can be any valid Rust identifiers, but it is recommended to avoid names that contain `storage`, `into_` or `_mut`.
-/// This is because the [define_layout!] macro creates a [View class with several accessors](#struct-view) for each field that contain those identifier parts.
+/// This is because the [crate::define_layout!] macro creates a [View class with several accessors](#struct-view) for each field that contain those identifier parts.
+/// Helpers working on the whole storage, like `len()` or `as_ptr()`, are methods of the [LayoutView](crate::LayoutView) trait instead of the `View`,
+/// so fields can have the same name as them.
 ///
 /// ## Example
 /// ```
@@ -33,6 +35,255 @@
 /// });
 /// ```
 ///
+/// ## Field documentation
+/// Doc comments on fields are forwarded to the generated field type, the [View accessors](#struct-view) and the [Owned](#struct-owned) struct,
+/// so they show up in the rustdoc of your layout.
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(icmp_header, BigEndian, {
+///   /// The type of the ICMP message, e.g. 8 for an echo request
+///   packet_type: u8,
+///   /// Further specifies the message within its type
+///   code: u8,
+///   /// Internet checksum over the whole ICMP message
+///   checksum: u16,
+/// });
+/// ```
+///
+/// ## Visibility
+/// By default, the generated module is `pub`. You can restrict its visibility by putting a visibility qualifier
+/// like `pub(crate)`, `pub(super)` or `pub(self)` in front of the layout name. This keeps the generated module
+/// and its `View` out of your crate's public API.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(pub(crate) internal_header, LittleEndian, {
+///   version: u16,
+///   flags: u16,
+/// });
+/// ```
+///
+/// Code outside of the visibility scope can't access the layout:
+///
+/// ```compile_fail
+/// mod inner {
+///   use binary_layout::prelude::*;
+///
+///   define_layout!(pub(self) hidden_layout, LittleEndian, {
+///     field: u16,
+///   });
+/// }
+///
+/// let view = inner::hidden_layout::View::new(vec![0; 2]);
+/// ```
+///
+/// ## Layouts inside functions
+/// Layouts can also be defined inside a function body. Everything the macro generates lives in the module of the layout,
+/// so several layouts in the same scope never clash, even if they have fields with the same names.
+/// However, the field types of such a layout can't refer to other items defined in the same function, e.g. a nested
+/// or included layout, because Rust doesn't make the items of a function visible to modules declared in it.
+/// Define those items outside of the function instead.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// # fn main() {
+/// define_layout!(request, LittleEndian, {
+///   id: u32,
+///   payload: [u8],
+/// });
+/// define_layout!(response, BigEndian, {
+///   id: u32,
+///   payload: [u8],
+/// });
+///
+/// assert_eq!(request::payload::OFFSET, response::payload::OFFSET);
+/// # }
+/// ```
+///
+/// ## Generic endianness
+/// If the same format appears in both endiannesses, pass `Endian` instead of an endianness. The macro then
+/// instantiates the layout twice, in the `big_endian` and `little_endian` submodules of the generated module,
+/// and offers a `View<S, E>` type alias that selects the instantiation based on the type parameter `E`.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(message, Endian, {
+///   id: u16,
+///   value: u32,
+/// });
+///
+/// # fn main() {
+/// let mut view = message::View::<_, BigEndian>::new(vec![0; message::SIZE.unwrap()]);
+/// view.id_mut().write(1);
+/// assert_eq!(&[0, 1, 0, 0, 0, 0], &*view.into_storage());
+///
+/// let mut view = message::View::<_, LittleEndian>::new(vec![0; message::SIZE.unwrap()]);
+/// view.id_mut().write(1);
+/// assert_eq!(&[1, 0, 0, 0, 0, 0], &*view.into_storage());
+/// # }
+/// ```
+///
+/// ## Including layouts
+/// Several layouts can share a common prefix, e.g. a header. Instead of nesting it, you can include a layout
+/// as the first entry of another layout with `@include`. All fields of the included layout are then also
+/// fields of the including layout, with the same offsets and accessors, and the fields following the include
+/// start after the included layout. The included fields keep the endianness of the included layout.
+/// The included layout must have a fixed size and must have been defined in the same crate.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(header, BigEndian, {
+///   version: u16,
+///   length: u32,
+/// });
+///
+/// define_layout!(message, BigEndian, {
+///   @include header,
+///   body: u32,
+/// });
+///
+/// # fn main() {
+/// assert_eq!(2, message::length::OFFSET);
+/// assert_eq!(6, message::body::OFFSET);
+///
+/// let mut view = message::View::new(vec![0; 10]);
+/// view.version_mut().write(1);
+/// view.body_mut().write(2);
+/// assert_eq!(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 2], &*view.into_storage());
+/// # }
+/// ```
+///
+/// ## Fixed total size
+/// Formats with fixed-size slots often pad each record to a fixed total size. Declare it with `size = N` after the endianness,
+/// e.g. `define_layout!(<<Name>>, <<Endianness>>, size = 64, { ... })`. The layout then has a `SIZE` of exactly `N` bytes,
+/// with padding bytes after the last field that don't belong to any field. It fails to compile if the fields take up more than `N` bytes
+/// or if the layout ends with an open ended field.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(slot, LittleEndian, size = 16, {
+///   id: u32,
+///   value: u64,
+/// });
+///
+/// # fn main() {
+/// assert_eq!(Some(16), slot::SIZE);
+/// assert_eq!(4, slot::value::OFFSET);
+///
+/// let view = slot::View::from_array([0; 16]);
+/// assert_eq!(0, view.value().read());
+/// # }
+/// ```
+///
+/// ## Size budget
+/// Constrained protocols often limit the size of a record without padding it. Declare the limit with `max_size = N` after the endianness,
+/// e.g. `define_layout!(<<Name>>, <<Endianness>>, max_size = 32, { ... })`. In contrast to `size = N`, this doesn't change the layout,
+/// it only fails to compile if the layout can be larger than `N` bytes, i.e. if its `MAX_SIZE` is larger than `N`.
+/// Layouts ending with an open ended field need to declare a maximum length for it, e.g. `[u8; ..=16]`.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(frame, BigEndian, max_size = 32, {
+///   kind: u8,
+///   length: u16,
+///   payload: [u8; ..=16],
+/// });
+///
+/// # fn main() {
+/// assert_eq!(Some(19), frame::MAX_SIZE);
+/// assert_eq!(3, frame::MIN_SIZE);
+/// # }
+/// ```
+///
+/// ## C compatible struct
+/// To hand a layout to C code, e.g. through a header generated by cbindgen, declare `repr_c = <<StructName>>` after the endianness,
+/// optionally after `max_size = N`. The macro then also defines a `#[repr(C, packed)]` struct with that name next to the layout module,
+/// with a public member of the declared type for each field. This is only valid for layouts with a fixed size in the byte order of the target,
+/// e.g. `NativeEndian`, whose fields are integers, floats or byte arrays, and anything else fails to compile.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(sample, NativeEndian, repr_c = SampleC, {
+///   channel: u8,
+///   timestamp: u64,
+///   value: f32,
+///   tag: [u8; 3],
+/// });
+///
+/// # fn main() {
+/// assert_eq!(sample::SIZE, Some(core::mem::size_of::<SampleC>()));
+/// let value = SampleC { channel: 1, timestamp: 2, value: 0.5, tag: *b"abc" };
+/// let timestamp = value.timestamp;
+/// assert_eq!(2, timestamp);
+/// # }
+/// ```
+///
+/// ## Trailer
+/// Some formats end with a fixed size trailer, e.g. a checksum, after a part of variable length. Declare the fields of the trailer
+/// in a `@trailer { ... }` section after the open ended field. These fields are anchored to the end of the storage, so their position
+/// is computed from the storage length at runtime, and the open ended field stops where the trailer starts.
+/// Trailer fields must have a static size and can't use `as`, sentinels or validators.
+///
+/// The `OFFSET` of a trailer field, and its entry in `FIELDS`, is its offset in a storage where the open ended field is empty,
+/// so `MIN_SIZE` includes the trailer. `for_each_field`, `eq_ignoring`, `field_region` and [migrate](crate::migrate) use the actual position.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(packet, LittleEndian, {
+///   kind: u8,
+///   payload: [u8],
+///   @trailer {
+///     checksum: u32,
+///   },
+/// });
+///
+/// # fn main() {
+/// let view = packet::View::new([1, 10, 20, 30, 0xEF, 0xBE, 0xAD, 0xDE]);
+/// assert_eq!(&[10, 20, 30], view.payload());
+/// assert_eq!(0xDEADBEEF, view.checksum().read());
+/// assert_eq!(5, packet::MIN_SIZE);
+/// # }
+/// ```
+///
+/// ## Const generic parameters
+/// Formats that only differ in the length of some fields can be defined once with const generic parameters, e.g. `<const K: usize>`,
+/// and then instantiated for each value with `define_layout!(<<Name>> = <<GenericName>><<<Values>>>)`.
+/// Each instantiation is a separate layout module with the parameters available as `const`s, so the offsets
+/// of all fields are still computed at compile time. Values that aren't a literal or a single identifier have to be put in braces,
+/// just like const generic arguments in Rust. Like included layouts, generic layouts must have been defined in the same crate.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(keyed_message<const K: usize>, BigEndian, {
+///   id: u16,
+///   key: [u8; K],
+///   value: u32,
+/// });
+///
+/// define_layout!(short_key_message = keyed_message<16>);
+/// define_layout!(long_key_message = keyed_message<32>);
+///
+/// # fn main() {
+/// assert_eq!(16, short_key_message::K);
+/// assert_eq!(18, short_key_message::value::OFFSET);
+/// assert_eq!(34, long_key_message::value::OFFSET);
+///
+/// let mut view = long_key_message::View::new(vec![0; long_key_message::SIZE.unwrap()]);
+/// view.key_mut().copy_from_slice(&[1; 32]);
+/// view.value_mut().write(5);
+/// # }
+/// ```
+///
 /// # Generated code
 /// See [icmp_packet](crate::example::icmp_packet) for an example.
 ///
@@ -40,6 +291,19 @@
 /// - For each field, there will be a struct containing
 ///   - metadata like [OFFSET](crate::Field::OFFSET) and [SIZE](crate::Field::SIZE) as rust `const`s
 ///   - data accessors for the [Field](crate::Field) API
+/// - `FIELD_COUNT` and `FIELD_NAMES` constants with the number of fields and their names in declaration order
+/// - `OFFSETS` and `SIZES` arrays with the offset and the size of each field, in the same order as `FIELD_NAMES`
+/// - a `MAX_SIZE` constant with the maximum number of bytes of a record, which is `None` for layouts ending with an open ended field without a declared maximum length
+/// - a `MIN_SIZE` constant with the number of bytes a storage needs to contain all fields, which for layouts ending with an open ended field is the offset of that field
+/// - a `FIELDS` table with the [FieldInfo](crate::FieldInfo) metadata of each field in declaration order
+/// - a `FIELD_META` table with the [FieldMeta](crate::FieldMeta) metadata of each field, which adds its declared type and byte order
+/// - a `const fn field_info(name)` returning the offset and size of the field with the given name
+/// - a `records(storage)` function iterating over a `View` for each record in a storage holding consecutive records of a layout with a fixed size
+/// - a `cursor(storage)` function returning a [LayoutCursor](crate::LayoutCursor) that writes the fields of a `View` over the storage in declaration order
+/// - For each field with [copy access](crate::FieldCopyAccess), a `peek_${field_name}(storage)` function that bounds-checks the storage
+///   and reads the field without creating a `View`, returning a [LayoutError::BufferTooSmall](crate::LayoutError::BufferTooSmall) error if the storage is too small.
+/// - For each primitive field with [copy access](crate::FieldCopyAccess), i.e. not using `as`, `${field_name}::read_advancing(storage, &mut pos)` reads the field at position `pos`
+///   instead of its offset and advances `pos` by its size, for manual streaming parsers. See [PrimitiveField::read_advancing](crate::PrimitiveField::read_advancing).
 /// - The module will also contain a `View` struct that offers the [FieldView](crate::FieldView) API.
 ///
 /// This macro will also generate rustdoc documentation for everything it generates. One of the best ways to figure out
@@ -55,6 +319,10 @@
 /// });
 /// assert_eq!(2, my_layout::field2::OFFSET);
 /// assert_eq!(Some(4), my_layout::field2::SIZE);
+/// assert_eq!(2, my_layout::FIELD_COUNT);
+/// assert_eq!(&["field1", "field2"], my_layout::FIELD_NAMES);
+/// assert_eq!([0, 2], my_layout::OFFSETS);
+/// assert_eq!([Some(2), Some(4)], my_layout::SIZES);
 /// ```
 ///
 /// ## struct View
@@ -67,30 +335,580 @@
 ///
 /// The generated `View` struct will offer
 /// - `View::new(storage)` to create a `View`
+/// - `View::new_exact(storage)` to create a `View` after checking that the storage is exactly as large as the layout, see [LayoutError](crate::LayoutError)
 /// - `View::into_storage(self)` to destroy a `View` and return the storage held
+/// - `ValidatedView::try_new(storage)` and `ValidatedView::try_new_mut(storage)` for a layout with a fixed size, to check once that a borrowed storage
+///   contains all fields and get a `ValidatedView` that dereferences to a `View` over a `[u8; SIZE]` array, so the compiler can remove the bounds checks of the accessors
+/// - `View::from_array(data)` to create a view over a byte array whose length is checked against `SIZE` at compile time
+/// - With the `std` feature, `View::resize_tail(new_len)` on a view over a `Vec<u8>` to resize the open ended field at the end of the layout
+/// - With the `std` feature, `View::write_framed(w)` and `View::read_framed(r)` to write and read records prefixed with their length
+/// - With the `std` feature, `View::new_boxed_zeroed()` to allocate a zeroed `Box<[u8]>` storage for a layout with a fixed size
+/// - With the `std` feature, `View::from_cow(cow)` to create a view over a `Cow<[u8]>` that clones the data on the first write
+/// - With the `memmap2` feature, `View::from_mmap(mmap)` to create a read-only `View` over a whole memory mapped file after checking its size
+/// - `View::new_split(header, body)` to create a `SplitView` for a layout ending with an open ended field, whose header fields and open ended field are in separate storages
+/// - `View::new_at(storage, base_offset)` to create a `View` for a record that starts at `base_offset` within a larger storage.
+///   This returns a `View<Data<S>>` that also offers `View::absolute_offset::<Field>(&self)` to map fields back to offsets in the whole storage.
+/// - `View::field_region::<Start, End>(&self)` to borrow the bytes covering all fields from `Start` through `End`, e.g. a header region
+/// - `View::eq_ignoring(&self, other, ignore)` to compare the binary representation of all fields except for the ones named in `ignore`
+/// - `View::matches_template(&self, template, mask)` to compare the storage with an expected byte template, ignoring the bits not set in `mask`
+/// - `View::read_fields::<(field1, field2, ...)>(&self)` to read a tuple of fields with a single bounds check, see [FieldTuple](crate::FieldTuple)
+/// - `View::for_each_field(&self, f)` to call `f` with the name, offset and [FieldValue](crate::FieldValue) of each field in declaration order
+/// - `View::copy_record_from(&mut self, src)` to copy a whole record from another view of the same layout with a single `copy_from_slice`
+/// - implementations of [LayoutView](crate::LayoutView) and [LayoutViewMut](crate::LayoutViewMut) for `View`, so it can be used with [migrate](crate::migrate).
+///   These traits also offer `as_ptr(&self)`, `as_mut_ptr(&mut self)` and `len(&self)` to pass the storage to C code,
+///   and `tail_offset(&self)` to get the offset at which the open ended field at the end of the layout starts
+/// - implementations of [ReadField](crate::ReadField) and [WriteField](crate::WriteField) for `View` for each field with copy access, so generic code can require a view with a certain field
+///
+/// `View` implements [Clone] if the storage does. Cloning a view clones the storage, so for shared storage
+/// like `Arc<[u8]>`, this only clones the pointer and not the underlying data.
 ///
 /// and it will offer the following accessors for each field
 /// - `${field_name}()`: Read access. This returns a [FieldView](crate::FieldView) instance with read access.
-/// - `${field_name}_mut()`: Read access. This returns a [FieldView](crate::FieldView) instance with write access.
+/// - `${field_name}_mut()`: Write access. This returns a [FieldView](crate::FieldView) instance with write access.
 /// - `into_${field_name}`: Extract access. This destroys the `View` and returns a [FieldView](crate::FieldView) instance owning the storage. Mostly useful for slice fields when you want to return an owning slice.
+///
+/// ### Borrowing
+/// The `${field_name}_mut()` accessors borrow the whole `View` mutably, so the borrow checker guarantees that
+/// there is at most one field view with write access at any time, and no field view with read access while it exists.
+/// The same holds for the storage itself: a `View` over a `&mut [u8]` holds an exclusive borrow of the storage.
+/// Since this crate doesn't use any `unsafe` code, these guarantees can't be circumvented through this crate's API.
+/// If you need to access several fields, access them one after the other.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let mut storage = vec![0; 6];
+/// let mut view = my_layout::View::new(&mut storage);
+/// view.field1_mut().write(1);
+/// view.field2_mut().write(2);
+/// ```
+///
+/// Holding two field views with write access at the same time doesn't compile:
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let mut storage = vec![0; 6];
+/// let mut view = my_layout::View::new(&mut storage);
+/// let mut field1 = view.field1_mut();
+/// let mut field2 = view.field2_mut();
+/// field1.write(1);
+/// field2.write(2);
+/// ```
+///
+/// And neither does reading a field while a field view with write access exists:
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let mut storage = vec![0; 6];
+/// let mut view = my_layout::View::new(&mut storage);
+/// let mut field1 = view.field1_mut();
+/// let value = view.field2().read();
+/// field1.write(1);
+/// ```
+///
+/// Nor does creating a second view over storage that is already mutably borrowed:
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// let mut storage = vec![0; 6];
+/// let mut view1 = my_layout::View::new(&mut storage);
+/// let mut view2 = my_layout::View::new(&mut storage);
+/// view1.field1_mut().write(1);
+/// view2.field1_mut().write(2);
+/// ```
+///
+/// ### Field regions
+/// `View::field_region::<Start, End>()` checks at compile time that `Start` and `End` are fields of the layout
+/// and that `End` doesn't come before `Start`. These checks run when the call is compiled to code, so `cargo check` alone doesn't report them.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+///   field3: u8,
+/// });
+///
+/// let storage = [1, 2, 3, 4, 5, 6, 7];
+/// let view = my_layout::View::new(&storage);
+/// assert_eq!(&[1, 2, 3, 4, 5, 6], view.field_region::<my_layout::field1, my_layout::field2>());
+/// ```
+///
+/// Passing the fields in the wrong order doesn't compile:
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+///   field3: u8,
+/// });
+///
+/// let storage = [1, 2, 3, 4, 5, 6, 7];
+/// let view = my_layout::View::new(&storage);
+/// view.field_region::<my_layout::field2, my_layout::field1>();
+/// ```
+///
+/// And neither does passing a field of another layout:
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+///   field3: u8,
+/// });
+///
+/// define_layout!(other_layout, LittleEndian, {
+///   field1: u8,
+///   field2: u64,
+/// });
+///
+/// let storage = [1, 2, 3, 4, 5, 6, 7];
+/// let view = my_layout::View::new(&storage);
+/// view.field_region::<my_layout::field1, other_layout::field2>();
+/// ```
+///
+/// ## struct Owned
+/// If the `std` feature is enabled, the macro also generates an `Owned` struct with a public member for each field,
+/// holding the decoded field values. Fixed size byte arrays are decoded into `[u8; N]`, open ended byte arrays into `Vec<u8>`
+/// and nested layouts into the `Owned` struct of the nested layout. `Owned` implements [Clone] and [Debug](core::fmt::Debug)
+/// if all field types do.
+///
+/// - `View::to_owned_struct(&self)` decodes all fields into an `Owned` struct that is detached from the storage.
+/// - `View::write_from_struct(&mut self, &Owned)` writes all fields from an `Owned` struct into the storage.
+/// - `LayoutView::hexdump(&self)`, implemented for `View`, formats the whole storage like `xxd` does, for debugging.
+/// - `Owned::to_view(&self)` allocates a `Vec<u8>` of the required size and returns a `View` with all fields written into it.
+/// - `Builder::new()` starts a record, `Builder::set_${field_name}(value)` sets a field and `Builder::default_${field_name}()` explicitly
+///   leaves a field at its default value. `Builder::build()` then returns the same `View` as `Owned::to_view()`, or a `MissingFields`
+///   error listing the fields that were neither set nor defaulted.
+///
+/// Writing requires wrapped field types to implement [Clone].
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: [u8; 2],
+///   tail: [u8],
+/// });
+///
+/// # #[cfg(feature = "std")] {
+/// let view = my_layout::View::new(vec![1, 0, 2, 3, 4, 5]);
+/// let owned: my_layout::Owned = view.to_owned_struct();
+/// assert_eq!(1, owned.field1);
+/// assert_eq!([2, 3], owned.field2);
+/// assert_eq!(vec![4, 5], owned.tail);
+///
+/// let view = owned.to_view();
+/// assert_eq!(&[1, 0, 2, 3, 4, 5], &*view.into_storage());
+///
+/// let view = my_layout::Builder::new()
+///   .set_field1(1)
+///   .set_field2([2, 3])
+///   .default_tail()
+///   .build()
+///   .unwrap();
+/// assert_eq!(&[1, 0, 2, 3], &*view.into_storage());
+/// # }
+/// ```
+///
+/// # Optional fields
+/// Some formats start with a presence bitmap and only store the fields whose bit is set, packed together.
+/// Such layouts can be defined with the `optional(...)` variant of the macro. The bitmap field (an unsigned integer type)
+/// is stored at the beginning of the layout and the `i`-th declared field is present if bit `i` (counted from the least
+/// significant bit) is set in the bitmap. All other fields are optional and their offsets are computed at runtime.
+/// The bitmap type must have at least as many bits as there are optional fields, otherwise the layout fails to compile.
+///
+/// ```text
+/// define_layout!(<<Name>>, <<Endianness>>, optional(<<BitmapName>>: <<BitmapType>>) {
+///   <<FieldName>>: <<FieldType>>,
+///   ...
+/// });
+/// ```
+///
+/// The generated `View` offers `${bitmap_name}()` and `${bitmap_name}_mut()` accessors like a regular layout, and for each
+/// optional field, `${field_name}()` and `${field_name}_mut()` accessors returning `None` if the field isn't present.
+/// The module also offers a `size(bitmap)` function returning the number of bytes a record with the given bitmap takes.
+///
+/// Note that setting a bit in the bitmap moves all of the following optional fields, so you should write the bitmap first
+/// and the fields afterwards.
+///
+/// ```
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_message, BigEndian, optional(presence: u8) {
+///   first: u16,
+///   second: u32,
+/// });
+///
+/// let mut storage = vec![0; my_message::size(0b10)];
+/// let mut view = my_message::View::new(&mut storage);
+/// view.presence_mut().write(0b10);
+/// view.second_mut().unwrap().write(1000);
+/// assert!(view.first().is_none());
+/// assert_eq!(1000, view.second().unwrap().read());
+/// assert_eq!(&[0b10, 0, 0, 3, 232], &*storage);
+/// ```
 #[macro_export]
 macro_rules! define_layout {
-    ($name: ident, $endianness: ident, {$($field_name: ident : $field_type: ty $(as $underlying_type: ty)?),* $(,)?}) => {
+    (pub $(($($restriction: tt)*))? $name: ident, $($tail: tt)*) => {
+        $crate::define_layout!(@layout (pub $(($($restriction)*))?) $name, $($tail)*);
+    };
+    ($name: ident, $($tail: tt)*) => {
+        $crate::define_layout!(@layout (pub) $name, $($tail)*);
+    };
+    (pub $(($($restriction: tt)*))? $name: ident <$(const $param: ident : $param_ty: ty),+ $(,)?>, $($tail: tt)*) => {
+        $crate::define_layout!(@impl_generic ($) (pub $(($($restriction)*))?) $name [$(const $param: $param_ty),+], $($tail)*);
+    };
+    ($name: ident <$(const $param: ident : $param_ty: ty),+ $(,)?>, $($tail: tt)*) => {
+        $crate::define_layout!(@impl_generic ($) (pub) $name [$(const $param: $param_ty),+], $($tail)*);
+    };
+    (pub $(($($restriction: tt)*))? $name: ident = $($generic: ident)::+ <$($value: tt),+ $(,)?>) => {
+        $($generic)::+::__binary_layout_instantiate!{(pub $(($($restriction)*))?) $name, $($value),+}
+    };
+    ($name: ident = $($generic: ident)::+ <$($value: tt),+ $(,)?>) => {
+        $($generic)::+::__binary_layout_instantiate!{(pub) $name, $($value),+}
+    };
+    (@layout ($($vis: tt)*) $name: ident, $endianness: ident, $(size = $size: expr,)? max_size = $max_size: expr, {$($fields: tt)*}) => {
+        $crate::define_layout!(@layout ($($vis)*) $name, $endianness, $(size = $size,)? {$($fields)*});
+        const _: () = $crate::internal::check_size_budget($name::MAX_SIZE, $max_size);
+    };
+    (@layout ($($vis: tt)*) $name: ident, $endianness: ident, $(max_size = $max_size: expr,)? repr_c = $repr_c: ident, {$($fields: tt)*}) => {
+        $crate::define_layout!(@layout ($($vis)*) $name, $endianness, $(max_size = $max_size,)? {$($fields)*});
+        $crate::define_layout!(@repr_c ($($vis)*) $name, $endianness, $repr_c, {$($fields)*});
+    };
+    (@repr_c ($($vis: tt)*) $name: ident, $endianness: ident, $repr_c: ident, {$($(#[doc = $field_doc: expr])* $field_name: ident : $field_type: ty),* $(,)?}) => {
+        $crate::internal::doc_comment!{
+            concat!("A `#[repr(C, packed)]` struct with the same memory representation as the [", stringify!($name), "](", stringify!($name), ") layout, e.g. for C header generators like cbindgen."),
+            #[repr(C, packed)]
+            #[derive(Clone, Copy)]
+            $($vis)* struct $repr_c {
+                $(
+                    #[allow(missing_docs)]
+                    $(#[doc = $field_doc])*
+                    pub $field_name: $field_type,
+                )*
+            }
+        }
+        const _: () = $crate::internal::check_repr_c($name::SIZE, $name::FIELD_META, core::mem::size_of::<$repr_c>());
+    };
+    (@repr_c $($tail: tt)*) => {
+        compile_error!("Error: `repr_c = Name` only supports layouts whose fields are declared with a plain type, without `as`, sentinels, validators, `@include` or `@trailer`");
+    };
+    (@layout ($($vis: tt)*) $name: ident, $endianness: ident, optional($bitmap_name: ident : $bitmap_type: ty) {$($(#[doc = $field_doc: expr])* $field_name: ident : $field_type: ty),* $(,)?}) => {
+        $crate::internal::doc_comment!{
+            concat!{"
+            This module is autogenerated. It defines a layout with optional fields using the [binary_layout] crate based on the following definition:
+            ```ignore
+            define_layout!(", stringify!($name), ", ", stringify!($endianness), ", optional(", stringify!($bitmap_name), ": ", stringify!($bitmap_type), ") {", $("
+                ", stringify!($field_name), ": ", stringify!($field_type), ",", )* "
+            });
+            ```
+            "},
+            #[allow(dead_code)]
+            $($vis)* mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $crate::internal::doc_comment!{
+                    concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($bitmap_name), "` presence bitmap"),
+                    #[allow(non_camel_case_types)]
+                    pub type $bitmap_name = $crate::PrimitiveField::<$bitmap_type, $crate::$endianness, 0>;
+                }
+
+                #[allow(non_camel_case_types)]
+                #[derive(Clone, Copy)]
+                enum FieldIndex {
+                    $($field_name),*
+                }
+
+                $(
+                    const _: () = $crate::internal::check_array_len(
+                        <$crate::PrimitiveField::<$field_type, $crate::$endianness, 0> as $crate::Field>::IS_ZERO_LENGTH_ARRAY,
+                        concat!("Error: The field `", stringify!($field_name), "` is a zero-length array. Use `()` for a field that doesn't take up any space."),
+                    );
+                )*
+
+                const FIELD_SIZES: &[usize] = &[$(
+                    $crate::internal::unwrap_field_size(<$crate::PrimitiveField::<$field_type, $crate::$endianness, 0> as $crate::Field>::SIZE)
+                ),*];
+
+                const _: () = $crate::internal::check_optional_field_count(FIELD_SIZES.len(), <$bitmap_type as $crate::internal::PresenceBitmap>::BITS);
+
+                #[inline]
+                fn field_offset(storage: &[u8], index: FieldIndex) -> Option<usize> {
+                    let presence = <$bitmap_name as $crate::FieldCopyAccess>::read(storage);
+                    if $crate::internal::PresenceBitmap::is_set(presence, index as usize) {
+                        Some($crate::internal::optional_field_offset(presence, $crate::internal::unwrap_field_size(<$bitmap_name as $crate::Field>::SIZE), FIELD_SIZES, index as usize))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Number of bytes a storage needs to contain a record, i.e. the size of the presence bitmap of a record without any fields.
+                pub const MIN_SIZE: usize = $crate::internal::unwrap_field_size(<$bitmap_name as $crate::Field>::SIZE);
+
+                /// Total size in number of bytes of a record with the given presence bitmap.
+                #[inline]
+                pub fn size(presence: $bitmap_type) -> usize {
+                    $crate::internal::optional_field_offset(presence, $crate::internal::unwrap_field_size(<$bitmap_name as $crate::Field>::SIZE), FIELD_SIZES, FIELD_SIZES.len())
+                }
+
+                /// The [View] struct defines the [FieldView](crate::FieldView) API for a layout with optional fields.
+                /// An instance of [View] wraps a storage (either borrowed or owned)
+                /// and allows accessors for the layout fields.
+                #[derive(Clone)]
+                pub struct View<S: AsRef<[u8]>> {
+                    /// binary container, might be `&[u8]`, `&mut [u8]` or owned `Vec<u8>`
+                    pub storage: S,
+                }
+                impl <S: AsRef<[u8]>> View<S> {
+                    /// You can create views over a storage by calling [View::new].
+                    #[inline]
+                    pub fn new(storage: S) -> Self {
+                        Self {storage}
+                    }
+
+                    /// This destroys the view and returns the underlying storage back to you.
+                    #[inline]
+                    pub fn into_storage(self) -> S {
+                        self.storage
+                    }
+
+                    $crate::internal::doc_comment!{
+                        concat!("Return a [FieldView](crate::FieldView) with read access to the `", stringify!($bitmap_name), "` presence bitmap"),
+                        #[inline]
+                        pub fn $bitmap_name(&self) -> <$bitmap_name as $crate::internal::StorageToFieldView<&[u8]>>::View {
+                            <$bitmap_name as $crate::internal::StorageToFieldView<&[u8]>>::view(self.storage.as_ref())
+                        }
+                    }
+
+                    $(
+                        $crate::internal::doc_comment!{
+                            concat!("Return a [FieldView](crate::FieldView) with read access to the optional `", stringify!($field_name), "` field, or `None` if it isn't present\n\n"),
+                            $(#[doc = $field_doc])*
+                            #[inline]
+                            pub fn $field_name(&self) -> Option<<$crate::PrimitiveField::<$field_type, $crate::$endianness, 0> as $crate::internal::StorageToFieldView<&[u8]>>::View> {
+                                let offset = field_offset(self.storage.as_ref(), FieldIndex::$field_name)?;
+                                Some(<$crate::PrimitiveField::<$field_type, $crate::$endianness, 0> as $crate::internal::StorageToFieldView<&[u8]>>::view(&self.storage.as_ref()[offset..]))
+                            }
+                        }
+                    )*
+                }
+                impl <S: AsRef<[u8]> + AsMut<[u8]>> View<S> {
+                    $crate::internal::paste!{
+                        $crate::internal::doc_comment!{
+                            concat!("Return a [FieldView](crate::FieldView) with write access to the `", stringify!($bitmap_name), "` presence bitmap"),
+                            #[inline]
+                            pub fn [<$bitmap_name _mut>](&mut self) -> <$bitmap_name as $crate::internal::StorageToFieldView<&mut [u8]>>::View {
+                                <$bitmap_name as $crate::internal::StorageToFieldView<&mut [u8]>>::view(self.storage.as_mut())
+                            }
+                        }
+
+                        $(
+                            $crate::internal::doc_comment!{
+                                concat!("Return a [FieldView](crate::FieldView) with write access to the optional `", stringify!($field_name), "` field, or `None` if it isn't present\n\n"),
+                                $(#[doc = $field_doc])*
+                                #[inline]
+                                pub fn [<$field_name _mut>](&mut self) -> Option<<$crate::PrimitiveField::<$field_type, $crate::$endianness, 0> as $crate::internal::StorageToFieldView<&mut [u8]>>::View> {
+                                    let offset = field_offset(self.storage.as_ref(), FieldIndex::$field_name)?;
+                                    Some(<$crate::PrimitiveField::<$field_type, $crate::$endianness, 0> as $crate::internal::StorageToFieldView<&mut [u8]>>::view(&mut self.storage.as_mut()[offset..]))
+                                }
+                            }
+                        )*
+                    }
+                }
+            }
+        }
+    };
+    (@layout ($($vis: tt)*) $name: ident, Endian, $(size = $size: expr,)? {$($fields: tt)*}) => {
+        $crate::internal::doc_comment!{
+            concat!{"
+            This module is autogenerated. It defines a layout with generic endianness using the [binary_layout] crate.
+            The layout is instantiated for both endiannesses in the [big_endian] and [little_endian] submodules.
+            "},
+            #[allow(dead_code)]
+            $($vis)* mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                $crate::define_layout!(@layout (pub) big_endian, BigEndian, $(size = $size,)? {$($fields)*});
+                $crate::define_layout!(@layout (pub) little_endian, LittleEndian, $(size = $size,)? {$($fields)*});
+
+                pub use big_endian::{
+                    field_info, FIELDS, FIELD_COUNT, FIELD_NAMES, MAX_SIZE, MIN_SIZE, OFFSETS, SIZE, SIZES,
+                };
+
+                /// Implemented for [BigEndian](crate::BigEndian) and [LittleEndian](crate::LittleEndian)
+                /// to select the instantiation of the layout for that endianness.
+                pub trait EndianVariant: $crate::Endianness {
+                    /// The `View` struct of the layout instantiated for this endianness
+                    type View<S: AsRef<[u8]>>;
+                }
+                impl EndianVariant for $crate::BigEndian {
+                    type View<S: AsRef<[u8]>> = big_endian::View<S>;
+                }
+                impl EndianVariant for $crate::LittleEndian {
+                    type View<S: AsRef<[u8]>> = little_endian::View<S>;
+                }
+
+                /// The `View` struct of the layout for the endianness `E`.
+                /// Use it as `View::<_, BigEndian>::new(storage)` or `View::<_, LittleEndian>::new(storage)`.
+                pub type View<S, E> = <E as EndianVariant>::View<S>;
+            }
+        }
+    };
+    (@layout ($($vis: tt)*) $name: ident, $endianness: ident, {@include $($include: ident)::+ $(, $($tail: tt)*)?}) => {
+        $($include)::+::__binary_layout_include!{($($vis)*) $name, $endianness, $($include)::+, {$($($tail)*)?}}
+    };
+    (@layout ($($vis: tt)*) $name: ident $([$($params: tt)*])?, $endianness: ident, $(size = $size: expr,)? {$($fields: tt)*}) => {
+        $crate::define_layout!(@bounded_tail ($($vis)*) $name $([$($params)*])?, $endianness, ($($size)?), [] {$($fields)*});
+    };
+    // Rewrites fields declared as `[u8; ..=MAX]` into the `BoundedBytes<MAX>` field type, one field at a time,
+    // since `[u8; ..=MAX]` can't be handled once it was matched as a `ty`.
+    (@bounded_tail ($($vis: tt)*) $name: ident $([$($params: tt)*])?, $endianness: ident, ($($size: expr)?), [$($done: tt)*] {}) => {
+        $crate::define_layout!(@layout_fields ($($vis)*) $name $([$($params)*])?, $endianness, ($($size)?), {$($done)*});
+    };
+    // Fields in a `@trailer` section are marked with `@end`, so @impl_fields anchors them to the end of the storage.
+    (@bounded_tail ($($vis: tt)*) $name: ident $([$($params: tt)*])?, $endianness: ident, ($($size: expr)?), [$($done: tt)*] {@trailer {$($(#[doc = $field_doc: expr])* $field_name: ident : $field_type: ty),* $(,)?} $(,)?}) => {
+        $crate::define_layout!(@layout_fields ($($vis)*) $name $([$($params)*])?, $endianness, ($($size)?), {$($done)* $(@end $(#[doc = $field_doc])* $field_name: $field_type,)*});
+    };
+    (@bounded_tail ($($vis: tt)*) $name: ident $([$($params: tt)*])?, $endianness: ident, ($($size: expr)?), [$($done: tt)*] {$(#[doc = $field_doc: expr])* $field_name: ident : [u8; ..= $max: expr] $(, $($tail: tt)*)?}) => {
+        $crate::define_layout!(@bounded_tail ($($vis)*) $name $([$($params)*])?, $endianness, ($($size)?), [$($done)* $(#[doc = $field_doc])* $field_name: $crate::BoundedBytes<{$max}>,] {$($($tail)*)?});
+    };
+    (@bounded_tail ($($vis: tt)*) $name: ident $([$($params: tt)*])?, $endianness: ident, ($($size: expr)?), [$($done: tt)*] {$(#[doc = $field_doc: expr])* $field_name: ident : $field_type: ty $(as $underlying_type: ty)? $(= sentinel($sentinel: expr))? $(where $validator: expr)? $(, $($tail: tt)*)?}) => {
+        $crate::define_layout!(@bounded_tail ($($vis)*) $name $([$($params)*])?, $endianness, ($($size)?), [$($done)* $(#[doc = $field_doc])* $field_name: $field_type $(as $underlying_type)? $(= sentinel($sentinel))? $(where $validator)?,] {$($($tail)*)?});
+    };
+    (@layout_fields ($($vis: tt)*) $name: ident $([$(const $param: ident : $param_ty: ty = $param_value: tt),+])?, $endianness: ident, ($($size: expr)?), {$($(@ $anchor: ident)? $(#[doc = $field_doc: expr])* $field_name: ident : $field_type: ty $(as $underlying_type: ty)? $(= sentinel($sentinel: expr))? $(where $validator: expr)?),* $(,)?}) => {
         $crate::internal::doc_comment!{
             concat!{"
             This module is autogenerated. It defines a layout using the [binary_layout] crate based on the following definition:
             ```ignore
-            define_layout!(", stringify!($name), ", ", stringify!($endianness), ", {", $("
-                ", stringify!($field_name), ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? ",", )* "
+            define_layout!(", stringify!($name), ", ", stringify!($endianness), ", ", $("size = ", stringify!($size), ", ", )? "{", $("
+                ", stringify!($field_name), ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? $(" = sentinel(", stringify!($sentinel), ")", )? $(" where ", stringify!($validator), )? ",", )* "
             });
             ```
             "},
             #[allow(dead_code)]
-            pub mod $name {
+            $($vis)* mod $name {
                 #[allow(unused_imports)]
                 use super::*;
 
-                $crate::define_layout!(@impl_fields $crate::$endianness, Some(0), {$($field_name : $field_type $(as $underlying_type)?),*});
+                $($(
+                    $crate::internal::doc_comment!{
+                        concat!("Value of the `", stringify!($param), "` parameter this layout was instantiated with"),
+                        pub const $param: $param_ty = $param_value;
+                    }
+                )+)?
+
+                $crate::define_layout!(@impl_fields $crate::$endianness, [$($size)?], Some(0), {$($(@ $anchor)? $(#[doc = $field_doc])* $field_name : $field_type $(as $underlying_type)? $(= sentinel($sentinel))? $(where $validator)?),*});
+                $crate::define_layout!(@impl_peek {$($field_name),*});
+                $(
+                    const _: () = $crate::internal::check_array_len(
+                        <$field_name as $crate::Field>::IS_ZERO_LENGTH_ARRAY,
+                        concat!("Error: The field `", stringify!($field_name), "` is a zero-length array. Use `()` for a field that doesn't take up any space."),
+                    );
+                )*
+
+                /// Names of the fields in the layout, in the order they were declared.
+                pub const FIELD_NAMES: &[&str] = &[$(stringify!($field_name)),*];
+
+                /// Number of fields in the layout.
+                pub const FIELD_COUNT: usize = FIELD_NAMES.len();
+
+                /// Offsets of the fields in the layout, in the same order as [FIELD_NAMES].
+                pub const OFFSETS: [usize; FIELD_COUNT] = [$(<$field_name as $crate::Field>::OFFSET),*];
+
+                /// Sizes of the fields in the layout, in the same order as [FIELD_NAMES]. Open ended fields have a size of `None`.
+                pub const SIZES: [Option<usize>; FIELD_COUNT] = [$(<$field_name as $crate::Field>::SIZE),*];
+
+                /// Metadata of the fields in the layout, in the order they were declared.
+                pub const FIELDS: &[$crate::FieldInfo] = &[$(
+                    $crate::FieldInfo {
+                        name: stringify!($field_name),
+                        offset: <$field_name as $crate::Field>::OFFSET,
+                        size: <$field_name as $crate::Field>::SIZE,
+                    }
+                ),*];
+
+                /// Metadata of the fields in the layout including their declared types and byte order, in the order they were declared.
+                pub const FIELD_META: &[$crate::FieldMeta] = &[$(
+                    $crate::FieldMeta {
+                        name: stringify!($field_name),
+                        offset: <$field_name as $crate::Field>::OFFSET,
+                        size: <$field_name as $crate::Field>::SIZE,
+                        type_name: concat!(stringify!($field_type) $(, " as ", stringify!($underlying_type))?),
+                        little_endian: <$field_name as $crate::Field>::IS_LITTLE_ENDIAN,
+                    }
+                ),*];
+
+                /// Number of bytes a storage needs to contain all fields of the layout.
+                /// For layouts ending with an open ended field, this is the offset of that field.
+                pub const MIN_SIZE: usize = $crate::internal::min_size(SIZE, FIELDS);
+
+                /// Maximum number of bytes of a record of the layout. This is [SIZE] for layouts with a fixed size.
+                /// For layouts ending with an open ended field, this is `None` unless the field was declared with a maximum length like `[u8; ..=64]`.
+                pub const MAX_SIZE: Option<usize> = $crate::internal::max_size(SIZE, FIELDS, &[$(<$field_name as $crate::Field>::MAX_SIZE),*]);
+
+                // Self-tests of this macro. It computes every offset itself, including for `@include` and `size = N`,
+                // so the layouts it composes don't have overlapping or out of order fields. These catch a bug in the offset computation
+                // or a composition broken by hand, e.g. a field of an included layout taken over at another position,
+                // at compile time instead of letting it corrupt data at runtime.
+                const _: () = $crate::internal::check_no_overlap(FIELDS);
+                const _: () = $crate::internal::check_ascending_offsets(FIELDS);
+
+                /// Look up a field by its name and return its offset and its size, or `None` if the layout doesn't have a field with that name.
+                /// This is a `const fn`, so it can be evaluated at compile time, e.g. by code generators.
+                #[inline]
+                pub const fn field_info(name: &str) -> Option<(usize, Option<usize>)> {
+                    $crate::internal::field_info(FIELDS, name)
+                }
+
+                #[doc(hidden)]
+                pub const __FIELD_DECLARATIONS: &[&str] = &[$(
+                    concat!(stringify!($endianness), " ", stringify!($field_type) $(, " as ", stringify!($underlying_type))? $(, " = sentinel(", stringify!($sentinel), ")")?)
+                ),*];
+
+                /// Split a storage into consecutive records of this layout and iterate over a [View] for each of them.
+                /// Trailing bytes that don't make up a whole record are ignored.
+                /// This is only available for layouts with a fixed size, calling it for a layout ending with an open ended field fails to compile.
+                #[inline]
+                pub fn records(storage: &[u8]) -> impl Iterator<Item = View<&[u8]>>
+                where
+                    for<'a> $crate::internal::SizeCheck<{ SIZE.is_some() }>: $crate::internal::FixedSizeLayout,
+                {
+                    $crate::internal::records($crate::internal::unwrap_field_size(SIZE), storage).map(View::new)
+                }
+
+                $crate::define_layout!(@impl_include_macro ($) {$($field_name),*});
 
                 $crate::internal::doc_comment!{
                     concat!{"
@@ -100,16 +918,28 @@ macro_rules! define_layout {
 
                     This view is based on the following layout definition:
                     ```ignore
-                    define_layout!(", stringify!($name), ", ", stringify!($endianness), ", {", $("
-                        ", stringify!($field_name), ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? ",",)* "
+                    define_layout!(", stringify!($name), ", ", stringify!($endianness), ", ", $("size = ", stringify!($size), ", ", )? "{", $("
+                        ", stringify!($field_name), ": ", stringify!($field_type), $(" as ", stringify!($underlying_type), )? $(" = sentinel(", stringify!($sentinel), ")", )? $(" where ", stringify!($validator), )? ",",)* "
                     });
                     ```
                     "},
+                    #[derive(Clone)]
                     pub struct View<S: AsRef<[u8]>> {
-                        /// binary container, might be &[u8], &mut [u8] or owned Vec<u8>
+                        /// binary container, might be `&[u8]`, `&mut [u8]` or owned `Vec<u8>`
                         pub storage: S,
                     }
                 }
+                impl View<[u8; MIN_SIZE]> {
+                    /// Create an owning view over a byte array of exactly [SIZE] bytes, e.g. `View::from_array([0; 6])`.
+                    /// The length of the array is part of the type, so passing an array of a different length fails to compile
+                    /// and no runtime check is needed. If the layout ends with an open ended field, the array has [MIN_SIZE] bytes
+                    /// and the open ended field is empty.
+                    #[inline]
+                    pub fn from_array(data: [u8; MIN_SIZE]) -> Self {
+                        Self::new(data)
+                    }
+                }
+
                 impl <S: AsRef<[u8]>> View<S> {
                     /// You can create views over a storage by calling [View::new].
                     ///
@@ -122,6 +952,17 @@ macro_rules! define_layout {
                         Self {storage}
                     }
 
+                    /// Create a view over a storage that must match the size of the layout exactly, i.e. be [SIZE] bytes long.
+                    /// This returns a [LayoutError](crate::LayoutError) if the storage is shorter or has trailing bytes.
+                    /// If the layout ends with an open ended field, this only checks that the storage contains all fields before it
+                    /// and, if the field was declared with a maximum length, that it isn't longer than [MAX_SIZE].
+                    #[inline]
+                    pub fn new_exact(storage: S) -> Result<Self, $crate::LayoutError> {
+                        $crate::internal::check_exact_size(SIZE, FIELDS, storage.as_ref().len())?;
+                        $crate::internal::check_max_size(SIZE, MAX_SIZE, storage.as_ref().len())?;
+                        Ok(Self::new(storage))
+                    }
+
                     /// This destroys the view and returns the underlying storage back to you.
                     /// This is useful if you created an owning view (e.g. based on `Vec<u8>`)
                     /// and now need the underlying `Vec<u8>` back.
@@ -130,13 +971,233 @@ macro_rules! define_layout {
                         self.storage
                     }
 
-                    $crate::define_layout!(@impl_view_into {$($field_name),*});
+                    $crate::define_layout!(@impl_view_into {$($(#[doc = $field_doc])* $field_name),*});
+
+                    /// Return the region of the storage covering all fields from `Start` up to and including `End`.
+                    /// If `End` is an open ended field, the region extends to the end of the storage.
+                    /// `Start` and `End` must be fields of this layout and `End` must not come before `Start`, otherwise this fails to compile.
+                    #[inline]
+                    pub fn field_region<Start: $crate::Field, End: $crate::Field>(&self) -> &[u8] {
+                        const { $crate::internal::check_field_region::<Start, End>(FIELDS) };
+                        $crate::internal::field_region::<Start, End>(self.storage.as_ref())
+                    }
+
+                    /// Read several fields at once with a single bounds check covering all of them, e.g. `view.read_fields::<(field1, field2)>()`.
+                    /// This panics if the storage is too small to contain all of the fields. See [FieldTuple](crate::FieldTuple).
+                    #[inline]
+                    pub fn read_fields<T: $crate::FieldTuple>(&self) -> T::Values {
+                        T::read_fields(self.storage.as_ref())
+                    }
+
+                    /// Call `f` with the name, the offset and the value of each field, in the order the fields were declared.
+                    /// The offset of a field in the `@trailer` section of the layout is its offset from the start of this storage.
+                    #[inline]
+                    pub fn for_each_field<'s>(&'s self, mut f: impl FnMut(&'static str, usize, $crate::FieldValue<'s>))
+                    where
+                        $(for<'a> $field_name: $crate::internal::FieldToValue,)*
+                    {
+                        $(
+                            f(stringify!($field_name), <$field_name as $crate::Field>::byte_range(self.storage.as_ref().len()).start, <$field_name as $crate::internal::FieldToValue>::read_value(self.storage.as_ref()));
+                        )*
+                    }
+
+                    /// Compare the fields of this view with the fields of another view, skipping the fields named in `ignore`.
+                    /// Fields are compared by their binary representation. Names in `ignore` that don't match any field are ignored.
+                    #[inline]
+                    pub fn eq_ignoring<S2: AsRef<[u8]>>(&self, other: &View<S2>, ignore: &[&str]) -> bool {
+                        $(
+                            (ignore.contains(&stringify!($field_name)) || $crate::internal::field_bytes_eq::<$field_name>(self.storage.as_ref(), other.storage.as_ref())) &&
+                        )* true
+                    }
+
+                    /// Compare the storage with an expected byte `template`, e.g. in golden tests, only comparing the bits that are set in `mask`.
+                    /// Use a mask byte of `0xFF` for bytes that must match and `0x00` for don't-care bytes like a checksum or a timestamp.
+                    /// The mask has the length of the template. This returns false if the storage doesn't have that length.
+                    #[inline]
+                    pub fn matches_template<const N: usize>(&self, template: &[u8; N], mask: &[u8; N]) -> bool {
+                        $crate::internal::matches_template(self.storage.as_ref(), template, mask)
+                    }
                 }
                 impl <S: AsRef<[u8]>> View<S> {
-                    $crate::define_layout!(@impl_view_asref {$($field_name),*});
+                    $crate::define_layout!(@impl_view_asref {$($(#[doc = $field_doc])* $field_name),*});
+                }
+                impl <S: AsRef<[u8]>> View<$crate::Data<S>> {
+                    /// Create a view for a record that starts at `base_offset` within a larger storage.
+                    /// Field accessors then access the bytes at `base_offset + field::OFFSET`.
+                    /// This panics if `base_offset` is larger than the storage.
+                    #[inline]
+                    pub fn new_at(storage: S, base_offset: usize) -> Self {
+                        Self::new($crate::Data::from(storage).into_subregion(base_offset..))
+                    }
+
+                    /// Return the offset of the field `F` within the whole storage, i.e. including the base offset of the view.
+                    #[inline]
+                    pub fn absolute_offset<F: $crate::Field>(&self) -> usize {
+                        self.storage.offset() + F::byte_range(self.storage.len()).start
+                    }
                 }
                 impl <S: AsRef<[u8]> + AsMut<[u8]>> View<S> {
-                    $crate::define_layout!(@impl_view_asmut {$($field_name),*});
+                    $crate::define_layout!(@impl_view_asmut {$($(#[doc = $field_doc])* $field_name),*});
+
+                    /// Copy the whole record from another view of the same layout with a single `copy_from_slice` instead of copying field by field.
+                    /// This copies [SIZE] bytes, or the whole storage of `src` if the layout ends with an open ended field.
+                    /// This panics if one of the storages is too small.
+                    #[inline]
+                    pub fn copy_record_from<S2: AsRef<[u8]>>(&mut self, src: &View<S2>) {
+                        $crate::internal::copy_record(SIZE, self.storage.as_mut(), src.storage.as_ref())
+                    }
+                }
+                impl <S: AsRef<[u8]>> $crate::LayoutView for View<S> {
+                    const FIELDS: &'static [$crate::FieldInfo] = FIELDS;
+                    const FIELD_DECLARATIONS: &'static [&'static str] = __FIELD_DECLARATIONS;
+
+                    #[inline]
+                    fn bytes(&self) -> &[u8] {
+                        self.storage.as_ref()
+                    }
+
+                    #[inline]
+                    fn tail_offset(&self) -> usize {
+                        $crate::internal::tail_offset(MIN_SIZE, FIELDS)
+                    }
+                }
+                impl <S: AsRef<[u8]> + AsMut<[u8]>> $crate::LayoutViewMut for View<S> {
+                    #[inline]
+                    fn bytes_mut(&mut self) -> &mut [u8] {
+                        self.storage.as_mut()
+                    }
+                }
+                $crate::define_layout!(@impl_field_access {$($field_name),*});
+
+                /// A cursor over a [View] that writes the fields of the layout one after the other, in the order they were declared.
+                /// Create it with [cursor]. See [LayoutCursor](crate::LayoutCursor).
+                pub type Cursor<S> = $crate::LayoutCursor<View<S>>;
+
+                /// Create a [Cursor] over a storage that writes the fields of the layout one after the other, in the order they were declared.
+                #[inline]
+                pub fn cursor<S: AsRef<[u8]> + AsMut<[u8]>>(storage: S) -> Cursor<S> {
+                    $crate::LayoutCursor::new(View::new(storage))
+                }
+
+                /// A [View] over a borrowed storage that was checked upfront to contain all fields of a layout with a fixed size.
+                /// Create it with [ValidatedView::try_new] or [ValidatedView::try_new_mut]. It dereferences to a [View] over
+                /// an array of [MIN_SIZE] bytes, so all read accessors of the [View] are available, and [ValidatedView::view_mut] offers the write accessors.
+                ///
+                /// Since the length of the storage is part of its type, every field is known to be in bounds and the compiler
+                /// can remove the bounds checks of the accessors once they are inlined. This is only available for layouts with a fixed size,
+                /// calling its constructors for other layouts fails to compile.
+                #[derive(Clone)]
+                pub struct ValidatedView<S: AsRef<[u8]>> {
+                    view: View<S>,
+                }
+                impl <'a> ValidatedView<&'a [u8; MIN_SIZE]> {
+                    /// Create a view over the first [MIN_SIZE] bytes of a storage after checking that it contains all fields of the layout.
+                    /// This returns a [LayoutError](crate::LayoutError) if the storage is too small. Trailing bytes are ignored.
+                    #[inline]
+                    pub fn try_new(storage: &'a [u8]) -> Result<Self, $crate::LayoutError>
+                    where
+                        for<'b> $crate::internal::SizeCheck<{ SIZE.is_some() }>: $crate::internal::FixedSizeLayout,
+                    {
+                        $crate::internal::check_min_size(SIZE, FIELDS, storage.len())?;
+                        let storage = storage[..MIN_SIZE].try_into().expect("Storage size was checked above");
+                        Ok(Self { view: View::new(storage) })
+                    }
+                }
+                impl <'a> ValidatedView<&'a mut [u8; MIN_SIZE]> {
+                    /// Create a view with write access over the first [MIN_SIZE] bytes of a storage after checking that it contains all fields of the layout.
+                    /// This returns a [LayoutError](crate::LayoutError) if the storage is too small. Trailing bytes are ignored.
+                    #[inline]
+                    pub fn try_new_mut(storage: &'a mut [u8]) -> Result<Self, $crate::LayoutError>
+                    where
+                        for<'b> $crate::internal::SizeCheck<{ SIZE.is_some() }>: $crate::internal::FixedSizeLayout,
+                    {
+                        $crate::internal::check_min_size(SIZE, FIELDS, storage.len())?;
+                        let storage = (&mut storage[..MIN_SIZE]).try_into().expect("Storage size was checked above");
+                        Ok(Self { view: View::new(storage) })
+                    }
+
+                    /// Return a [View] with write access to the fields. It borrows the storage of this [ValidatedView].
+                    #[inline]
+                    pub fn view_mut(&mut self) -> View<&mut [u8; MIN_SIZE]> {
+                        View::new(&mut *self.view.storage)
+                    }
+                }
+                impl <S: AsRef<[u8]>> ValidatedView<S> {
+                    /// Return the underlying [View]
+                    #[inline]
+                    pub fn into_view(self) -> View<S> {
+                        self.view
+                    }
+                }
+                impl <S: AsRef<[u8]>> core::ops::Deref for ValidatedView<S> {
+                    type Target = View<S>;
+
+                    #[inline(always)]
+                    fn deref(&self) -> &View<S> {
+                        &self.view
+                    }
+                }
+
+                /// A [View] for a layout ending with an open ended field, whose fields before the open ended field are in one storage
+                /// and whose open ended field is in another one, e.g. a header and a body in different allocations.
+                /// Create it with [View::new_split]. It dereferences to the [View] over the header, so the accessors of all fields
+                /// before the open ended field are available and read from the header. The open ended field itself is accessed with
+                /// [SplitView::body] and [SplitView::body_mut], the accessor of the header [View] for it returns an empty slice.
+                #[derive(Clone)]
+                pub struct SplitView<H: AsRef<[u8]>, B: AsRef<[u8]>> {
+                    header: View<H>,
+                    body: B,
+                }
+                impl <H: AsRef<[u8]>, B: AsRef<[u8]>> SplitView<H, B> {
+                    /// Return the bytes of the open ended field, i.e. the body storage
+                    #[inline]
+                    pub fn body(&self) -> &[u8] {
+                        self.body.as_ref()
+                    }
+
+                    /// Return the header and the body storage
+                    #[inline]
+                    pub fn into_parts(self) -> (H, B) {
+                        (self.header.storage, self.body)
+                    }
+                }
+                impl <H: AsRef<[u8]>, B: AsRef<[u8]> + AsMut<[u8]>> SplitView<H, B> {
+                    /// Return the bytes of the open ended field for writing, i.e. the body storage
+                    #[inline]
+                    pub fn body_mut(&mut self) -> &mut [u8] {
+                        self.body.as_mut()
+                    }
+                }
+                impl <H: AsRef<[u8]>, B: AsRef<[u8]>> core::ops::Deref for SplitView<H, B> {
+                    type Target = View<H>;
+
+                    #[inline(always)]
+                    fn deref(&self) -> &View<H> {
+                        &self.header
+                    }
+                }
+                impl <H: AsRef<[u8]>, B: AsRef<[u8]>> core::ops::DerefMut for SplitView<H, B> {
+                    #[inline(always)]
+                    fn deref_mut(&mut self) -> &mut View<H> {
+                        &mut self.header
+                    }
+                }
+                impl <H: AsRef<[u8]>> View<H> {
+                    /// Create a [SplitView] over a header storage with the fields before the open ended field of the layout
+                    /// and a separate body storage with the open ended field, without concatenating them.
+                    /// This returns a [LayoutError](crate::LayoutError) if the header isn't exactly [MIN_SIZE] bytes long, or if the open ended field
+                    /// was declared with a maximum length and the record would be longer than [MAX_SIZE].
+                    /// This is only available for layouts ending with an open ended field without a `@trailer` section,
+                    /// calling it for other layouts fails to compile.
+                    #[inline]
+                    pub fn new_split<B: AsRef<[u8]>>(header: H, body: B) -> Result<SplitView<H, B>, $crate::LayoutError>
+                    where
+                        for<'a> $crate::internal::SizeCheck<{ SIZE.is_none() && $crate::internal::tail_offset(MIN_SIZE, FIELDS) == MIN_SIZE }>: $crate::internal::SplittableLayout,
+                    {
+                        $crate::internal::check_exact_size(Some(MIN_SIZE), FIELDS, header.as_ref().len())?;
+                        $crate::internal::check_max_size(SIZE, MAX_SIZE, MIN_SIZE + body.as_ref().len())?;
+                        Ok(SplitView { header: View::new(header), body })
+                    }
                 }
 
                 /// Use this as a marker type for using this layout as a nested field within another layout.
@@ -178,103 +1239,662 @@ macro_rules! define_layout {
                 impl $crate::internal::NestedViewInfo for NestedView {
                     const SIZE: Option<usize> = SIZE;
                 }
+
+                $crate::internal::if_std!{
+                    /// The [Owned] struct holds a decoded copy of all fields of the layout, detached from any storage.
+                    /// Open ended byte arrays are decoded into a `Vec<u8>` and nested layouts into the [Owned] struct of the nested layout.
+                    /// You can create it from a [View] by calling [View::to_owned_struct] and write it back by calling [View::write_from_struct] or [Owned::to_view].
+                    pub struct Owned {
+                        $(
+                            #[allow(missing_docs)]
+                            $(#[doc = $field_doc])*
+                            pub $field_name: <$field_name as $crate::internal::FieldToOwned>::Owned,
+                        )*
+                    }
+                    impl Clone for Owned
+                    where
+                        $(for<'a> <$field_name as $crate::internal::FieldToOwned>::Owned: Clone,)*
+                    {
+                        #[inline]
+                        fn clone(&self) -> Self {
+                            Self {
+                                $($field_name: Clone::clone(&self.$field_name),)*
+                            }
+                        }
+                    }
+                    impl core::fmt::Debug for Owned
+                    where
+                        $(for<'a> <$field_name as $crate::internal::FieldToOwned>::Owned: core::fmt::Debug,)*
+                    {
+                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                            f.debug_struct("Owned")
+                                $(.field(stringify!($field_name), &self.$field_name))*
+                                .finish()
+                        }
+                    }
+
+                    impl <S: AsRef<[u8]>> View<S> {
+                        /// Decode all fields of the layout into an [Owned] struct that is detached from the storage.
+                        #[inline]
+                        pub fn to_owned_struct(&self) -> Owned {
+                            Owned {
+                                $($field_name: <$field_name as $crate::internal::FieldToOwned>::read_owned(self.storage.as_ref()),)*
+                            }
+                        }
+                    }
+
+                    impl <S: AsRef<[u8]>> View<S> {
+                        /// Write the storage of the view to `w`, prefixed with its length as a `u32` in the endianness of the layout.
+                        /// This gives records a simple framing, e.g. on a socket, so the receiver knows where each record ends.
+                        /// Use [View::read_framed] to read it back.
+                        #[inline]
+                        pub fn write_framed(&self, w: impl $crate::internal::io::Write) -> $crate::internal::io::Result<()> {
+                            $crate::internal::write_framed::<$crate::$endianness>(self.storage.as_ref(), w)
+                        }
+                    }
+
+                    impl View<$crate::internal::Vec<u8>> {
+                        /// Grow or shrink the underlying `Vec` so that the open ended field at the end of the layout has exactly `new_len` bytes.
+                        /// The bytes of all other fields, including the fields in a `@trailer` section, are preserved, and new bytes of the open ended field are zero-initialized.
+                        /// If the storage is too short to contain the `@trailer` section, a zero-initialized trailer is appended.
+                        /// This is only available for layouts ending with an open ended field, calling it for a layout with a fixed size fails to compile.
+                        #[inline]
+                        pub fn resize_tail(&mut self, new_len: usize)
+                        where
+                            for<'a> $crate::internal::SizeCheck<{ SIZE.is_none() }>: $crate::internal::OpenEndedLayout,
+                        {
+                            let tail_offset = $crate::internal::tail_offset(MIN_SIZE, FIELDS);
+                            let trailer_len = MIN_SIZE - tail_offset;
+                            let mut trailer = if self.storage.len() >= MIN_SIZE {
+                                self.storage.split_off(self.storage.len() - trailer_len)
+                            } else {
+                                $crate::internal::Vec::new()
+                            };
+                            trailer.resize(trailer_len, 0);
+                            self.storage.resize(tail_offset + new_len, 0);
+                            self.storage.extend_from_slice(&trailer);
+                        }
+
+                        /// Read a record written by [View::write_framed] from `r` and create an owning view over it.
+                        /// This returns an [UnexpectedEof](std::io::ErrorKind::UnexpectedEof) error if `r` ends before the whole record
+                        /// was read, and an [InvalidData](std::io::ErrorKind::InvalidData) error if the record doesn't match the size of the layout, see [View::new_exact].
+                        /// Records longer than [MAX_SIZE] are rejected before any memory is allocated for them.
+                        #[inline]
+                        pub fn read_framed(r: impl $crate::internal::io::Read) -> $crate::internal::io::Result<Self> {
+                            let storage = $crate::internal::read_framed::<$crate::$endianness>(r, MAX_SIZE)?;
+                            $crate::internal::check_exact_size(SIZE, FIELDS, storage.len())
+                                .map_err(|err| $crate::internal::io::Error::new($crate::internal::io::ErrorKind::InvalidData, err))?;
+                            Ok(Self::new(storage))
+                        }
+                    }
+
+                    impl View<$crate::internal::Box<[u8]>> {
+                        /// Allocate a zero-initialized `Box<[u8]>` of [SIZE] bytes and create an owning view over it.
+                        /// Use [into_storage](View::into_storage) to get the box back after writing the fields.
+                        /// This is only available for layouts with a fixed size, calling it for a layout ending with an open ended field fails to compile.
+                        #[inline]
+                        pub fn new_boxed_zeroed() -> Self
+                        where
+                            for<'a> $crate::internal::SizeCheck<{ SIZE.is_some() }>: $crate::internal::FixedSizeLayout,
+                        {
+                            let mut storage = $crate::internal::Vec::new();
+                            storage.resize($crate::internal::unwrap_field_size(SIZE), 0);
+                            Self::new(storage.into_boxed_slice())
+                        }
+                    }
+
+                    impl<'a> View<$crate::CowStorage<'a>> {
+                        /// Create a view over a [Cow](std::borrow::Cow) that keeps borrowing the data until the first write through the view,
+                        /// which clones the data into an owned buffer. See [CowStorage](crate::CowStorage).
+                        #[inline]
+                        pub fn from_cow(storage: $crate::internal::Cow<'a, [u8]>) -> Self {
+                            Self::new($crate::CowStorage::new(storage))
+                        }
+                    }
+
+                    $crate::internal::if_memmap2!{
+                        impl View<$crate::internal::Mmap> {
+                            /// Create a read-only view over a whole memory mapped file after checking that it contains all fields of the layout.
+                            /// This returns a [LayoutError](crate::LayoutError) if the file is too small.
+                            /// Use [records] with the mapped file instead if it contains a sequence of records.
+                            #[inline]
+                            pub fn from_mmap(mmap: $crate::internal::Mmap) -> Result<Self, $crate::LayoutError> {
+                                $crate::internal::check_min_size(SIZE, FIELDS, mmap.len())?;
+                                $crate::internal::check_max_size(SIZE, MAX_SIZE, mmap.len())?;
+                                Ok(Self::new(mmap))
+                            }
+                        }
+                    }
+
+                    impl <S: AsRef<[u8]> + AsMut<[u8]>> View<S> {
+                        /// Write all fields of the layout from the given [Owned] struct into the storage.
+                        ///
+                        /// This panics if the layout ends with an open ended byte array and the remaining storage
+                        /// doesn't have exactly the length of the `Vec<u8>` in the [Owned] struct.
+                        #[inline]
+                        pub fn write_from_struct(&mut self, value: &Owned)
+                        where
+                            $(for<'a> $field_name: $crate::internal::FieldFromOwned,)*
+                        {
+                            $(<$field_name as $crate::internal::FieldFromOwned>::write_owned(self.storage.as_mut(), &value.$field_name);)*
+                        }
+                    }
+
+                    impl Owned {
+                        /// Allocate a storage of the required size and write all fields into it.
+                        #[inline]
+                        pub fn to_view(&self) -> View<$crate::internal::Vec<u8>>
+                        where
+                            $(for<'a> $field_name: $crate::internal::FieldFromOwned,)*
+                        {
+                            let mut storage = $crate::internal::Vec::new();
+                            storage.resize(<NestedView as $crate::internal::OwnedNestedViewMut>::owned_size(self), 0);
+                            let mut view = View::new(storage);
+                            view.write_from_struct(self);
+                            view
+                        }
+                    }
+
+                    /// The [Builder] assembles a record field by field and keeps track of which fields were set,
+                    /// so [Builder::build] returns an error instead of silently writing zeros for fields that were forgotten.
+                    /// Fields that should keep their default value have to be marked as defaulted explicitly.
+                    pub struct Builder {
+                        $($field_name: Option<<$field_name as $crate::internal::FieldToOwned>::Owned>,)*
+                    }
+
+                    impl Builder {
+                        /// Create a builder without any fields set.
+                        #[inline]
+                        pub fn new() -> Self {
+                            Self {
+                                $($field_name: None,)*
+                            }
+                        }
+
+                        $($crate::internal::paste!{
+                            #[doc = concat!("Set the value of the `", stringify!($field_name), "` field.")]
+                            #[inline]
+                            pub fn [<set_ $field_name>](mut self, value: <$field_name as $crate::internal::FieldToOwned>::Owned) -> Self {
+                                self.$field_name = Some(value);
+                                self
+                            }
+
+                            #[doc = concat!("Set the `", stringify!($field_name), "` field to its default value, e.g. zero. A defaulted field counts as set.")]
+                            #[inline]
+                            pub fn [<default_ $field_name>](mut self) -> Self
+                            where
+                                for<'a> <$field_name as $crate::internal::FieldToOwned>::Owned: Default,
+                            {
+                                self.$field_name = Some(Default::default());
+                                self
+                            }
+                        })*
+
+                        /// Allocate a storage of the required size and write all fields into it.
+                        /// This returns a [MissingFields](crate::MissingFields) error listing the fields that were neither set nor defaulted.
+                        #[inline]
+                        pub fn build(self) -> Result<View<$crate::internal::Vec<u8>>, $crate::MissingFields>
+                        where
+                            $(for<'a> $field_name: $crate::internal::FieldFromOwned,)*
+                        {
+                            let mut missing = $crate::internal::Vec::new();
+                            $(
+                                if self.$field_name.is_none() {
+                                    missing.push(stringify!($field_name));
+                                }
+                            )*
+                            if !missing.is_empty() {
+                                return Err($crate::MissingFields { fields: missing });
+                            }
+                            let owned = Owned {
+                                $($field_name: self.$field_name.expect("Field was checked above"),)*
+                            };
+                            Ok(owned.to_view())
+                        }
+                    }
+
+                    impl Default for Builder {
+                        #[inline]
+                        fn default() -> Self {
+                            Self::new()
+                        }
+                    }
+
+                    impl $crate::internal::OwnedNestedView for NestedView {
+                        type Owned = Owned;
+
+                        #[inline(always)]
+                        fn read_owned(storage: &[u8]) -> Self::Owned {
+                            View::new(storage).to_owned_struct()
+                        }
+                    }
+                    impl $crate::internal::OwnedNestedViewMut for NestedView
+                    where
+                        $(for<'a> $field_name: $crate::internal::FieldFromOwned,)*
+                    {
+                        #[inline(always)]
+                        fn owned_size(v: &Self::Owned) -> usize {
+                            __TRAILING_PADDING $(+ <$field_name as $crate::internal::FieldFromOwned>::owned_size(&v.$field_name))*
+                        }
+
+                        #[inline(always)]
+                        fn write_owned(storage: &mut [u8], v: &Self::Owned) {
+                            View::new(storage).write_from_struct(v)
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    (@impl_generic ($dollar: tt) ($($vis: tt)*) $name: ident [$(const $param: ident : $param_ty: ty),+], $endianness: ident, $(size = $size: expr,)? {$($fields: tt)*}) => {
+        $crate::internal::doc_comment!{
+            concat!{"
+            This module is autogenerated. It defines a layout with const generic parameters using the [binary_layout] crate.
+            Instantiate it with `define_layout!(instance_name = ", stringify!($name), "<...>)`, passing a value for each of the parameters ", $("`", stringify!($param), "` ",)+ "in order.
+            "},
+            #[allow(dead_code)]
+            $($vis)* mod $name {
+                // Instantiations call this macro to define the layout with the given parameter values.
+                #[doc(hidden)]
+                #[allow(unused_macros)]
+                macro_rules! __binary_layout_instantiate {
+                    (($dollar($dollar vis: tt)*) $dollar name: ident, $($dollar $param: tt),+ $dollar(,)?) => {
+                        $crate::define_layout!(@layout ($dollar($dollar vis)*) $dollar name [$(const $param: $param_ty = $dollar $param),+], $endianness, $(size = $size,)? {$($fields)*});
+                    };
+                }
+                #[doc(hidden)]
+                #[allow(unused_imports)]
+                pub(crate) use __binary_layout_instantiate;
             }
         }
     };
 
-    (@impl_fields $endianness: ty, $offset_accumulator: expr, {}) => {
+    // An open ended field followed by a `@trailer` section stops before the trailer, and the trailer fields
+    // are anchored to the end of the storage. Their `OFFSET` is their offset if the open ended field is empty.
+    (@impl_fields $endianness: ty, [$($size: expr)?], @trailer $trailer_offset: expr, {}) => {
+        #[doc(hidden)]
+        pub const __TRAILER_SIZE: usize = $trailer_offset;
+
+        $crate::define_layout!(@impl_fields $endianness, [$($size)?], None, {});
+    };
+    (@impl_fields $endianness: ty, [$($size: expr)?], @trailer $trailer_offset: expr, {@end $(#[doc = $doc: expr])* $name: ident : $type: ty $(, $($tail:tt)*)?}) => {
+        $crate::internal::doc_comment!{
+            concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field, which is anchored to the end of the storage\n\n"),
+            $(#[doc = $doc])*
+            #[allow(non_camel_case_types)]
+            pub type $name = $crate::PrimitiveField::<$crate::internal::FromEnd<$type, {__TRAILER_SIZE - $trailer_offset}>, $endianness, {__TAIL_OFFSET + $trailer_offset}>;
+        }
+        $crate::define_layout!(@impl_fields $endianness, [$($size)?], @trailer ($trailer_offset + $crate::internal::unwrap_field_size(<$crate::PrimitiveField::<$type, $endianness, 0> as $crate::Field>::SIZE)), {$($($tail)*)?});
+    };
+    (@impl_fields $endianness: ty, [$($size: expr)?], $offset_accumulator: expr, {$(#[doc = $doc: expr])* $name: ident : $type: ty, @end $($tail:tt)*}) => {
+        #[doc(hidden)]
+        pub const __TAIL_OFFSET: usize = $crate::internal::trailed_field_offset($offset_accumulator, <$crate::PrimitiveField::<$type, $endianness, 0> as $crate::Field>::SIZE);
+
+        $crate::internal::doc_comment!{
+            concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field\n\n"),
+            $(#[doc = $doc])*
+            #[allow(non_camel_case_types)]
+            pub type $name = $crate::PrimitiveField::<$crate::internal::Trailed<$type, __TRAILER_SIZE>, $endianness, __TAIL_OFFSET>;
+        }
+        $crate::define_layout!(@impl_fields $endianness, [$($size)?], @trailer 0, {@end $($tail)*});
+    };
+    (@impl_fields $endianness: ty, [], $offset_accumulator: expr, {}) => {
         /// Total size of the layout in number of bytes.
         /// This can be None if the layout ends with an open ended field like a byte slice.
         pub const SIZE: Option<usize> = $offset_accumulator;
+
+        #[doc(hidden)]
+        pub const __TRAILING_PADDING: usize = 0;
+    };
+    (@impl_fields $endianness: ty, [$size: expr], $offset_accumulator: expr, {}) => {
+        /// Total size of the layout in number of bytes, as declared with `size = N`.
+        /// This includes the padding after the last field.
+        pub const SIZE: Option<usize> = Some($size);
+
+        #[doc(hidden)]
+        pub const __TRAILING_PADDING: usize = $crate::internal::declared_size_padding($offset_accumulator, $size);
     };
-    (@impl_fields $endianness: ty, $offset_accumulator: expr, {$name: ident : $type: ty as $underlying_type: ty $(, $($tail:tt)*)?}) => {
+    (@impl_fields $endianness: ty, [$($size: expr)?], $offset_accumulator: expr, {$(#[doc = $doc: expr])* $name: ident : $type: ty as $underlying_type: ty $(, $($tail:tt)*)?}) => {
         $crate::internal::doc_comment!{
-            concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field"),
+            concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field\n\n"),
+            $(#[doc = $doc])*
             #[allow(non_camel_case_types)]
             pub type $name = $crate::WrappedField::<$underlying_type, $type, $crate::PrimitiveField::<$underlying_type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>>;
         }
-        $crate::define_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+        $crate::define_layout!(@impl_fields $endianness, [$($size)?], ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+    };
+    (@impl_fields $endianness: ty, [$($size: expr)?], $offset_accumulator: expr, {$(#[doc = $doc: expr])* $name: ident : $type: ty = sentinel($sentinel: expr) $(, $($tail:tt)*)?}) => {
+        $crate::internal::paste!{
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct [<__ $name _sentinel>];
+            impl $crate::SentinelValue<$type> for [<__ $name _sentinel>] {
+                const VALUE: $type = $sentinel;
+            }
+
+            $crate::internal::doc_comment!{
+                concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field\n\n"),
+                $(#[doc = $doc])*
+                #[allow(non_camel_case_types)]
+                pub type $name = $crate::SentinelField::<[<__ $name _sentinel>], $crate::PrimitiveField::<$type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>>;
+            }
+        }
+        $crate::define_layout!(@impl_fields $endianness, [$($size)?], ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+    };
+    (@impl_fields $endianness: ty, [$($size: expr)?], $offset_accumulator: expr, {$(#[doc = $doc: expr])* $name: ident : $type: ty where $validator: expr $(, $($tail:tt)*)?}) => {
+        $crate::internal::paste!{
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct [<__ $name _validator>];
+            impl $crate::FieldValidator<$type> for [<__ $name _validator>] {
+                const CONDITION: &'static str = stringify!($validator);
+
+                #[inline(always)]
+                fn is_valid(value: &$type) -> bool {
+                    ($validator)(*value)
+                }
+            }
+
+            $crate::internal::doc_comment!{
+                concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field\n\n"),
+                $(#[doc = $doc])*
+                #[allow(non_camel_case_types)]
+                pub type $name = $crate::ValidatedField::<[<__ $name _validator>], $crate::PrimitiveField::<$type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>>;
+            }
+        }
+        $crate::define_layout!(@impl_fields $endianness, [$($size)?], ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
     };
-    (@impl_fields $endianness: ty, $offset_accumulator: expr, {$name: ident : $type: ty $(, $($tail:tt)*)?}) => {
+    (@impl_fields $endianness: ty, [$($size: expr)?], $offset_accumulator: expr, {$(#[doc = $doc: expr])* $name: ident : $type: ty $(, $($tail:tt)*)?}) => {
         $crate::internal::doc_comment!{
-            concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field"),
+            concat!("Metadata and [Field](crate::Field) API accessors for the `", stringify!($name), "` field\n\n"),
+            $(#[doc = $doc])*
             #[allow(non_camel_case_types)]
             pub type $name = $crate::PrimitiveField::<$type, $endianness, {$crate::internal::unwrap_field_size($offset_accumulator)}>;
         }
-        $crate::define_layout!(@impl_fields $endianness, ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+        $crate::define_layout!(@impl_fields $endianness, [$($size)?], ($crate::internal::option_usize_add(<$name as $crate::Field>::OFFSET, <$name as $crate::Field>::SIZE)), {$($($tail)*)?});
+    };
+
+    (@impl_include_macro ($dollar: tt) {$($field_name: ident),*}) => {
+        // Layouts including this layout call this macro to get the list of fields they have to take over.
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! __binary_layout_include {
+            (($dollar($dollar vis: tt)*) $dollar name: ident, $dollar endianness: ident, $dollar($dollar include: ident)::+, {$dollar($dollar tail: tt)*}) => {
+                $crate::define_layout!(@layout ($dollar($dollar vis)*) $dollar name, $dollar endianness, {
+                    $($field_name: $crate::internal::Included<$dollar($dollar include)::+::$field_name>,)* $dollar($dollar tail)*
+                });
+            };
+        }
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use __binary_layout_include;
+    };
+
+    (@impl_peek {}) => {};
+    (@impl_peek {$name: ident $(, $name_tail: ident)*}) => {
+        $crate::internal::paste!{
+            $crate::internal::doc_comment!{
+                concat!("Read the `", stringify!($name), "` field from the given storage without creating a [View]. Returns an error if the storage is too small to contain the field. This is only available for fields with [copy access](crate::FieldCopyAccess)."),
+                #[inline]
+                pub fn [<peek_ $name>](storage: &[u8]) -> Result<<$name as $crate::FieldCopyAccess>::HighLevelType, $crate::LayoutError>
+                where
+                    for<'a> $name: $crate::FieldCopyAccess,
+                {
+                    $crate::internal::peek::<$name>(storage)
+                }
+            }
+        }
+        $crate::define_layout!(@impl_peek {$($name_tail),*});
+    };
+
+    (@impl_field_access {}) => {};
+    (@impl_field_access {$name: ident $(, $name_tail: ident)*}) => {
+        $crate::internal::paste!{
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub struct [<__ $name _key>];
+
+            impl<S: AsRef<[u8]>> $crate::ReadField<$name, [<__ $name _key>]> for View<S>
+            where
+                for<'a> $name: $crate::FieldCopyAccess,
+            {
+                #[inline]
+                fn read_field(&self) -> <$name as $crate::FieldCopyAccess>::HighLevelType {
+                    <$name as $crate::FieldCopyAccess>::read(self.storage.as_ref())
+                }
+            }
+
+            impl<S: AsRef<[u8]> + AsMut<[u8]>> $crate::WriteField<$name, [<__ $name _key>]> for View<S>
+            where
+                for<'a> $name: $crate::FieldCopyAccess,
+            {
+                #[inline]
+                fn write_field(&mut self, value: <$name as $crate::FieldCopyAccess>::HighLevelType) {
+                    <$name as $crate::FieldCopyAccess>::write(self.storage.as_mut(), value)
+                }
+            }
+        }
+        $crate::define_layout!(@impl_field_access {$($name_tail),*});
     };
 
     (@impl_view_asref {}) => {};
-    (@impl_view_asref {$name: ident $(, $name_tail: ident)*}) => {
+    (@impl_view_asref {$(#[doc = $doc: expr])* $name: ident $(, $($tail: tt)*)?}) => {
         $crate::internal::doc_comment!{
-            concat!("Return a [FieldView](crate::FieldView) with read access to the `", stringify!($name), "` field"),
+            concat!("Return a [FieldView](crate::FieldView) with read access to the `", stringify!($name), "` field\n\n"),
+            $(#[doc = $doc])*
             #[inline]
             pub fn $name(&self) -> <$name as $crate::internal::StorageToFieldView<&[u8]>>::View {
                 <$name as $crate::internal::StorageToFieldView<&[u8]>>::view(self.storage.as_ref())
             }
         }
-        $crate::define_layout!(@impl_view_asref {$($name_tail),*});
+        $crate::define_layout!(@impl_view_asref {$($($tail)*)?});
     };
 
     (@impl_view_asmut {}) => {};
-    (@impl_view_asmut {$name: ident $(, $name_tail: ident)*}) => {
+    (@impl_view_asmut {$(#[doc = $doc: expr])* $name: ident $(, $($tail: tt)*)?}) => {
         $crate::internal::paste!{
             $crate::internal::doc_comment!{
-                concat!("Return a [FieldView](crate::FieldView) with write access to the `", stringify!($name), "` field"),
+                concat!("Return a [FieldView](crate::FieldView) with write access to the `", stringify!($name), "` field\n\n"),
+                $(#[doc = $doc])*
                 #[inline]
                 pub fn [<$name _mut>](&mut self) -> <$name as $crate::internal::StorageToFieldView<&mut [u8]>>::View {
                     <$name as $crate::internal::StorageToFieldView<&mut [u8]>>::view(self.storage.as_mut())
                 }
             }
         }
-        $crate::define_layout!(@impl_view_asmut {$($name_tail),*});
+        $crate::define_layout!(@impl_view_asmut {$($($tail)*)?});
     };
 
     (@impl_view_into {}) => {};
-    (@impl_view_into {$name: ident $(, $name_tail: ident)*}) => {
+    (@impl_view_into {$(#[doc = $doc: expr])* $name: ident $(, $($tail: tt)*)?}) => {
         $crate::internal::paste!{
             $crate::internal::doc_comment!{
-                concat!("Destroy the [View] and return a field accessor to the `", stringify!($name), "` field owning the storage. This is mostly useful for [FieldView::extract](crate::FieldView::extract)"),
+                concat!("Destroy the [View] and return a field accessor to the `", stringify!($name), "` field owning the storage. This is mostly useful for [Data::into_slice](crate::Data::into_slice)\n\n"),
+                $(#[doc = $doc])*
                 #[inline]
                 pub fn [<into_ $name>](self) -> <$name as $crate::internal::StorageIntoFieldView<S>>::View {
                     <$name as $crate::internal::StorageIntoFieldView<S>>::into_view(self.storage)
                 }
             }
         }
-        $crate::define_layout!(@impl_view_into {$($name_tail),*});
+        $crate::define_layout!(@impl_view_into {$($($tail)*)?});
     };
 }
 
+/// Internal macro, don't use!
+/// Expands to its input if the `std` feature of this crate is enabled, and to nothing otherwise.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! if_std {
+    ($($tt: tt)*) => {$($tt)*};
+}
+
+/// Internal macro, don't use!
+/// Expands to its input if the `std` feature of this crate is enabled, and to nothing otherwise.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! if_std {
+    ($($tt: tt)*) => {};
+}
+
+/// Internal macro, don't use!
+/// Expands to its input if the `memmap2` feature of this crate is enabled, and to nothing otherwise.
+#[cfg(feature = "memmap2")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! if_memmap2 {
+    ($($tt: tt)*) => {$($tt)*};
+}
+
+/// Internal macro, don't use!
+/// Expands to its input if the `memmap2` feature of this crate is enabled, and to nothing otherwise.
+#[cfg(not(feature = "memmap2"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! if_memmap2 {
+    ($($tt: tt)*) => {};
+}
+
 // TODO This only exists because Option<usize>::unwrap() isn't const. Remove this once it is.
 /// Internal function, don't use!
-/// Unwraps an option<usize>
+/// Unwraps an `Option<usize>`
 #[inline(always)]
 pub const fn unwrap_field_size(opt: Option<usize>) -> usize {
     match opt {
         Some(x) => x,
-        None => {
-            #[allow(unconditional_panic)]
-            #[allow(clippy::no_effect)]
-            ["Error: Fields without a static size (e.g. open-ended byte arrays) can only be used at the end of a layout"][10];
-            #[allow(clippy::empty_loop)]
-            loop {}
+        None => panic!("Error: Fields without a static size (e.g. open-ended byte arrays) can only be used at the end of a layout"),
+    }
+}
+
+/// Internal function, don't use!
+/// Computes the number of padding bytes after the last field of a layout declared with `size = N`, given the end of its last field.
+/// This is evaluated at compile time by the define_layout! macro, so fields exceeding the declared size are a compile error.
+#[inline(always)]
+pub const fn declared_size_padding(fields_end: Option<usize>, declared: usize) -> usize {
+    match fields_end {
+        Some(fields_end) => {
+            assert!(
+                fields_end <= declared,
+                "Error: The fields of the layout exceed the size declared with `size = N`"
+            );
+            declared - fields_end
         }
+        None => panic!(
+            "Error: Layouts ending with an open ended field can't declare a size with `size = N`"
+        ),
+    }
+}
+
+/// Internal function, don't use!
+/// Checks that the struct generated for a layout declared with `repr_c = Name` has the same memory representation as the layout.
+/// This is evaluated at compile time by the define_layout! macro, so layouts that can't be mirrored by a `#[repr(C, packed)]` struct are a compile error.
+pub const fn check_repr_c(
+    layout_size: Option<usize>,
+    fields: &[crate::FieldMeta],
+    struct_size: usize,
+) {
+    let layout_size = match layout_size {
+        Some(layout_size) => layout_size,
+        None => panic!(
+            "Error: Layouts ending with an open ended field can't generate a struct with `repr_c = Name`"
+        ),
+    };
+    let mut i = 0;
+    while i < fields.len() {
+        assert!(
+            fields[i].little_endian == cfg!(target_endian = "little"),
+            "Error: Only layouts in the byte order of the target, e.g. NativeEndian, can generate a struct with `repr_c = Name`"
+        );
+        i += 1;
+    }
+    assert!(
+        layout_size == struct_size,
+        "Error: The struct generated with `repr_c = Name` doesn't have the size of the layout, only use integer, float and byte array fields"
+    );
+}
+
+/// Internal function, don't use!
+/// Checks that a field isn't an array with a length of zero, e.g. `[u8; 0]`, which wouldn't hold any data. This is evaluated at compile time
+/// by the define_layout! macro for each field separately, so a zero-length array field is a compile error with a `message` naming the field.
+#[inline(always)]
+pub const fn check_array_len(is_zero_length_array: bool, message: &str) {
+    if is_zero_length_array {
+        panic!("{}", message);
+    }
+}
+
+/// Internal function, don't use!
+/// Checks the maximum size of a layout against the budget declared with `max_size = N`. This is evaluated at compile time
+/// by the define_layout! macro, so a layout that can exceed its budget is a compile error.
+#[inline(always)]
+pub const fn check_size_budget(max_size: Option<usize>, budget: usize) {
+    match max_size {
+        Some(max_size) => assert!(
+            max_size <= budget,
+            "Error: The layout exceeds the size budget declared with `max_size = N`"
+        ),
+        None => panic!(
+            "Error: Layouts ending with an open ended field without a maximum length can't declare a size budget with `max_size = N`"
+        ),
     }
 }
 
 /// Internal function, don't use!
+/// Returns the offset of an open ended field that is followed by a `@trailer` section. This is evaluated at compile time
+/// by the define_layout! macro, so a `@trailer` section after a field that isn't open ended is a compile error.
+///
+/// ```compile_fail
+/// use binary_layout::prelude::*;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   header: u16,
+///   body: [u8; 4],
+///   @trailer {
+///     checksum: u32,
+///   },
+/// });
+///
+/// fn main() {
+///   let _ = my_layout::checksum::OFFSET;
+/// }
+/// ```
+#[inline(always)]
+pub const fn trailed_field_offset(offset: Option<usize>, field_size: Option<usize>) -> usize {
+    assert!(
+        field_size.is_none(),
+        "Error: Only an open ended field can be followed by a `@trailer` section"
+    );
+    unwrap_field_size(offset)
+}
+
+/// Internal function, don't use!
+/// Adds a field size to a field offset. This is evaluated at compile time by the [crate::define_layout!] macro,
+/// so a layout whose offsets overflow `usize` (e.g. a large layout on a 16-bit target) is a compile error.
+///
+/// ```
+/// const OFFSET: Option<usize> = binary_layout::internal::option_usize_add(usize::MAX - 2, Some(2));
+/// assert_eq!(Some(usize::MAX), OFFSET);
+/// ```
+///
+/// ```compile_fail
+/// const OFFSET: Option<usize> = binary_layout::internal::option_usize_add(usize::MAX - 1, Some(2));
+/// ```
 #[inline(always)]
 pub const fn option_usize_add(lhs: usize, rhs: Option<usize>) -> Option<usize> {
     match (lhs, rhs) {
-        (lhs, Some(rhs)) => Some(lhs + rhs),
+        (lhs, Some(rhs)) => match lhs.checked_add(rhs) {
+            Some(sum) => Some(sum),
+            None => panic!("Error: The layout is too large, field offsets overflow usize"),
+        },
         (_, None) => None,
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use crate::prelude::*;
-
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
     fn data_region(size: usize, seed: u64) -> Vec<u8> {
@@ -357,4 +1977,16 @@ mod tests {
         });
         assert_eq!(None, my_layout::SIZE);
     }
+
+    #[test]
+    fn offsets_up_to_usize_max_are_allowed() {
+        const SIZE: Option<usize> = super::option_usize_add(usize::MAX - 2, Some(2));
+        assert_eq!(Some(usize::MAX), SIZE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error: The layout is too large, field offsets overflow usize")]
+    fn offsets_overflowing_usize_are_rejected() {
+        let _ = super::option_usize_add(usize::MAX - 1, Some(2));
+    }
 }
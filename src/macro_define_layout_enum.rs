@@ -0,0 +1,110 @@
+/// This macro defines an entry point for formats where a tag field selects among several layouts defined with [define_layout!](crate::define_layout!).
+/// It generates a module with a `View<S>` enum that has a variant for each layout, holding the `View` of that layout,
+/// and a `parse(storage)` function that reads the tag and returns the variant for its value.
+///
+/// The tag field is given as the field of a layout, e.g. a `msg_type` field of a common header layout, and must be an unsigned integer.
+/// Each variant is given as a pattern for the tag value, the name of the variant and the layout.
+///
+/// `parse(storage)` returns a [LayoutError](crate::LayoutError) if the storage is too small for the tag or for the selected layout,
+/// or if no pattern matches the tag value.
+///
+/// # API
+/// ```text
+/// define_layout_enum!(<<Visibility>>? <<Name>>, <<TagField>>, {
+///   <<TagPattern>> => <<VariantName>>(<<Layout>>),
+///   <<TagPattern>> => <<VariantName>>(<<Layout>>),
+///   ...
+/// });
+/// ```
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use binary_layout::{define_layout_enum, LayoutError};
+///
+/// define_layout!(header, BigEndian, {
+///   msg_type: u8,
+/// });
+///
+/// define_layout!(ping, BigEndian, {
+///   msg_type: u8,
+///   sequence: u16,
+/// });
+///
+/// define_layout!(data, BigEndian, {
+///   msg_type: u8,
+///   length: u16,
+///   payload: [u8],
+/// });
+///
+/// define_layout_enum!(message, header::msg_type, {
+///   1 => Ping(ping),
+///   2 | 3 => Data(data),
+/// });
+///
+/// # fn main() {
+/// match message::parse(&[1, 0, 5][..]).unwrap() {
+///   message::View::Ping(view) => assert_eq!(5, view.sequence().read()),
+///   message::View::Data(_) => panic!("Expected a ping message"),
+/// }
+/// assert_eq!(Err(LayoutError::UnknownTag(4)), message::parse(&[4, 0, 5][..]).map(|_| ()));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! define_layout_enum {
+    (pub $(($($restriction: tt)*))? $name: ident, $($tail: tt)*) => {
+        $crate::define_layout_enum!(@enum (pub $(($($restriction)*))?) $name, $($tail)*);
+    };
+    ($name: ident, $($tail: tt)*) => {
+        $crate::define_layout_enum!(@enum (pub) $name, $($tail)*);
+    };
+    (@enum ($($vis: tt)*) $name: ident, $tag: ty, {$($value: pat => $variant: ident ($($layout: ident)::+)),+ $(,)?}) => {
+        $crate::internal::doc_comment!{
+            concat!{"
+            This module is autogenerated. It selects among several layouts defined using the [binary_layout] crate based on the `", stringify!($tag), "` tag field.
+            "},
+            #[allow(dead_code)]
+            $($vis)* mod $name {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// A view of the layout selected by the tag, created by [parse]
+                pub enum View<S: AsRef<[u8]>> {
+                    $(
+                        #[doc = concat!("The `", stringify!($($layout)::+), "` layout")]
+                        $variant($($layout)::+::View<S>),
+                    )+
+                }
+
+                /// Read the tag from the storage and return a view of the layout it selects.
+                /// This returns a [LayoutError](crate::LayoutError) if the storage is too small for the tag or for the selected layout,
+                /// or if the tag doesn't select any layout.
+                #[inline]
+                pub fn parse<S: AsRef<[u8]>>(storage: S) -> Result<View<S>, $crate::LayoutError> {
+                    let tag = $crate::internal::peek::<$tag>(storage.as_ref())?;
+                    match tag {
+                        $(
+                            $value => {
+                                $crate::internal::check_min_size($($layout)::+::SIZE, $($layout)::+::FIELDS, storage.as_ref().len())?;
+                                $crate::internal::check_max_size($($layout)::+::SIZE, $($layout)::+::MAX_SIZE, storage.as_ref().len())?;
+                                Ok(View::$variant($($layout)::+::View::new(storage)))
+                            }
+                        )+
+                        #[allow(unreachable_patterns)]
+                        tag => Err($crate::LayoutError::UnknownTag(tag.into())),
+                    }
+                }
+
+                impl <S: AsRef<[u8]>> View<S> {
+                    /// This destroys the view and returns the underlying storage back to you.
+                    #[inline]
+                    pub fn into_storage(self) -> S {
+                        match self {
+                            $(View::$variant(view) => view.into_storage(),)+
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+/// A storage for views that borrows its data until the first write and then clones it into an owned buffer.
+/// It wraps a [Cow] and implements `AsMut<[u8]>` by calling [Cow::to_mut], which `Cow<[u8]>` itself doesn't implement.
+/// Views over it are created with `View::from_cow(cow)`. This is only available if the `std` feature is enabled.
+///
+/// # Example
+/// ```
+/// use binary_layout::prelude::*;
+/// use std::borrow::Cow;
+///
+/// define_layout!(my_layout, LittleEndian, {
+///   field1: u16,
+///   field2: u32,
+/// });
+///
+/// # fn main() {
+/// let data = [1, 0, 2, 0, 0, 0];
+/// let mut view = my_layout::View::from_cow(Cow::Borrowed(&data[..]));
+/// assert_eq!(1, view.field1().read());
+/// assert!(!view.storage.is_owned());
+///
+/// view.field2_mut().write(10);
+/// assert!(view.storage.is_owned());
+/// assert_eq!([1, 0, 2, 0, 0, 0], data);
+/// assert_eq!(&[1, 0, 10, 0, 0, 0], &*view.into_storage().into_cow());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CowStorage<'a> {
+    cow: Cow<'a, [u8]>,
+}
+
+impl<'a> CowStorage<'a> {
+    /// Create a storage from the given [Cow]
+    #[inline]
+    pub fn new(cow: Cow<'a, [u8]>) -> Self {
+        Self { cow }
+    }
+
+    /// Return true if the data was already cloned into an owned buffer, i.e. if it was written to or was owned from the start
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        matches!(self.cow, Cow::Owned(_))
+    }
+
+    /// This destroys the storage and returns the [Cow] back to you
+    #[inline]
+    pub fn into_cow(self) -> Cow<'a, [u8]> {
+        self.cow
+    }
+}
+
+impl<'a> From<Cow<'a, [u8]>> for CowStorage<'a> {
+    #[inline]
+    fn from(cow: Cow<'a, [u8]>) -> Self {
+        Self::new(cow)
+    }
+}
+
+impl AsRef<[u8]> for CowStorage<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.cow
+    }
+}
+
+impl AsMut<[u8]> for CowStorage<'_> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.cow.to_mut()
+    }
+}
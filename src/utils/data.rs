@@ -1,6 +1,9 @@
 use core::fmt::Debug;
 use core::ops::{Bound, Deref, DerefMut, Range, RangeBounds};
 
+use crate::fields::primitive::{copy_into, count_ones};
+use crate::LayoutError;
+
 /// An instance of data owns a block of data. It implements `AsRef<[u8]>` and `AsMut<[u8]>` to allow
 /// borrowing that data, and it has a [Data::into_subregion] function that cuts away bytes at either
 /// end of the block and returns a [Data] instance that (semantically) owns a subrange of the original
@@ -34,6 +37,13 @@ impl<S> Data<S> {
         self.region.is_empty()
     }
 
+    /// Return the offset of the [Data] instance in the original storage, i.e. the number of bytes
+    /// cut away at the front when creating subregions with [Data::into_subregion].
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        self.region.start
+    }
+
     /// Return a [Data] instance that semantically only represents a subregion of the original instance.
     /// Using any data accessors like `AsRef<[u8]>` or `AsMut<[u8]>` on the new instance will behave
     /// as if the instance only owned the subregion.
@@ -114,6 +124,21 @@ where
     }
 }
 
+impl<S: AsRef<[u8]>> Data<S> {
+    /// Copy the data into the beginning of `dst` and return the number of bytes copied.
+    /// This returns a [LayoutError::DestinationTooShort] error and leaves `dst` untouched if `dst` is shorter than the data.
+    #[inline]
+    pub fn copy_into(&self, dst: &mut [u8]) -> Result<usize, LayoutError> {
+        copy_into(self.as_ref(), dst)
+    }
+
+    /// Count the set bits over all bytes of the data, e.g. for a presence bitmap extracted from a view.
+    #[inline]
+    pub fn count_ones(&self) -> u32 {
+        count_ones(self.as_ref())
+    }
+}
+
 // TODO Test
 impl<S> Deref for Data<S>
 where
@@ -138,11 +163,12 @@ where
     }
 }
 
-impl<'a> Data<&'a [u8]> {
+impl<'a, S: AsRef<[u8]> + ?Sized> Data<&'a S> {
     /// Transform the [Data] object into a slice for the data pointed to.
     /// This also extracts the lifetime and can be useful to get an object
     /// whose lifetime isn't bound to the view or any local object anymore,
     /// but instead bound to the original storage of the View.
+    /// This works for views over any borrowed storage, e.g. `&[u8]`, `&Vec<u8>` or `&Arc<[u8]>`.
     ///
     /// Example:
     /// ---------------
@@ -165,15 +191,16 @@ impl<'a> Data<&'a [u8]> {
     /// assert_eq!(0, func(&data)[0]);
     /// ```
     pub fn into_slice(self) -> &'a [u8] {
-        &self.storage[self.region]
+        &self.storage.as_ref()[self.region]
     }
 }
 
-impl<'a> Data<&'a mut [u8]> {
+impl<'a, S: AsMut<[u8]> + ?Sized> Data<&'a mut S> {
     /// Transform the [Data] object into a slice for the data pointed to.
     /// This also extracts the lifetime and can be useful to get an object
     /// whose lifetime isn't bound to the view or any local object anymore,
     /// but instead bound to the original storage of the View.
+    /// This works for views over any mutably borrowed storage, e.g. `&mut [u8]` or `&mut Vec<u8>`.
     ///
     /// Example:
     /// ---------------
@@ -196,11 +223,11 @@ impl<'a> Data<&'a mut [u8]> {
     /// func(&mut data)[0] = 5;
     /// ```
     pub fn into_slice(self) -> &'a mut [u8] {
-        &mut self.storage[self.region]
+        &mut self.storage.as_mut()[self.region]
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
     use rand::{rngs::StdRng, RngCore, SeedableRng};
@@ -0,0 +1,61 @@
+use std::fmt::Write;
+use std::string::String;
+
+const BYTES_PER_LINE: usize = 16;
+
+/// Internal function, don't use!
+/// Formats the data like `xxd` does: 16 bytes per line, each line starting with the offset,
+/// followed by the bytes in hex in groups of two and an ASCII gutter with `.` for non-printable bytes.
+pub fn hexdump(data: &[u8]) -> String {
+    let mut result = String::new();
+    for (line_index, line) in data.chunks(BYTES_PER_LINE).enumerate() {
+        write!(result, "{:08x}:", line_index * BYTES_PER_LINE).unwrap();
+        for index in 0..BYTES_PER_LINE {
+            if index % 2 == 0 {
+                result.push(' ');
+            }
+            match line.get(index) {
+                Some(byte) => write!(result, "{:02x}", byte).unwrap(),
+                None => result.push_str("  "),
+            }
+        }
+        result.push_str("  ");
+        for &byte in line {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                result.push(byte as char);
+            } else {
+                result.push('.');
+            }
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!("", hexdump(&[]));
+    }
+
+    #[test]
+    fn full_line() {
+        assert_eq!(
+            "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 2100 01ff  Hello, world!...\n",
+            hexdump(b"Hello, world!\x00\x01\xff")
+        );
+    }
+
+    #[test]
+    fn partial_line() {
+        let data: Vec<u8> = (0..20).collect();
+        assert_eq!(
+            "00000000: 0001 0203 0405 0607 0809 0a0b 0c0d 0e0f  ................\n\
+             00000010: 1011 1213                                ....\n",
+            hexdump(&data)
+        );
+    }
+}
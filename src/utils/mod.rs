@@ -1 +1,5 @@
+#[cfg(feature = "std")]
+pub mod cow;
 pub mod data;
+#[cfg(feature = "std")]
+pub mod hexdump;
@@ -0,0 +1,71 @@
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+
+mod common;
+use common::data_region;
+
+define_layout!(aligned, LittleEndian, {
+    first: [u8; 5],
+    _padding: AlignTo<8>,
+    second: u64,
+    third: u16,
+    _padding2: AlignTo<4>,
+    fourth: u32,
+    _padding3: AlignTo<4>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(5, aligned::_padding::OFFSET);
+    assert_eq!(Some(3), aligned::_padding::SIZE);
+    assert_eq!(8, aligned::second::OFFSET);
+    assert_eq!(16, aligned::third::OFFSET);
+    assert_eq!(18, aligned::_padding2::OFFSET);
+    assert_eq!(Some(2), aligned::_padding2::SIZE);
+    assert_eq!(20, aligned::fourth::OFFSET);
+    assert_eq!(24, aligned::_padding3::OFFSET);
+    assert_eq!(Some(0), aligned::_padding3::SIZE);
+    assert_eq!(Some(24), aligned::SIZE);
+}
+
+#[test]
+fn view_readwrite() {
+    let mut storage = data_region(1024, 5);
+    let mut view = aligned::View::new(&mut storage);
+
+    // Test initial data is read correctly
+    assert_eq!(&data_region(1024, 5)[5..8], view._padding());
+    assert_eq!(
+        u64::from_le_bytes((&data_region(1024, 5)[8..16]).try_into().unwrap()),
+        view.second().read()
+    );
+    assert_eq!(
+        u32::from_le_bytes((&data_region(1024, 5)[20..24]).try_into().unwrap()),
+        view.fourth().read()
+    );
+
+    // Test data can be written
+    view.second_mut().write(10u64.pow(15));
+    view._padding_mut().fill(0);
+
+    // Test original storage is actually changed
+    assert_eq!(&[0, 0, 0], &storage[5..8]);
+    assert_eq!(
+        10u64.pow(15),
+        u64::from_le_bytes((&storage[8..16]).try_into().unwrap())
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_struct_roundtrip() {
+    let owned = aligned::View::new(data_region(1024, 5)).to_owned_struct();
+
+    let view = owned.to_view();
+    assert_eq!(24, view.storage.len());
+    // Padding is zeroed
+    assert_eq!(&[0, 0, 0], view._padding());
+    assert_eq!(&[0, 0], view._padding2());
+    assert_eq!(owned.second, view.second().read());
+    assert_eq!(owned.fourth, view.fourth().read());
+}
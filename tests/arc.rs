@@ -0,0 +1,85 @@
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+use std::sync::Arc;
+use std::thread;
+
+mod common;
+use common::data_region;
+
+define_layout!(arc_layout, LittleEndian, {
+    first: i8,
+    second: i64,
+    third: u16,
+    tail: [u8],
+});
+
+#[test]
+fn view_readonly() {
+    let storage: Arc<[u8]> = data_region(1024, 5).into();
+    let view = arc_layout::View::new(Arc::clone(&storage));
+
+    // Test initial data is read correctly
+    assert_eq!(
+        i8::from_le_bytes((&data_region(1024, 5)[0..1]).try_into().unwrap()),
+        view.first().read()
+    );
+    assert_eq!(
+        i64::from_le_bytes((&data_region(1024, 5)[1..9]).try_into().unwrap()),
+        view.second().read()
+    );
+    assert_eq!(
+        u16::from_le_bytes((&data_region(1024, 5)[9..11]).try_into().unwrap()),
+        view.third().read()
+    );
+    assert_eq!(&data_region(1024, 5)[11..], view.tail());
+
+    // Test into_storage will return the original Arc
+    let extracted_storage: Arc<[u8]> = view.into_storage();
+    assert!(Arc::ptr_eq(&storage, &extracted_storage));
+}
+
+#[test]
+fn clone_shares_storage() {
+    let storage: Arc<[u8]> = data_region(1024, 5).into();
+    let view = arc_layout::View::new(Arc::clone(&storage));
+    let cloned = view.clone();
+    assert_eq!(3, Arc::strong_count(&storage));
+
+    assert_eq!(view.second().read(), cloned.second().read());
+
+    let extracted_storage = view.into_storage();
+    let extracted_clone_storage = cloned.into_storage();
+    assert!(Arc::ptr_eq(&extracted_storage, &extracted_clone_storage));
+    assert!(Arc::ptr_eq(&storage, &extracted_storage));
+}
+
+#[test]
+fn read_concurrently() {
+    let storage: Arc<[u8]> = data_region(1024, 5).into();
+    let view = arc_layout::View::new(Arc::clone(&storage));
+
+    let threads: Vec<_> = (0..2)
+        .map(|_| {
+            let view = view.clone();
+            thread::spawn(move || {
+                (
+                    view.first().read(),
+                    view.second().read(),
+                    view.third().read(),
+                    view.tail().to_vec(),
+                )
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        let (first, second, third, tail) = thread.join().unwrap();
+        assert_eq!(view.first().read(), first);
+        assert_eq!(view.second().read(), second);
+        assert_eq!(view.third().read(), third);
+        assert_eq!(view.tail(), &*tail);
+    }
+
+    drop(view);
+    assert_eq!(1, Arc::strong_count(&storage));
+}
@@ -0,0 +1,57 @@
+use binary_layout::prelude::*;
+
+define_layout!(header, BigEndian, {
+    lsb_flags: BitFields<u8>,
+    msb_flags: BitFields<u8, MsbFirst>,
+    wide: BitFields<u32, MsbFirst>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, header::lsb_flags::OFFSET);
+    assert_eq!(Some(1), header::lsb_flags::SIZE);
+    assert_eq!(2, header::wide::OFFSET);
+    assert_eq!(Some(4), header::wide::SIZE);
+}
+
+#[test]
+fn bit_order() {
+    // The same byte and the same declared bit range read differently in both bit orders
+    let storage = [0b1100_0001, 0b1100_0001, 0, 0, 0, 0];
+    let view = header::View::new(&storage);
+    assert_eq!(0b01, view.lsb_flags().read_bits(0..2));
+    assert_eq!(0b11, view.msb_flags().read_bits(0..2));
+    assert_eq!(0b1100, view.lsb_flags().read_bits(4..8));
+    assert_eq!(0b0001, view.msb_flags().read_bits(4..8));
+}
+
+#[test]
+fn view_readwrite() {
+    let mut storage = [0; 6];
+    let mut view = header::View::new(&mut storage);
+
+    view.lsb_flags_mut().write_bits(0..1, 1);
+    view.msb_flags_mut().write_bits(0..1, 1);
+    // RFC style: version in bits 0-3, traffic class in bits 4-11, flow label in bits 12-31
+    view.wide_mut().write_bits(0..4, 6);
+    view.wide_mut().write_bits(4..12, 0xAB);
+    view.wide_mut().write_bits(12..32, 0x12345);
+    view.wide_mut().write_bits(4..12, 0xCD);
+
+    assert_eq!(6, view.wide().read_bits(0..4));
+    assert_eq!(0xCD, view.wide().read_bits(4..12));
+    assert_eq!(0x12345, view.wide().read_bits(12..32));
+    assert_eq!(0x6CD1_2345, view.wide().read());
+    assert_eq!([0b0000_0001, 0b1000_0000, 0x6C, 0xD1, 0x23, 0x45], storage);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned() {
+    let view = header::View::new(vec![1, 2, 0, 0, 0, 3]);
+    let owned = view.to_owned_struct();
+    assert_eq!(1, owned.lsb_flags);
+    assert_eq!(2, owned.msb_flags);
+    assert_eq!(3, owned.wide);
+    assert_eq!(vec![1, 2, 0, 0, 0, 3], owned.to_view().into_storage());
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "bitflags")]
+
+use binary_layout::prelude::*;
+use binary_layout::{FlagsAs, LayoutError, UnknownFlags};
+use std::convert::TryInto;
+
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Capabilities: u16 {
+        const READ = 1 << 0;
+        const WRITE = 1 << 1;
+        const ADMIN = 1 << 15;
+    }
+}
+
+define_layout!(user_entry, BigEndian, {
+    id: u32,
+    capabilities: FlagsAs<Capabilities>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(4, user_entry::capabilities::OFFSET);
+    assert_eq!(Some(2), user_entry::capabilities::SIZE);
+    assert_eq!(Some(6), user_entry::SIZE);
+}
+
+#[test]
+fn round_trip() {
+    let mut storage = vec![0; 1024];
+    let mut view = user_entry::View::new(&mut storage);
+
+    let capabilities = Capabilities::READ | Capabilities::ADMIN;
+    view.capabilities_mut().write(capabilities);
+    assert_eq!(capabilities, view.capabilities().read());
+    assert_eq!(Ok(capabilities), view.capabilities().try_read());
+    assert_eq!(capabilities, user_entry::capabilities::read(&storage));
+    assert_eq!(
+        0x8001,
+        u16::from_be_bytes(storage[4..6].try_into().unwrap())
+    );
+
+    let owned = user_entry::View::new(&storage).to_owned_struct();
+    assert_eq!(capabilities, owned.capabilities);
+}
+
+#[test]
+fn unknown_bits() {
+    let mut storage = vec![0; 1024];
+    storage[4..6].copy_from_slice(&0x0103u16.to_be_bytes());
+
+    let view = user_entry::View::new(&storage);
+    assert_eq!(
+        Capabilities::READ | Capabilities::WRITE,
+        view.capabilities().read()
+    );
+    assert_eq!(
+        Err(LayoutError::UnknownFlags(0x0103)),
+        view.capabilities().try_read()
+    );
+    assert_eq!(
+        "Stored value 0x103 has unknown flags set",
+        UnknownFlags(0x0103u16).to_string()
+    );
+}
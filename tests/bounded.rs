@@ -0,0 +1,90 @@
+use binary_layout::prelude::*;
+use binary_layout::LayoutError;
+
+mod common;
+use common::data_region;
+
+const MAX_PAYLOAD: usize = 8;
+
+define_layout!(message, LittleEndian, {
+    /// Message type
+    msg_type: u8,
+    length: u16 where |v| v as usize <= MAX_PAYLOAD,
+    payload: [u8; ..=MAX_PAYLOAD],
+});
+
+define_layout!(unbounded, LittleEndian, {
+    msg_type: u8,
+    payload: [u8],
+});
+
+define_layout!(generic_message, Endian, {
+    id: u16,
+    payload: [u8; ..=4],
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(3, message::payload::OFFSET);
+    assert_eq!(None, message::payload::SIZE);
+    assert_eq!(Some(MAX_PAYLOAD), message::payload::MAX_SIZE);
+    assert_eq!(None, message::SIZE);
+    assert_eq!(3, message::MIN_SIZE);
+    assert_eq!(Some(11), message::MAX_SIZE);
+    assert_eq!(None, unbounded::MAX_SIZE);
+    assert_eq!(Some(6), generic_message::MAX_SIZE);
+}
+
+#[test]
+fn accessors() {
+    let mut storage = data_region(7, 0);
+    let mut view = message::View::new(&mut storage);
+    view.length_mut().write(4);
+    view.payload_mut().copy_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(&[1, 2, 3, 4], view.payload());
+    assert_eq!(&[1, 2, 3, 4], message::payload::data(&storage));
+}
+
+#[test]
+fn new_exact() {
+    let storage = data_region(11, 0);
+    assert!(message::View::new_exact(&storage[..]).is_ok());
+    assert!(message::View::new_exact(&storage[..3]).is_ok());
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 3,
+            actual: 2
+        }),
+        message::View::new_exact(&storage[..2]).map(|_| ())
+    );
+
+    let storage = data_region(12, 0);
+    assert_eq!(
+        Err(LayoutError::BufferTooLarge {
+            expected: 11,
+            actual: 12
+        }),
+        message::View::new_exact(&storage[..]).map(|_| ())
+    );
+    assert!(unbounded::View::new_exact(&storage[..]).is_ok());
+}
+
+#[test]
+fn generic_endianness() {
+    let storage = data_region(7, 0);
+    assert!(generic_message::View::<_, BigEndian>::new_exact(&storage[..6]).is_ok());
+    assert!(generic_message::View::<_, LittleEndian>::new_exact(&storage[..]).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_framed_rejects_too_large_record() {
+    let mut buffer = Vec::new();
+    unbounded::View::new(data_region(12, 0))
+        .write_framed(&mut buffer)
+        .unwrap();
+    let error = message::View::read_framed(std::io::Cursor::new(buffer))
+        .err()
+        .unwrap();
+    assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+}
@@ -0,0 +1,48 @@
+use binary_layout::prelude::*;
+
+define_layout!(boxed_layout, BigEndian, {
+    first: u8,
+    second: u32,
+    third: [u8; 3],
+});
+
+define_layout!(boxed_slice_layout, BigEndian, {
+    first: u8,
+    tail: [u8],
+});
+
+#[cfg(feature = "std")]
+#[test]
+fn new_boxed_zeroed() {
+    let mut view = boxed_layout::View::new_boxed_zeroed();
+    assert_eq!(0, view.first().read());
+    assert_eq!(0, view.second().read());
+
+    view.first_mut().write(1);
+    view.second_mut().write(0x0203_0405);
+    view.third_mut().copy_from_slice(&[6, 7, 8]);
+
+    let storage: Box<[u8]> = view.into_storage();
+    assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8][..], &*storage);
+}
+
+#[test]
+fn view_over_box() {
+    let storage: Box<[u8]> = vec![9, 0, 0, 0, 1, 0, 0, 0].into_boxed_slice();
+    let ptr = storage.as_ptr();
+    let mut view = boxed_layout::View::new(storage);
+    assert_eq!(9, view.first().read());
+    assert_eq!(1, view.second().read());
+
+    view.second_mut().write(2);
+    let storage = view.into_storage();
+    assert_eq!(ptr, storage.as_ptr());
+    assert_eq!([0, 0, 0, 2], storage[1..5]);
+}
+
+#[test]
+fn into_field_over_box() {
+    let storage: Box<[u8]> = vec![5, 1, 2, 3].into_boxed_slice();
+    let tail = boxed_slice_layout::View::new(storage).into_tail();
+    assert_eq!(&[1, 2, 3], tail.as_ref());
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "std")]
+
+use binary_layout::prelude::*;
+use binary_layout::{LayoutError, MissingFields};
+
+define_layout!(inner, LittleEndian, {
+    a: u8,
+    b: u16,
+});
+
+define_layout!(record, BigEndian, {
+    kind: u8,
+    length: u16,
+    nested: inner::NestedView,
+    reserved: [u8; 2],
+    payload: [u8],
+});
+
+#[test]
+fn build_with_all_fields_set() {
+    let view = record::Builder::new()
+        .set_kind(1)
+        .set_length(3)
+        .set_nested(inner::Owned { a: 2, b: 0x0304 })
+        .set_reserved([5, 6])
+        .set_payload(vec![7, 8, 9])
+        .build()
+        .unwrap();
+    assert_eq!(
+        &[1, 0, 3, 2, 0x04, 0x03, 5, 6, 7, 8, 9],
+        &*view.into_storage()
+    );
+}
+
+#[test]
+fn defaulted_fields_count_as_set() {
+    let view = record::Builder::new()
+        .set_kind(1)
+        .set_length(0)
+        .set_nested(inner::Owned { a: 2, b: 3 })
+        .default_reserved()
+        .default_payload()
+        .build()
+        .unwrap();
+    assert_eq!(&[1, 0, 0, 2, 3, 0, 0, 0], &*view.into_storage());
+}
+
+#[test]
+fn build_with_missing_field() {
+    let result = record::Builder::new()
+        .set_kind(1)
+        .set_nested(inner::Owned { a: 2, b: 3 })
+        .default_payload()
+        .build();
+    let error = result.err().unwrap();
+    assert_eq!(
+        MissingFields {
+            fields: vec!["length", "reserved"]
+        },
+        error
+    );
+    assert_eq!(
+        "Missing required fields: length, reserved",
+        error.to_string()
+    );
+}
+
+#[test]
+fn propagate_missing_fields_with_question_mark() {
+    fn build(a: Option<u8>) -> Result<u16, LayoutError> {
+        let mut builder = inner::Builder::new().set_b(3);
+        if let Some(a) = a {
+            builder = builder.set_a(a);
+        }
+        Ok(builder.build()?.b().read())
+    }
+
+    assert_eq!(Ok(3), build(Some(1)));
+    assert_eq!(Err(LayoutError::MissingField("a")), build(None));
+    assert_eq!(
+        "Missing required field: a",
+        LayoutError::MissingField("a").to_string()
+    );
+}
+
+#[test]
+fn build_without_fields_set() {
+    let result = inner::Builder::default().build();
+    assert_eq!(vec!["a", "b"], result.err().unwrap().fields);
+}
+
+#[test]
+fn setting_a_field_twice_keeps_the_last_value() {
+    let view = inner::Builder::new()
+        .set_a(1)
+        .set_a(2)
+        .set_b(3)
+        .build()
+        .unwrap();
+    assert_eq!(2, view.a().read());
+}
@@ -0,0 +1,64 @@
+use binary_layout::{prelude::*, LayoutError};
+use std::convert::TryInto;
+
+define_layout!(char_layout, BigEndian, {
+    first: u8,
+    symbol: char,
+    last: u16,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(1, char_layout::symbol::OFFSET);
+    assert_eq!(Some(4), char_layout::symbol::SIZE);
+    assert_eq!(5, char_layout::last::OFFSET);
+}
+
+#[test]
+fn fields() {
+    let mut storage = vec![0; 7];
+
+    char_layout::symbol::write(&mut storage, 'ß');
+    assert_eq!(Ok('ß'), char_layout::symbol::read(&storage));
+    assert_eq!(
+        0xDF,
+        u32::from_be_bytes((&storage[1..5]).try_into().unwrap())
+    );
+}
+
+#[test]
+fn view_valid_char() {
+    let mut storage = vec![0; 7];
+    let mut view = char_layout::View::new(&mut storage);
+
+    view.symbol_mut().write('🦀');
+    assert_eq!(Ok('🦀'), view.symbol().read());
+    assert_eq!(
+        0x1F980,
+        u32::from_be_bytes((&storage[1..5]).try_into().unwrap())
+    );
+}
+
+#[test]
+fn view_invalid_char() {
+    let mut storage = vec![0; 7];
+    storage[1..5].copy_from_slice(&0x110000u32.to_be_bytes());
+    let view = char_layout::View::new(&storage);
+
+    assert_eq!(
+        Err(LayoutError::InvalidChar(0x110000)),
+        view.symbol().read()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_struct_roundtrip() {
+    let mut storage = vec![0; 7];
+    storage[1..5].copy_from_slice(&0x110000u32.to_be_bytes());
+    let owned = char_layout::View::new(&storage).to_owned_struct();
+    assert_eq!(Err(binary_layout::InvalidChar(0x110000)), owned.symbol);
+
+    // Invalid code points are written back unchanged
+    assert_eq!(&storage, &owned.to_view().into_storage());
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "std")]
+
+use binary_layout::prelude::*;
+use binary_layout::{ChunkedReader, LayoutError};
+
+define_layout!(header, BigEndian, {
+    msg_type: u8,
+    flags: u16,
+    length: u32,
+    payload: [u8],
+});
+
+#[test]
+fn field_in_first_chunk() {
+    let chunks: [&[u8]; 2] = [&[7, 0x12, 0x34, 0], &[0, 1, 0]];
+    let reader = ChunkedReader::new(&chunks);
+    assert_eq!(Ok(7), reader.read::<header::msg_type>());
+    assert_eq!(Ok(0x1234), reader.read::<header::flags>());
+}
+
+#[test]
+fn field_split_across_two_chunks() {
+    let chunks: [&[u8]; 2] = [&[7, 0x12, 0x34, 0xAB, 0xCD], &[0xEF, 0x01, 0xFF, 0xFF]];
+    let reader = ChunkedReader::new(&chunks);
+    assert_eq!(Ok(0xABCD_EF01), reader.read::<header::length>());
+}
+
+#[test]
+fn field_split_across_many_chunks() {
+    let chunks: [&[u8]; 5] = [&[7, 0x12], &[0x34, 0xAB], &[], &[0xCD, 0xEF], &[0x01]];
+    let reader = ChunkedReader::new(&chunks);
+    assert_eq!(Ok(0x1234), reader.read::<header::flags>());
+    assert_eq!(Ok(0xABCD_EF01), reader.read::<header::length>());
+    assert_eq!(7, reader.len());
+}
+
+#[test]
+fn chunks_too_small() {
+    let chunks: [&[u8]; 2] = [&[7, 0x12, 0x34], &[0xAB, 0xCD]];
+    let reader = ChunkedReader::new(&chunks);
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 7,
+            actual: 5
+        }),
+        reader.read::<header::length>()
+    );
+}
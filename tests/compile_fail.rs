@@ -0,0 +1,8 @@
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    // Functions that only exist with the std feature fail with a different error without it
+    #[cfg(feature = "std")]
+    t.compile_fail("tests/ui/std/*.rs");
+}
@@ -0,0 +1,61 @@
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+
+mod common;
+use common::data_region;
+
+const N: usize = 5;
+
+mod sizes {
+    pub const HEADER_SIZE: usize = 3;
+}
+
+define_layout!(const_array_size, LittleEndian, {
+    first: u8,
+    payload: [u8; N],
+    header: [u8; sizes::HEADER_SIZE],
+    doubled: [u8; 2 * N],
+    last: u16,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, const_array_size::first::OFFSET);
+    assert_eq!(Some(1), const_array_size::first::SIZE);
+    assert_eq!(1, const_array_size::payload::OFFSET);
+    assert_eq!(Some(5), const_array_size::payload::SIZE);
+    assert_eq!(6, const_array_size::header::OFFSET);
+    assert_eq!(Some(3), const_array_size::header::SIZE);
+    assert_eq!(9, const_array_size::doubled::OFFSET);
+    assert_eq!(Some(10), const_array_size::doubled::SIZE);
+    assert_eq!(19, const_array_size::last::OFFSET);
+    assert_eq!(Some(2), const_array_size::last::SIZE);
+    assert_eq!(Some(21), const_array_size::SIZE);
+}
+
+#[test]
+fn view_readwrite() {
+    let mut storage = data_region(1024, 5);
+    let mut view = const_array_size::View::new(&mut storage);
+
+    // Test initial data is read correctly
+    let payload: &[u8; N] = view.payload();
+    assert_eq!(&data_region(1024, 5)[1..6], payload);
+    assert_eq!(&data_region(1024, 5)[6..9], view.header());
+    assert_eq!(&data_region(1024, 5)[9..19], view.doubled());
+    assert_eq!(
+        u16::from_le_bytes((&data_region(1024, 5)[19..21]).try_into().unwrap()),
+        view.last().read()
+    );
+
+    // Test data can be written
+    view.payload_mut().copy_from_slice(&[1, 2, 3, 4, 5]);
+    view.last_mut().write(1000);
+
+    // Test original storage is actually changed
+    assert_eq!(&[1, 2, 3, 4, 5], &storage[1..6]);
+    assert_eq!(
+        1000,
+        u16::from_le_bytes((&storage[19..21]).try_into().unwrap())
+    );
+}
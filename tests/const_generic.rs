@@ -0,0 +1,70 @@
+use binary_layout::prelude::*;
+
+define_layout!(keyed_message<const K: usize>, BigEndian, {
+    id: u16,
+    key: [u8; K],
+    value: u32,
+});
+
+define_layout!(short_key_message = keyed_message<4>);
+define_layout!(pub(crate) long_key_message = keyed_message<8>);
+
+define_layout!(framed<const HEADER: usize, const TRAILER: usize>, LittleEndian, {
+    header: [u8; HEADER],
+    length: u16,
+    trailer: [u8; TRAILER],
+    payload: [u8],
+});
+
+const TRAILER_LEN: usize = 3;
+define_layout!(small_frame = framed<2, TRAILER_LEN>);
+define_layout!(large_frame = framed<{ 2 * 8 }, 1>);
+
+#[test]
+fn metadata() {
+    assert_eq!(4, short_key_message::K);
+    assert_eq!(Some(4), short_key_message::key::SIZE);
+    assert_eq!(6, short_key_message::value::OFFSET);
+    assert_eq!(Some(10), short_key_message::SIZE);
+
+    assert_eq!(8, long_key_message::K);
+    assert_eq!(Some(8), long_key_message::key::SIZE);
+    assert_eq!(10, long_key_message::value::OFFSET);
+    assert_eq!(Some(14), long_key_message::SIZE);
+}
+
+#[test]
+fn view() {
+    let mut short_storage = vec![0; short_key_message::SIZE.unwrap()];
+    let mut view = short_key_message::View::new(&mut short_storage);
+    view.id_mut().write(1);
+    view.key_mut().copy_from_slice(&[2; 4]);
+    view.value_mut().write(3);
+    assert_eq!(&[2; 4], view.key());
+    assert_eq!(&[0, 1, 2, 2, 2, 2, 0, 0, 0, 3], &*short_storage);
+
+    let mut long_storage = vec![0; long_key_message::SIZE.unwrap()];
+    let mut view = long_key_message::View::new(&mut long_storage);
+    view.id_mut().write(1);
+    view.key_mut().copy_from_slice(&[2; 8]);
+    view.value_mut().write(3);
+    assert_eq!(&[2; 8], view.key());
+    assert_eq!(&[0, 1, 2, 2, 2, 2, 2, 2, 2, 2, 0, 0, 0, 3], &*long_storage);
+}
+
+#[test]
+fn several_parameters() {
+    assert_eq!(2, small_frame::HEADER);
+    assert_eq!(3, small_frame::TRAILER);
+    assert_eq!(4, small_frame::trailer::OFFSET);
+    assert_eq!(7, small_frame::payload::OFFSET);
+
+    assert_eq!(16, large_frame::HEADER);
+    assert_eq!(1, large_frame::TRAILER);
+    assert_eq!(18, large_frame::trailer::OFFSET);
+    assert_eq!(19, large_frame::payload::OFFSET);
+
+    let view = small_frame::View::new(vec![0, 0, 5, 0, 0, 0, 0, 9, 9]);
+    assert_eq!(5, view.length().read());
+    assert_eq!(&[9, 9], view.payload());
+}
@@ -0,0 +1,79 @@
+use binary_layout::prelude::*;
+
+mod common;
+use common::data_region;
+
+define_layout!(record, LittleEndian, {
+    first: u64,
+    second: [u8; 1000],
+    third: i32,
+    fourth: [u8; 3000],
+});
+
+define_layout!(record_with_tail, BigEndian, {
+    first: u16,
+    tail: [u8],
+});
+
+#[test]
+fn copy_record_from() {
+    let src = record::View::new(data_region(record::SIZE.unwrap(), 1));
+    let mut dst = record::View::new(data_region(record::SIZE.unwrap(), 2));
+    assert_ne!(src.storage, dst.storage);
+
+    dst.copy_record_from(&src);
+    assert_eq!(src.storage, dst.storage);
+    assert_eq!(src.first().read(), dst.first().read());
+    assert_eq!(src.fourth(), dst.fourth());
+}
+
+#[test]
+fn copy_record_from_larger_storage() {
+    let size = record::SIZE.unwrap();
+    let src = record::View::new(data_region(size + 10, 1));
+    let mut dst = record::View::new(vec![0; size + 10]);
+
+    dst.copy_record_from(&src);
+    assert_eq!(&src.storage[..size], &dst.storage[..size]);
+    assert_eq!(&[0; 10], &dst.storage[size..]);
+}
+
+#[test]
+fn copy_record_from_open_ended() {
+    let src = record_with_tail::View::new(data_region(100, 1));
+    let mut dst = record_with_tail::View::new(data_region(150, 2));
+
+    dst.copy_record_from(&src);
+    assert_eq!(&src.storage[..], &dst.storage[..100]);
+    assert_eq!(&data_region(150, 2)[100..], &dst.storage[100..]);
+}
+
+#[test]
+#[should_panic]
+fn copy_record_from_too_small() {
+    let src = record::View::new(data_region(record::SIZE.unwrap(), 1));
+    let mut dst = record::View::new(vec![0; 10]);
+    dst.copy_record_from(&src);
+}
+
+#[test]
+fn copy_record_from_many_times() {
+    // Copy a wide record back and forth many times to make sure the single copy is cheap enough for a hot loop.
+    let size = record::SIZE.unwrap();
+    let mut a = record::View::new(data_region(size, 1));
+    let mut b = record::View::new(data_region(size, 2));
+    let expected_field_by_field = {
+        let mut view = record::View::new(vec![0; size]);
+        view.first_mut().write(a.first().read());
+        view.second_mut().copy_from_slice(a.second());
+        view.third_mut().write(a.third().read());
+        view.fourth_mut().copy_from_slice(a.fourth());
+        view.into_storage()
+    };
+    for _ in 0..10_000 {
+        b.copy_record_from(&a);
+        a.copy_record_from(&b);
+    }
+    assert_eq!(expected_field_by_field, a.into_storage());
+    assert_eq!(expected_field_by_field, b.into_storage());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+use binary_layout::prelude::*;
+use binary_layout::CowStorage;
+use std::borrow::Cow;
+
+define_layout!(header, LittleEndian, {
+    id: u16,
+    flags: u8,
+    payload: [u8],
+});
+
+#[test]
+fn read_only_keeps_borrowing() {
+    let data = [1, 0, 7, 10, 20];
+    let view = header::View::from_cow(Cow::Borrowed(&data[..]));
+    assert_eq!(1, view.id().read());
+    assert_eq!(7, view.flags().read());
+    assert_eq!(&[10, 20], view.payload());
+    assert!(!view.storage.is_owned());
+    assert!(matches!(view.into_storage().into_cow(), Cow::Borrowed(_)));
+}
+
+#[test]
+fn first_write_clones() {
+    let data = [1, 0, 7, 10, 20];
+    let mut view = header::View::from_cow(Cow::Borrowed(&data[..]));
+    assert_eq!(1, view.id().read());
+    assert!(!view.storage.is_owned());
+
+    view.flags_mut().write(8);
+    assert!(view.storage.is_owned());
+    view.payload_mut()[1] = 21;
+
+    assert_eq!([1, 0, 7, 10, 20], data);
+    assert_eq!(8, view.flags().read());
+    assert_eq!(
+        Cow::<[u8]>::Owned(vec![1, 0, 8, 10, 21]),
+        view.into_storage().into_cow()
+    );
+}
+
+#[test]
+fn owned_from_the_start() {
+    let storage = CowStorage::from(Cow::Owned(vec![0; 4]));
+    assert!(storage.is_owned());
+    let mut view = header::View::new(storage);
+    view.id_mut().write(0x0102);
+    assert_eq!(&[2, 1, 0, 0], view.storage.as_ref());
+}
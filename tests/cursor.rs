@@ -0,0 +1,92 @@
+use binary_layout::prelude::*;
+
+define_layout!(record, BigEndian, {
+    kind: u8,
+    id: u32,
+    name: [u8; 4],
+    marker: (),
+    value: i16,
+    payload: [u8],
+});
+
+#[test]
+fn write_full_record() {
+    let mut storage = vec![0; 13];
+    let mut cursor = record::cursor(&mut storage);
+    assert_eq!(Some("kind"), cursor.next_field().map(|field| field.name));
+    cursor.write_next::<record::kind>(1);
+    cursor.write_next::<record::id>(0x01020304);
+    cursor.write_next_bytes::<record::name>(b"abcd");
+    cursor.write_next::<record::marker>(());
+    cursor.write_next::<record::value>(-2);
+    assert!(!cursor.is_complete());
+    cursor.write_next_bytes::<record::payload>(&[9, 8]);
+    assert!(cursor.is_complete());
+    assert_eq!(None, cursor.next_field());
+
+    let view = cursor.into_view();
+    assert_eq!(0x01020304, view.id().read());
+    assert_eq!(
+        &[1, 1, 2, 3, 4, b'a', b'b', b'c', b'd', 0xFF, 0xFE, 9, 8],
+        &storage[..]
+    );
+}
+
+#[test]
+fn chained() {
+    define_layout!(pair, LittleEndian, {
+        first: u16,
+        second: u16,
+    });
+
+    let mut cursor = pair::cursor([0; 4]);
+    cursor
+        .write_next::<pair::first>(1)
+        .write_next::<pair::second>(2);
+    assert_eq!([1, 0, 2, 0], cursor.into_view().into_storage());
+}
+
+#[test]
+#[should_panic(
+    expected = "Error: Fields must be written in declaration order, the next field is `id`"
+)]
+fn skipped_field() {
+    let mut cursor = record::cursor(vec![0; 13]);
+    cursor.write_next::<record::kind>(1);
+    cursor.write_next_bytes::<record::name>(b"abcd");
+}
+
+#[test]
+#[should_panic(
+    expected = "Error: Fields must be written in declaration order, the next field is `id`"
+)]
+fn field_written_twice() {
+    let mut cursor = record::cursor(vec![0; 13]);
+    cursor.write_next::<record::kind>(1);
+    cursor.write_next::<record::kind>(2);
+}
+
+#[test]
+#[should_panic(
+    expected = "Error: Fields must be written in declaration order, the next field is `marker`"
+)]
+fn zero_sized_field_skipped() {
+    let mut cursor = record::cursor(vec![0; 13]);
+    cursor
+        .write_next::<record::kind>(1)
+        .write_next::<record::id>(2)
+        .write_next_bytes::<record::name>(b"abcd")
+        .write_next::<record::value>(3);
+}
+
+#[test]
+#[should_panic(expected = "Error: All fields of the layout were already written")]
+fn past_the_end() {
+    define_layout!(single, LittleEndian, {
+        only: u8,
+    });
+
+    let mut cursor = single::cursor([0; 1]);
+    cursor.write_next::<single::only>(1);
+    cursor.write_next::<single::only>(1);
+}
@@ -0,0 +1,100 @@
+use binary_layout::prelude::*;
+
+define_layout!(slot, BigEndian, size = 16, {
+    id: u32,
+    value: u16,
+});
+
+define_layout!(exact, BigEndian, size = 6, {
+    id: u32,
+    value: u16,
+});
+
+define_layout!(table, BigEndian, {
+    first: slot::NestedView,
+    second: slot::NestedView,
+});
+
+define_layout!(generic_slot, Endian, size = 8, {
+    id: u16,
+});
+
+define_layout!(budgeted, BigEndian, max_size = 8, {
+    id: u32,
+    value: u16,
+});
+
+define_layout!(budgeted_slot, BigEndian, size = 16, max_size = 16, {
+    id: u32,
+});
+
+define_layout!(budgeted_frame, Endian, max_size = 32, {
+    length: u16,
+    payload: [u8; ..=30],
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(Some(16), slot::SIZE);
+    assert_eq!(16, slot::MIN_SIZE);
+    assert_eq!(Some(16), slot::MAX_SIZE);
+    assert_eq!(4, slot::value::OFFSET);
+    assert_eq!(2, slot::FIELD_COUNT);
+    assert_eq!(Some(6), exact::SIZE);
+    assert_eq!(Some(8), generic_slot::SIZE);
+}
+
+#[test]
+fn exact_size_includes_padding() {
+    assert!(slot::View::new_exact(&[0; 16][..]).is_ok());
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 16,
+            actual: 6
+        }),
+        slot::View::new_exact(&[0; 6][..]).map(|_| ())
+    );
+    assert!(slot::ValidatedView::try_new(&[0; 6]).is_err());
+    assert!(slot::ValidatedView::try_new(&[0; 16]).is_ok());
+}
+
+#[test]
+fn records_are_padded() {
+    let mut storage = [0; 32];
+    storage[16..20].copy_from_slice(&7u32.to_be_bytes());
+    let ids: Vec<u32> = slot::records(&storage).map(|r| r.id().read()).collect();
+    assert_eq!(vec![0, 7], ids);
+}
+
+#[test]
+fn nested_slots_are_padded() {
+    assert_eq!(16, table::second::OFFSET);
+    assert_eq!(Some(32), table::SIZE);
+    let mut view = table::View::new([0; 32]);
+    view.second_mut().value_mut().write(0x0102);
+    assert_eq!(&[0x01, 0x02], &view.into_storage()[20..22]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_struct_includes_padding() {
+    let owned = slot::Owned { id: 1, value: 2 };
+    let view = owned.to_view();
+    assert_eq!(16, view.len());
+    let read = view.to_owned_struct();
+    assert_eq!((1, 2), (read.id, read.value));
+}
+
+#[test]
+fn size_budget_doesnt_change_layout() {
+    assert_eq!(Some(6), budgeted::SIZE);
+    assert_eq!(Some(6), budgeted::MAX_SIZE);
+    assert_eq!(4, budgeted::value::OFFSET);
+    assert_eq!(Some(16), budgeted_slot::SIZE);
+    assert_eq!(None, budgeted_frame::SIZE);
+    assert_eq!(Some(32), budgeted_frame::MAX_SIZE);
+
+    let mut view = budgeted::View::new([0; 6]);
+    view.value_mut().write(0x0102);
+    assert_eq!([0, 0, 0, 0, 1, 2], view.into_storage());
+}
@@ -0,0 +1,179 @@
+use binary_layout::prelude::*;
+use binary_layout::LayoutError;
+
+define_layout!(audio, BigEndian, {
+    channel: u8,
+    sample_rate: u32,
+    samples: [u16],
+});
+
+define_layout!(measurements, LittleEndian, {
+    count: u16,
+    values: [f32],
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(5, audio::samples::OFFSET);
+    assert_eq!(None, audio::samples::SIZE);
+    assert_eq!(None, audio::SIZE);
+}
+
+#[test]
+fn read_samples() {
+    let storage = [
+        1, 0, 0, 0xAC, 0x44, 0x01, 0x02, 0x7F, 0xFF, 0x00, 0x10, 0xAB,
+    ];
+    let view = audio::View::new(&storage[..]);
+    let samples = view.samples();
+
+    assert_eq!(3, samples.len());
+    assert!(!samples.is_empty());
+    assert_eq!(Some(0x0102), samples.get(0));
+    assert_eq!(Some(0x7FFF), samples.get(1));
+    assert_eq!(Some(0x0010), samples.get(2));
+    assert_eq!(None, samples.get(3));
+    assert_eq!(
+        vec![0x0102, 0x7FFF, 0x0010],
+        samples.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn write_samples() {
+    let mut storage = vec![0; 9];
+    let mut view = audio::View::new(&mut storage);
+    view.samples_mut().set(0, 0xABCD);
+    view.samples_mut().set(1, 1);
+    assert_eq!([0xAB, 0xCD, 0x00, 0x01], storage[5..]);
+}
+
+#[test]
+#[should_panic(expected = "Error: Index 2 is out of bounds for 2 elements")]
+fn write_out_of_bounds() {
+    let mut storage = vec![0; 9];
+    audio::View::new(&mut storage).samples_mut().set(2, 0);
+}
+
+#[test]
+fn floats_little_endian() {
+    let mut view = measurements::View::new(vec![0; 10]);
+    view.values_mut().set(1, 1.5);
+    assert_eq!(2, view.values().len());
+    assert_eq!(Some(0.0), view.values().get(0));
+    assert_eq!(Some(1.5), view.values().get(1));
+    assert_eq!(1.5f32.to_le_bytes(), view.into_storage()[6..]);
+}
+
+#[test]
+fn into_samples() {
+    let storage = vec![1, 0, 0, 0, 0, 0, 5, 0, 6];
+    let samples = audio::View::new(storage).into_samples();
+    assert_eq!(vec![5, 6], samples.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn into_elements() {
+    let storage: std::rc::Rc<[u8]> = vec![1, 0, 0, 0, 0, 0x01, 0x02, 0x03, 0x04, 0xFF].into();
+    let mut elements = audio::View::new(std::rc::Rc::clone(&storage))
+        .into_samples()
+        .into_elements();
+    assert_eq!(2, elements.len());
+    assert_eq!(Some(0x0102), elements.next());
+    assert_eq!(1, elements.len());
+    assert_eq!(2, std::rc::Rc::strong_count(&storage));
+
+    let rest: Vec<u16> = elements.collect();
+    assert_eq!(vec![0x0304], rest);
+    // The iterator owned the storage and dropped it after it was consumed
+    assert_eq!(1, std::rc::Rc::strong_count(&storage));
+
+    let samples: Vec<u16> = audio::View::new(storage.to_vec())
+        .into_samples()
+        .into_elements()
+        .collect();
+    assert_eq!(vec![0x0102, 0x0304], samples);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned() {
+    let storage = [1, 0, 0, 0xAC, 0x44, 0x01, 0x02, 0x7F, 0xFF];
+    let owned = audio::View::new(&storage[..]).to_owned_struct();
+    assert_eq!(vec![0x0102, 0x7FFF], owned.samples);
+    assert_eq!(storage[..], owned.to_view().into_storage()[..]);
+}
+
+define_layout!(aligned_samples, NativeEndian, {
+    samples: [u32],
+});
+
+define_layout!(misaligned_samples, NativeEndian, {
+    channel: u8,
+    samples: [u32],
+});
+
+#[cfg(feature = "std")]
+#[repr(align(4))]
+struct Aligned([u8; 12]);
+
+fn native_bytes(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_ne_bytes()).collect()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bulk_copy_aligned() {
+    let mut storage = Aligned([0; 12]);
+    storage
+        .0
+        .copy_from_slice(&native_bytes(&[1, 0xDEADBEEF, 3]));
+    let view = aligned_samples::View::new(&storage.0[..]);
+    assert_eq!(0, view.samples().as_bytes().as_ptr() as usize % 4);
+
+    let mut dst = [0u32; 4];
+    assert_eq!(Ok(3), view.samples().copy_into(&mut dst));
+    assert_eq!([1, 0xDEADBEEF, 3, 0], dst);
+    assert_eq!(vec![1, 0xDEADBEEF, 3], view.samples().to_vec());
+    assert_eq!(&storage.0[..], view.samples().as_bytes());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bulk_copy_misaligned() {
+    let mut storage = Aligned([0; 12]);
+    storage.0[0] = 7;
+    storage.0[1..9].copy_from_slice(&native_bytes(&[1, 0xDEADBEEF]));
+    let view = misaligned_samples::View::new(&storage.0[..]);
+    assert_eq!(1, view.samples().as_bytes().as_ptr() as usize % 4);
+
+    let mut dst = [0u32; 2];
+    assert_eq!(Ok(2), view.samples().copy_into(&mut dst));
+    assert_eq!([1, 0xDEADBEEF], dst);
+    assert_eq!(vec![1, 0xDEADBEEF], view.samples().to_vec());
+    // The trailing 3 bytes don't belong to an element
+    assert_eq!(&storage.0[1..9], view.samples().as_bytes());
+}
+
+#[test]
+fn bulk_copy_big_endian() {
+    let storage = [1, 0, 0, 0xAC, 0x44, 0x01, 0x02, 0x7F, 0xFF, 0x00];
+    let view = audio::View::new(&storage[..]);
+    let mut dst = [0u16; 2];
+    assert_eq!(Ok(2), view.samples().copy_into(&mut dst));
+    assert_eq!([0x0102, 0x7FFF], dst);
+}
+
+#[test]
+fn bulk_copy_destination_too_short() {
+    let storage = native_bytes(&[1, 2, 3]);
+    let view = aligned_samples::View::new(&storage[..]);
+    let mut dst = [0u32; 2];
+    assert_eq!(
+        Err(LayoutError::DestinationTooShort {
+            required: 12,
+            actual: 8
+        }),
+        view.samples().copy_into(&mut dst)
+    );
+}
@@ -0,0 +1,92 @@
+use binary_layout::prelude::*;
+
+define_layout!(record, BigEndian, {
+    kind: u8,
+    checksum: u16,
+    payload: [u8; 3],
+    tail: [u8],
+});
+
+fn make_record(checksum: u16, payload: [u8; 3]) -> record::View<Vec<u8>> {
+    let mut view = record::View::new(vec![0; 8]);
+    view.kind_mut().write(1);
+    view.checksum_mut().write(checksum);
+    view.payload_mut().copy_from_slice(&payload);
+    view.tail_mut().copy_from_slice(&[4, 5]);
+    view
+}
+
+#[test]
+fn equal_records() {
+    let lhs = make_record(1000, [1, 2, 3]);
+    let rhs = make_record(1000, [1, 2, 3]);
+    assert!(lhs.eq_ignoring(&rhs, &[]));
+    assert!(lhs.eq_ignoring(&rhs, &["checksum"]));
+}
+
+#[test]
+fn records_differing_only_in_ignored_field() {
+    let lhs = make_record(1000, [1, 2, 3]);
+    let rhs = make_record(2000, [1, 2, 3]);
+    assert!(!lhs.eq_ignoring(&rhs, &[]));
+    assert!(lhs.eq_ignoring(&rhs, &["checksum"]));
+    assert!(lhs.eq_ignoring(&rhs, &["payload", "checksum"]));
+}
+
+#[test]
+fn records_differing_in_other_field() {
+    let lhs = make_record(1000, [1, 2, 3]);
+    let rhs = make_record(2000, [1, 2, 4]);
+    assert!(!lhs.eq_ignoring(&rhs, &["checksum"]));
+    assert!(lhs.eq_ignoring(&rhs, &["checksum", "payload"]));
+}
+
+#[test]
+fn records_differing_in_tail() {
+    let lhs = make_record(1000, [1, 2, 3]);
+    let mut rhs = make_record(1000, [1, 2, 3]);
+    rhs.tail_mut()[1] = 6;
+    assert!(!lhs.eq_ignoring(&rhs, &["checksum"]));
+    assert!(lhs.eq_ignoring(&rhs, &["tail"]));
+
+    let shorter = record::View::new(&lhs.storage[..7]);
+    assert!(!lhs.eq_ignoring(&shorter, &[]));
+}
+
+#[test]
+fn unknown_field_names_are_ignored() {
+    let lhs = make_record(1000, [1, 2, 3]);
+    let rhs = make_record(2000, [1, 2, 3]);
+    assert!(!lhs.eq_ignoring(&rhs, &["unknown"]));
+    assert!(lhs.eq_ignoring(&rhs, &["unknown", "checksum"]));
+}
+
+const TEMPLATE: [u8; 8] = [1, 0, 0, 1, 2, 3, 4, 5];
+const CHECKSUM_MASK: [u8; 8] = [0xFF, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+
+#[test]
+fn matches_template_ignoring_checksum() {
+    assert!(make_record(1000, [1, 2, 3]).matches_template(&TEMPLATE, &CHECKSUM_MASK));
+    assert!(make_record(2000, [1, 2, 3]).matches_template(&TEMPLATE, &CHECKSUM_MASK));
+    assert!(!make_record(1000, [1, 2, 3]).matches_template(&TEMPLATE, &[0xFF; 8]));
+    assert!(!make_record(1000, [1, 2, 4]).matches_template(&TEMPLATE, &CHECKSUM_MASK));
+}
+
+#[test]
+fn matches_template_with_partial_mask_bits() {
+    let mut mask = CHECKSUM_MASK;
+    mask[0] = 0xF0;
+    let mut view = make_record(1000, [1, 2, 3]);
+    view.kind_mut().write(0x0E);
+    assert!(view.matches_template(&TEMPLATE, &mask));
+    view.kind_mut().write(0x11);
+    assert!(!view.matches_template(&TEMPLATE, &mask));
+}
+
+#[test]
+fn matches_template_with_different_length() {
+    let view = make_record(1000, [1, 2, 3]);
+    assert!(!view.matches_template(&[1, 0, 0, 1, 2, 3, 4], &[0xFF; 7]));
+    let shorter = record::View::new(&view.storage[..7]);
+    assert!(!shorter.matches_template(&TEMPLATE, &CHECKSUM_MASK));
+}
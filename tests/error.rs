@@ -0,0 +1,214 @@
+use binary_layout::error::{
+    BufferTooSmall, InvalidChar, IterLengthError, LayoutError, LengthError, RangeError, TooShort,
+};
+use binary_layout::prelude::*;
+#[cfg(feature = "std")]
+use std::error::Error;
+use std::str::Utf8Error;
+
+define_layout!(record, LittleEndian, {
+    id: u16,
+    letter: char,
+    tail: [u8],
+});
+
+#[cfg(feature = "std")]
+fn parse(storage: &[u8]) -> Result<(u16, char), Box<dyn Error>> {
+    let view = record::View::new(storage);
+    let id = record::peek_id(storage)?;
+    let letter = view.letter().read()?;
+    record::tail::data_checked(storage, 2)?;
+    Ok((id, letter))
+}
+
+#[test]
+fn propagate_with_question_mark() {
+    fn inner(storage: &[u8]) -> Result<char, LayoutError> {
+        let view = record::View::new(storage);
+        let letter = view.letter().read()?;
+        Ok(letter)
+    }
+
+    assert_eq!(Ok('x'), inner(&[0, 0, b'x', 0, 0, 0]));
+    assert_eq!(
+        Err(LayoutError::InvalidChar(0x110000)),
+        inner(&[0, 0, 0, 0, 0x11, 0])
+    );
+}
+
+#[test]
+fn propagate_utf8_error_with_question_mark() {
+    fn tail(storage: &[u8]) -> Result<&str, LayoutError> {
+        let tail = record::tail::data(storage).as_str()?;
+        Ok(tail)
+    }
+
+    assert_eq!(Ok("ok"), tail(&[0, 0, b'x', 0, 0, 0, b'o', b'k']));
+    assert!(matches!(
+        tail(&[0, 0, b'x', 0, 0, 0, b'o', 0xFF]),
+        Err(LayoutError::Utf8Error(error)) if error.valid_up_to() == 1
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn convert_into_box_dyn_error() {
+    assert_eq!((5, 'x'), parse(&[5, 0, b'x', 0, 0, 0, 1, 2]).unwrap());
+
+    let error = parse(&[5, 0, 0, 0, 0x11, 0, 1, 2]).unwrap_err();
+    assert_eq!("Invalid unicode scalar value: 0x110000", error.to_string());
+
+    let error = parse(&[5, 0, b'x', 0, 0, 0, 1]).unwrap_err();
+    assert_eq!(
+        "Byte array too short: required at least 2 bytes but only got 1",
+        error.to_string()
+    );
+
+    let error: Box<dyn Error> = Box::new(LayoutError::OutOfRange);
+    assert_eq!(
+        Some(&LayoutError::OutOfRange),
+        error.downcast_ref::<LayoutError>()
+    );
+}
+
+fn invalid_utf8() -> Utf8Error {
+    let bytes = vec![b'a', 0xFF];
+    std::str::from_utf8(&bytes).unwrap_err()
+}
+
+#[test]
+fn from_specific_errors() {
+    assert_eq!(
+        LayoutError::BufferTooSmall {
+            required: 4,
+            actual: 2
+        },
+        LayoutError::from(BufferTooSmall {
+            required: 4,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        LayoutError::TooShort {
+            min_len: 3,
+            actual_len: 1
+        },
+        LayoutError::from(TooShort {
+            min_len: 3,
+            actual_len: 1
+        })
+    );
+    assert_eq!(
+        LayoutError::DestinationTooShort {
+            required: 5,
+            actual: 4
+        },
+        LayoutError::from(LengthError {
+            required: 5,
+            actual: 4
+        })
+    );
+    assert_eq!(
+        LayoutError::IterLength {
+            expected: 4,
+            actual: 2
+        },
+        LayoutError::from(IterLengthError {
+            expected: 4,
+            actual: 2
+        })
+    );
+    assert_eq!(LayoutError::OutOfRange, LayoutError::from(RangeError));
+    assert_eq!(
+        LayoutError::InvalidChar(0xD800),
+        LayoutError::from(InvalidChar(0xD800))
+    );
+    let utf8_error = invalid_utf8();
+    assert_eq!(
+        LayoutError::Utf8Error(utf8_error),
+        LayoutError::from(utf8_error)
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        "Storage too small: required 4 bytes but only got 2",
+        LayoutError::BufferTooSmall {
+            required: 4,
+            actual: 2
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Storage too large: expected 4 bytes but got 6",
+        LayoutError::BufferTooLarge {
+            expected: 4,
+            actual: 6
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Byte array too short: required at least 3 bytes but only got 1",
+        LayoutError::TooShort {
+            min_len: 3,
+            actual_len: 1
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Destination too short: required 5 bytes but only got 4",
+        LayoutError::DestinationTooShort {
+            required: 5,
+            actual: 4
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Iterator too short: expected 4 elements but only got 2",
+        LayoutError::IterLength {
+            expected: 4,
+            actual: 2
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Iterator too long: expected 4 elements but got more",
+        LayoutError::IterLength {
+            expected: 4,
+            actual: 5
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Value is out of range for the field type",
+        LayoutError::OutOfRange.to_string()
+    );
+    assert_eq!(
+        "Invalid unicode scalar value: 0xd800",
+        LayoutError::InvalidChar(0xD800).to_string()
+    );
+    assert_eq!(
+        "Unknown tag value 7",
+        LayoutError::UnknownTag(7).to_string()
+    );
+    assert_eq!(
+        "Stored value 0x9 has unknown flags set",
+        LayoutError::UnknownFlags(0b1001).to_string()
+    );
+    assert_eq!(
+        "invalid utf-8 sequence of 1 bytes from index 1",
+        LayoutError::Utf8Error(invalid_utf8()).to_string()
+    );
+}
+
+#[cfg(feature = "bitflags")]
+#[test]
+fn from_unknown_flags() {
+    use binary_layout::error::UnknownFlags;
+
+    assert_eq!(
+        LayoutError::UnknownFlags(0b1001),
+        LayoutError::from(UnknownFlags(0b1001u16))
+    );
+}
@@ -1,4 +1,5 @@
 use binary_layout::{prelude::*, Data};
+use std::sync::Arc;
 
 mod common;
 use common::data_region;
@@ -100,3 +101,75 @@ fn given_mutableview_with_reftovec_when_extractingmutableref() {
 
     assert_eq!(&data_region(1024, 0)[1..], &*extracted);
 }
+
+#[test]
+fn given_immutableview_with_reftovec_when_extractingslice() {
+    define_layout!(layout, LittleEndian, {
+        field: u8,
+        tail: [u8],
+    });
+
+    fn extract(storage: &Vec<u8>) -> &[u8] {
+        layout::View::new(storage).into_tail().into_slice()
+    }
+
+    let storage = data_region(1024, 0);
+    assert_eq!(&data_region(1024, 0)[1..], extract(&storage));
+}
+
+#[test]
+fn given_mutableview_with_reftovec_when_extractingmutableslice() {
+    define_layout!(layout, LittleEndian, {
+        field: u8,
+        tail: [u8],
+    });
+
+    fn extract(storage: &mut Vec<u8>) -> &mut [u8] {
+        layout::View::new(storage).into_tail().into_slice()
+    }
+
+    let mut storage = data_region(1024, 0);
+    extract(&mut storage)[0] = 5;
+    assert_eq!(5, storage[1]);
+}
+
+#[test]
+fn given_arcview_when_extracting() {
+    define_layout!(layout, LittleEndian, {
+        field: u8,
+        tail: [u8],
+    });
+
+    let storage: Arc<[u8]> = data_region(1024, 0).into();
+    let extracted: Data<Arc<[u8]>> = {
+        let view: layout::View<Arc<[u8]>> = layout::View::new(Arc::clone(&storage));
+        view.into_tail()
+    };
+    assert_eq!(&data_region(1024, 0)[1..], &*extracted);
+
+    // A borrowed Arc can be extracted into a slice bound to the lifetime of the Arc
+    let slice: &[u8] = layout::View::new(&storage).into_tail().into_slice();
+    assert_eq!(&data_region(1024, 0)[1..], slice);
+}
+
+#[test]
+fn given_boxview_when_extracting() {
+    define_layout!(layout, LittleEndian, {
+        field: u8,
+        tail: [u8],
+    });
+
+    let storage: Box<[u8]> = data_region(1024, 0).into_boxed_slice();
+    let mut extracted: Data<Box<[u8]>> = {
+        let view: layout::View<Box<[u8]>> = layout::View::new(storage);
+        view.into_tail()
+    };
+    assert_eq!(&data_region(1024, 0)[1..], &*extracted);
+    extracted[0] = 5;
+    assert_eq!(5, extracted[0]);
+
+    let mut storage: Box<[u8]> = data_region(1024, 0).into_boxed_slice();
+    let slice: &mut [u8] = layout::View::new(&mut storage).into_tail().into_slice();
+    slice[0] = 6;
+    assert_eq!(6, storage[1]);
+}
@@ -0,0 +1,99 @@
+use binary_layout::prelude::*;
+
+define_layout!(ffi_layout, LittleEndian, {
+    first: u8,
+    second: u32,
+    tail: [u8],
+});
+
+#[test]
+fn as_ptr() {
+    let mut storage = [0; 8];
+    let mut view = ffi_layout::View::new(&mut storage[..]);
+    view.first_mut().write(42);
+
+    let ptr = view.as_ptr();
+    assert_eq!(8, view.len());
+    assert!(!view.is_empty());
+
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, view.len()) };
+    assert_eq!(42, bytes[0]);
+    assert_eq!(storage.as_ptr(), ptr);
+}
+
+#[test]
+fn as_mut_ptr() {
+    let mut view = ffi_layout::View::new(vec![0; 5]);
+    let len = view.len();
+    let ptr = view.as_mut_ptr();
+
+    // Simulate C code filling in the record
+    let bytes = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+    bytes.copy_from_slice(&[7, 1, 0, 0, 0]);
+
+    assert_eq!(7, view.first().read());
+    assert_eq!(1, view.second().read());
+}
+
+#[test]
+fn as_ptr_at_base_offset() {
+    let storage = [0, 0, 0, 9, 1, 0, 0, 0];
+    let view = ffi_layout::View::new_at(&storage[..], 3);
+    assert_eq!(5, view.len());
+    assert_eq!(9, unsafe { *view.as_ptr() });
+    assert_eq!(storage[3..].as_ptr(), view.as_ptr());
+}
+
+#[test]
+fn empty() {
+    let view = ffi_layout::View::new(&[][..]);
+    assert_eq!(0, view.len());
+    assert!(view.is_empty());
+}
+
+define_layout!(ffi_record, NativeEndian, repr_c = FfiRecord, {
+    kind: u8,
+    id: u64,
+    value: f32,
+    /// Name of the record
+    name: [u8; 3],
+    flags: i16,
+});
+
+define_layout!(budgeted_ffi_record, NativeEndian, max_size = 8, repr_c = BudgetedFfiRecord, {
+    first: u16,
+    second: [u8; 6],
+});
+
+#[test]
+fn repr_c_struct_has_layout_size() {
+    assert_eq!(Some(std::mem::size_of::<FfiRecord>()), ffi_record::SIZE);
+    assert_eq!(
+        Some(std::mem::size_of::<BudgetedFfiRecord>()),
+        budgeted_ffi_record::SIZE
+    );
+}
+
+#[test]
+fn repr_c_struct_has_layout_representation() {
+    let record = FfiRecord {
+        kind: 1,
+        id: 0x0102_0304_0506_0708,
+        value: 1.5,
+        name: *b"abc",
+        flags: -2,
+    };
+    let bytes = unsafe {
+        std::slice::from_raw_parts(
+            &record as *const FfiRecord as *const u8,
+            std::mem::size_of::<FfiRecord>(),
+        )
+    };
+
+    let view = ffi_record::View::new(bytes);
+    assert_eq!(1, view.kind().read());
+    assert_eq!(0x0102_0304_0506_0708, view.id().read());
+    assert_eq!(1.5, view.value().read());
+    assert_eq!(b"abc", view.name());
+    assert_eq!(-2, view.flags().read());
+}
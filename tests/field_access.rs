@@ -0,0 +1,89 @@
+use binary_layout::prelude::*;
+
+define_layout!(header, LittleEndian, {
+    version: u16,
+    flags: u8,
+    length: u32,
+    payload: [u8],
+});
+
+define_layout!(packet, BigEndian, {
+    kind: u8,
+    version: u16,
+    first: (),
+    second: (),
+});
+
+fn read_u16<F, K>(view: &impl ReadField<F, K>) -> u16
+where
+    F: FieldCopyAccess<HighLevelType = u16>,
+{
+    view.read_field()
+}
+
+fn increment_u16<F, K>(view: &mut impl WriteField<F, K>)
+where
+    F: FieldCopyAccess<HighLevelType = u16>,
+{
+    let value = view.read_field();
+    view.write_field(value + 1);
+}
+
+#[test]
+fn read_u16_from_different_layouts() {
+    let header = header::View::new([0x01, 0x02, 0, 0, 0, 0, 0]);
+    let packet = packet::View::new([0, 0x03, 0x04]);
+    assert_eq!(0x0201, read_u16::<header::version, _>(&header));
+    assert_eq!(0x0304, read_u16::<packet::version, _>(&packet));
+}
+
+#[test]
+fn read_u16_through_reference() {
+    let storage = [0x05, 0x00, 0, 0, 0, 0, 0];
+    let view = header::View::new(&storage[..]);
+    assert_eq!(5, read_u16::<header::version, _>(&view));
+}
+
+#[test]
+fn write_u16_from_different_layouts() {
+    let mut header = header::View::new(vec![0xff, 0x00, 0, 0, 0, 0, 0, 1, 2]);
+    increment_u16::<header::version, _>(&mut header);
+    assert_eq!(0x100, header.version().read());
+    assert_eq!(&[1, 2], header.payload());
+
+    let mut storage = [0, 0x00, 0xff];
+    let mut packet = packet::View::new(&mut storage[..]);
+    increment_u16::<packet::version, _>(&mut packet);
+    assert_eq!(0x100, packet.version().read());
+}
+
+#[test]
+fn other_field_types() {
+    let mut header = header::View::new([0; 7]);
+    WriteField::<header::length, _>::write_field(&mut header, 1000);
+    WriteField::<header::flags, _>::write_field(&mut header, 3);
+    assert_eq!(1000, ReadField::<header::length, _>::read_field(&header));
+    assert_eq!(3, ReadField::<header::flags, _>::read_field(&header));
+}
+
+type DirectField = binary_layout::PrimitiveField<u32, BigEndian, 12>;
+const _: () = binary_layout::check_field_bounds::<DirectField>(16);
+const _: () = binary_layout::check_field_bounds::<header::payload>(7);
+
+#[test]
+fn field_bounds() {
+    binary_layout::check_field_bounds::<header::length>(7);
+    binary_layout::check_field_bounds::<DirectField>(100);
+}
+
+#[test]
+#[should_panic(expected = "Error: The field exceeds the declared maximum size")]
+fn field_out_of_bounds() {
+    binary_layout::check_field_bounds::<DirectField>(15);
+}
+
+#[test]
+#[should_panic(expected = "Error: The field exceeds the declared maximum size")]
+fn open_ended_field_out_of_bounds() {
+    binary_layout::check_field_bounds::<header::payload>(6);
+}
@@ -0,0 +1,62 @@
+#![deny(missing_docs)]
+//! Tests that doc comments on fields are accepted and forwarded by the define_layout! macro
+
+use binary_layout::prelude::*;
+use binary_layout::LayoutAs;
+
+/// A custom field type
+#[derive(Debug, PartialEq, Eq)]
+pub struct Version(u16);
+impl LayoutAs<u16> for Version {
+    fn read(v: u16) -> Self {
+        Version(v)
+    }
+
+    fn write(v: Self) -> u16 {
+        v.0
+    }
+}
+
+define_layout!(documented, LittleEndian, {
+    /// The first field
+    ///
+    /// It has a doc comment with multiple paragraphs.
+    first: u8,
+    /// A wrapped field
+    version: Version as u16,
+    /// A field with a sentinel
+    id: u32 = sentinel(0xFFFF_FFFF),
+    #[doc = "A field documented with a doc attribute"]
+    tail: [u8],
+});
+
+define_layout!(documented_optional, LittleEndian, optional(presence: u8) {
+    /// An optional field
+    first: u16,
+    second: u32,
+});
+
+#[cfg(feature = "std")]
+#[test]
+fn documented_fields_work() {
+    let mut storage = vec![0; 1024];
+    let mut view = documented::View::new(&mut storage[..]);
+    view.first_mut().write(5);
+    view.version_mut().write(Version(2));
+    view.id_mut().write(None);
+    assert_eq!(5, view.first().read());
+    assert_eq!(Version(2), view.version().read());
+    assert_eq!(None, view.id().read());
+    assert_eq!(&[0, 0], &view.tail()[..2]);
+    assert_eq!(&["first", "version", "id", "tail"], documented::FIELD_NAMES);
+
+    let owned = documented::View::new(&storage[..]).to_owned_struct();
+    assert_eq!(5, owned.first);
+
+    let mut storage = vec![0; 1024];
+    let mut view = documented_optional::View::new(&mut storage[..]);
+    view.presence_mut().write(0b1);
+    view.first_mut().unwrap().write(10);
+    assert_eq!(Some(10), view.first().map(|f| f.read()));
+    assert!(view.second().is_none());
+}
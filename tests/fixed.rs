@@ -0,0 +1,43 @@
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+
+define_layout!(sensor, LittleEndian, {
+    id: u8,
+    reading: Fixed<16, 16>,
+    offset: Fixed<8, 8>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(1, sensor::reading::OFFSET);
+    assert_eq!(Some(4), sensor::reading::SIZE);
+    assert_eq!(5, sensor::offset::OFFSET);
+    assert_eq!(Some(2), sensor::offset::SIZE);
+    assert_eq!(Some(7), sensor::SIZE);
+}
+
+#[test]
+fn view_readwrite() {
+    let mut storage = vec![0; 1024];
+    let mut view = sensor::View::new(&mut storage);
+
+    view.reading_mut().write(21.375);
+    view.offset_mut().write(-0.1);
+    assert_eq!(21.375, view.reading().read());
+    assert!((-0.1 - view.offset().read()).abs() <= 0.5 / 256.0);
+
+    assert_eq!(
+        0x15_6000,
+        i32::from_le_bytes(storage[1..5].try_into().unwrap())
+    );
+    assert_eq!(-26, i16::from_le_bytes(storage[5..7].try_into().unwrap()));
+}
+
+#[test]
+fn round_trip() {
+    let mut storage = vec![0; 1024];
+    for value in [0.0, 1.0, -1.0, 0.123456, -32768.0, 32767.99998, 1234.5678] {
+        sensor::reading::write(&mut storage, value);
+        assert!((value - sensor::reading::read(&storage)).abs() <= 0.5 / 65536.0);
+    }
+}
@@ -0,0 +1,79 @@
+use binary_layout::prelude::*;
+
+mod common;
+use common::data_region;
+
+define_layout!(flags_layout, LittleEndian, {
+    first: u8,
+    packed: BitFlags<10>,
+    unpacked: [bool; 3],
+    last: u8,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(1, flags_layout::packed::OFFSET);
+    assert_eq!(Some(2), flags_layout::packed::SIZE);
+    assert_eq!(3, flags_layout::unpacked::OFFSET);
+    assert_eq!(Some(3), flags_layout::unpacked::SIZE);
+    assert_eq!(6, flags_layout::last::OFFSET);
+    assert_eq!(Some(7), flags_layout::SIZE);
+}
+
+#[test]
+fn fields() {
+    let mut storage = vec![0; 1024];
+
+    flags_layout::packed::set(&mut storage, 0, true);
+    flags_layout::packed::set(&mut storage, 8, true);
+    flags_layout::packed::set(&mut storage, 9, true);
+    flags_layout::unpacked::set(&mut storage, 1, true);
+    assert_eq!(&[0, 0b1, 0b11, 0, 1, 0, 0], &storage[..7]);
+
+    for index in 0..10 {
+        assert_eq!(
+            index == 0 || index == 8 || index == 9,
+            flags_layout::packed::get(&storage, index)
+        );
+    }
+    assert!(!flags_layout::unpacked::get(&storage, 0));
+    assert!(flags_layout::unpacked::get(&storage, 1));
+    assert!(!flags_layout::unpacked::get(&storage, 2));
+
+    flags_layout::packed::set(&mut storage, 8, false);
+    flags_layout::unpacked::set(&mut storage, 1, false);
+    assert_eq!(&[0, 0b1, 0b10, 0, 0, 0, 0], &storage[..7]);
+}
+
+#[test]
+fn view_readwrite() {
+    let mut storage = data_region(1024, 0);
+    let mut view = flags_layout::View::new(&mut storage);
+
+    for index in 0..10 {
+        view.packed_mut().set(index, index % 3 == 0);
+    }
+    view.unpacked_mut().set(2, true);
+
+    for index in 0..10 {
+        assert_eq!(index % 3 == 0, view.packed().get(index));
+    }
+    assert!(view.unpacked().get(2));
+
+    // Unused bits in the last byte of the packed flags are left untouched
+    let expected_byte_2 = (data_region(1024, 0)[2] & !0b11) | 0b10;
+    assert_eq!(&[0b0100_1001, expected_byte_2], &storage[1..3]);
+    assert_eq!(1, storage[5]);
+
+    // Other fields are left untouched
+    assert_eq!(data_region(1024, 0)[0], storage[0]);
+    assert_eq!(data_region(1024, 0)[6], storage[6]);
+}
+
+#[test]
+#[should_panic(expected = "Error: Flag index 10 is out of bounds for 10 flags")]
+fn out_of_bounds() {
+    let storage = vec![0; 1024];
+    let view = flags_layout::View::new(&storage);
+    view.packed().get(10);
+}
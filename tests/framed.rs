@@ -0,0 +1,78 @@
+#![cfg(feature = "std")]
+
+use binary_layout::prelude::*;
+use std::io::{Cursor, ErrorKind};
+
+define_layout!(message, BigEndian, {
+    id: u16,
+    payload: [u8],
+});
+
+define_layout!(fixed, LittleEndian, {
+    first: u8,
+    second: u32,
+});
+
+#[test]
+fn round_trip() {
+    let mut buffer = Vec::new();
+
+    let mut view = message::View::new(vec![0; 5]);
+    view.id_mut().write(7);
+    view.payload_mut().copy_from_slice(&[1, 2, 3]);
+    view.write_framed(&mut buffer).unwrap();
+
+    let mut view = message::View::new(vec![0; 2]);
+    view.id_mut().write(8);
+    view.write_framed(&mut buffer).unwrap();
+
+    assert_eq!(&[0, 0, 0, 5, 0, 7, 1, 2, 3, 0, 0, 0, 2, 0, 8], &*buffer);
+
+    let mut cursor = Cursor::new(buffer);
+    let view = message::View::read_framed(&mut cursor).unwrap();
+    assert_eq!(7, view.id().read());
+    assert_eq!(&[1, 2, 3], view.payload());
+    let view = message::View::read_framed(&mut cursor).unwrap();
+    assert_eq!(8, view.id().read());
+    assert!(view.payload().is_empty());
+    assert_eq!(
+        ErrorKind::UnexpectedEof,
+        message::View::read_framed(&mut cursor)
+            .err()
+            .unwrap()
+            .kind()
+    );
+}
+
+#[test]
+fn length_in_layout_endianness() {
+    let mut buffer = Vec::new();
+    let mut view = fixed::View::new([0; 5]);
+    view.second_mut().write(0x0102_0304);
+    view.write_framed(&mut buffer).unwrap();
+    assert_eq!(&[5, 0, 0, 0, 0, 4, 3, 2, 1], &*buffer);
+
+    let view = fixed::View::read_framed(Cursor::new(buffer)).unwrap();
+    assert_eq!(0x0102_0304, view.second().read());
+}
+
+#[test]
+fn wrong_size() {
+    let error = fixed::View::read_framed(Cursor::new([4, 0, 0, 0, 1, 2, 3, 4]))
+        .err()
+        .unwrap();
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    let error = message::View::read_framed(Cursor::new([0, 0, 0, 1, 1]))
+        .err()
+        .unwrap();
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+fn truncated_record() {
+    let error = fixed::View::read_framed(Cursor::new([5, 0, 0, 0, 1, 2]))
+        .err()
+        .unwrap();
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+}
@@ -0,0 +1,73 @@
+use binary_layout::prelude::*;
+
+mod common;
+use common::data_region;
+
+define_layout!(message, Endian, {
+    id: u16,
+    value: i32,
+    tail: [u8],
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, message::big_endian::id::OFFSET);
+    assert_eq!(2, message::little_endian::value::OFFSET);
+    assert_eq!(6, message::big_endian::tail::OFFSET);
+    assert_eq!(None, message::SIZE);
+    assert_eq!(6, message::MIN_SIZE);
+    assert_eq!(3, message::FIELD_COUNT);
+    assert_eq!(&["id", "value", "tail"], message::FIELD_NAMES);
+    assert_eq!(Some((2, Some(4))), message::field_info("value"));
+}
+
+#[test]
+fn field_endianness() {
+    assert_eq!(
+        [false, false, true, true],
+        [
+            message::big_endian::id::IS_LITTLE_ENDIAN,
+            message::big_endian::value::IS_LITTLE_ENDIAN,
+            message::little_endian::id::IS_LITTLE_ENDIAN,
+            message::little_endian::value::IS_LITTLE_ENDIAN,
+        ]
+    );
+
+    let storage = [0x01, 0x02];
+    let id = message::big_endian::id::read(&storage);
+    let raw = if message::big_endian::id::IS_LITTLE_ENDIAN {
+        u16::from_le_bytes(storage)
+    } else {
+        u16::from_be_bytes(storage)
+    };
+    assert_eq!(id, raw);
+}
+
+#[test]
+fn view() {
+    let mut big_storage = data_region(8, 0);
+    let mut view = message::View::<_, BigEndian>::new(&mut big_storage);
+    view.id_mut().write(0x0102);
+    view.value_mut().write(0x03040506);
+    view.tail_mut().copy_from_slice(&[7, 8]);
+    assert_eq!(0x0102, view.id().read());
+    assert_eq!(0x03040506, view.value().read());
+
+    let mut little_storage = data_region(8, 0);
+    let mut view = message::View::<_, LittleEndian>::new(&mut little_storage);
+    view.id_mut().write(0x0102);
+    view.value_mut().write(0x03040506);
+    view.tail_mut().copy_from_slice(&[7, 8]);
+    assert_eq!(0x0102, view.id().read());
+    assert_eq!(0x03040506, view.value().read());
+
+    assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], &*big_storage);
+    assert_eq!(&[2, 1, 6, 5, 4, 3, 7, 8], &*little_storage);
+}
+
+#[test]
+fn fields() {
+    let mut storage = data_region(8, 0);
+    message::big_endian::id::write(&mut storage, 0x0102);
+    assert_eq!(0x0201, message::little_endian::id::read(&storage));
+}
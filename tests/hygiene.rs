@@ -0,0 +1,115 @@
+use binary_layout::prelude::*;
+
+define_layout!(header, BigEndian, {
+    id: u32,
+    version: u8,
+});
+
+#[test]
+fn layouts_with_same_field_names_in_one_function() {
+    define_layout!(first, LittleEndian, {
+        id: u32 = sentinel(0xFFFF_FFFF),
+        version: u8 where |v| v <= 3,
+        payload: [u8],
+    });
+
+    define_layout!(second, BigEndian, {
+        id: u32 = sentinel(0),
+        version: u8 where |v| v >= 1,
+        payload: [u8],
+    });
+
+    define_layout!(third, LittleEndian, {
+        @include header,
+        flags: u8,
+    });
+
+    define_layout!(fourth, LittleEndian, {
+        @include header,
+        flags: u16,
+    });
+
+    let mut storage = [0; 6];
+    let mut view = first::View::new(&mut storage[..]);
+    view.id_mut().write(Some(0x0102_0304));
+    view.version_mut().write(3);
+    assert_eq!(&[0x04, 0x03, 0x02, 0x01, 3, 0], &storage);
+
+    let mut storage = [0; 6];
+    let mut view = second::View::new(&mut storage[..]);
+    view.id_mut().write(Some(0x0102_0304));
+    view.version_mut().write(1);
+    assert_eq!(&[0x01, 0x02, 0x03, 0x04, 1, 0], &storage);
+
+    assert_eq!(Some(0x0102_0304), second::id::read(&storage));
+    assert_eq!(Some(0x0403_0201), first::id::read(&storage));
+
+    assert_eq!(5, third::flags::OFFSET);
+    assert_eq!(5, fourth::flags::OFFSET);
+    assert_eq!(Some(6), third::SIZE);
+    assert_eq!(Some(7), fourth::SIZE);
+    assert_eq!(first::FIELD_NAMES, second::FIELD_NAMES);
+}
+
+#[test]
+fn layouts_with_same_field_names_in_nested_blocks() {
+    {
+        define_layout!(record, LittleEndian, {
+            value: u16,
+        });
+        assert_eq!(Some(2), record::SIZE);
+    }
+    {
+        define_layout!(record, LittleEndian, {
+            value: u64,
+        });
+        assert_eq!(Some(8), record::SIZE);
+    }
+}
+
+define_layout!(helper_names, LittleEndian, {
+    len: u16,
+    is_empty: u8,
+    as_ptr: u8,
+    as_mut_ptr: u8,
+    hexdump: u8,
+    tail_offset: u8,
+    cursor: u8,
+    checked: u8,
+    tail: [u8],
+});
+
+#[test]
+fn fields_named_like_view_helpers() {
+    let mut storage = [0; 10];
+    let mut view = helper_names::View::new(&mut storage[..]);
+    view.len_mut().write(300);
+    view.is_empty_mut().write(1);
+    view.as_ptr_mut().write(2);
+    view.as_mut_ptr_mut().write(3);
+    view.hexdump_mut().write(4);
+    view.tail_offset_mut().write(5);
+    view.cursor_mut().write(6);
+    view.checked_mut().write(7);
+    assert_eq!(300, view.len().read());
+    assert_eq!(1, view.is_empty().read());
+    assert_eq!(2, view.as_ptr().read());
+    assert_eq!(3, view.as_mut_ptr().read());
+    assert_eq!(4, view.hexdump().read());
+    assert_eq!(5, view.tail_offset().read());
+    assert_eq!(6, view.cursor().read());
+    assert_eq!(7, view.checked().read());
+
+    assert_eq!(10, LayoutView::len(&view));
+    assert_eq!(9, LayoutView::tail_offset(&view));
+    assert!(!LayoutView::is_empty(&view));
+    let ptr = LayoutViewMut::as_mut_ptr(&mut view);
+    assert_eq!(storage.as_mut_ptr(), ptr);
+
+    let mut cursor = helper_names::cursor(&mut storage[..]);
+    cursor.write_next::<helper_names::len>(1);
+    assert_eq!(
+        Some("is_empty"),
+        cursor.next_field().map(|field| field.name)
+    );
+}
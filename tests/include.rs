@@ -0,0 +1,112 @@
+use binary_layout::prelude::*;
+
+mod common;
+use common::data_region;
+
+define_layout!(common_header, BigEndian, {
+    version: u16,
+    flags: u8,
+    length: u32,
+});
+
+define_layout!(first_message, BigEndian, {
+    @include common_header,
+    body: u32,
+});
+
+define_layout!(second_message, LittleEndian, {
+    @include common_header,
+    id: u16,
+    payload: [u8],
+});
+
+mod headers {
+    use binary_layout::prelude::*;
+
+    define_layout!(pub(crate) short_header, LittleEndian, {
+        magic: [u8; 2],
+    });
+}
+
+define_layout!(third_message, LittleEndian, {
+    @include headers::short_header,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, first_message::version::OFFSET);
+    assert_eq!(2, first_message::flags::OFFSET);
+    assert_eq!(3, first_message::length::OFFSET);
+    assert_eq!(7, first_message::body::OFFSET);
+    assert_eq!(Some(11), first_message::SIZE);
+    assert_eq!(
+        &["version", "flags", "length", "body"],
+        first_message::FIELD_NAMES
+    );
+
+    assert_eq!(0, second_message::version::OFFSET);
+    assert_eq!(2, second_message::flags::OFFSET);
+    assert_eq!(3, second_message::length::OFFSET);
+    assert_eq!(7, second_message::id::OFFSET);
+    assert_eq!(9, second_message::payload::OFFSET);
+    assert_eq!(None, second_message::SIZE);
+
+    assert_eq!(0, third_message::magic::OFFSET);
+    assert_eq!(Some(2), third_message::SIZE);
+}
+
+#[test]
+fn fields() {
+    let mut storage = data_region(1024, 0);
+
+    first_message::version::write(&mut storage, 3);
+    first_message::length::write(&mut storage, 1000);
+    first_message::body::write(&mut storage, 5);
+
+    assert_eq!(3, common_header::version::read(&storage));
+    assert_eq!(1000, common_header::length::read(&storage));
+    assert_eq!(&[0, 3], &storage[0..2]);
+    assert_eq!(5, first_message::body::read(&storage));
+}
+
+#[test]
+fn view() {
+    let mut storage = data_region(1024, 0);
+    let mut view = second_message::View::new(&mut storage);
+    view.version_mut().write(3);
+    view.flags_mut().write(1);
+    view.length_mut().write(2);
+    view.id_mut().write(10);
+    view.payload_mut()[..2].copy_from_slice(&[4, 5]);
+
+    assert_eq!(3, view.version().read());
+    assert_eq!(1, view.flags().read());
+    assert_eq!(2, view.length().read());
+    assert_eq!(10, view.id().read());
+
+    // The included fields keep the endianness of the included layout
+    assert_eq!(&[0, 3, 1, 0, 0, 0, 2, 10, 0, 4, 5], &storage[..11]);
+
+    let view = common_header::View::new(&storage);
+    assert_eq!(3, view.version().read());
+
+    let mut storage = [0; 2];
+    let mut view = third_message::View::new(&mut storage);
+    view.magic_mut().copy_from_slice(b"ab");
+    assert_eq!(b"ab", view.magic());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_struct() {
+    let view = first_message::View::new(vec![0, 1, 2, 0, 0, 0, 3, 0, 0, 0, 4]);
+    let owned = view.to_owned_struct();
+    assert_eq!(1, owned.version);
+    assert_eq!(2, owned.flags);
+    assert_eq!(3, owned.length);
+    assert_eq!(4, owned.body);
+    assert_eq!(
+        &[0, 1, 2, 0, 0, 0, 3, 0, 0, 0, 4],
+        &*owned.to_view().into_storage()
+    );
+}
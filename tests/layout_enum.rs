@@ -0,0 +1,79 @@
+use binary_layout::prelude::*;
+use binary_layout::{define_layout_enum, LayoutError};
+
+define_layout!(header, LittleEndian, {
+    version: u8,
+    msg_type: u16,
+});
+
+define_layout!(request, LittleEndian, {
+    version: u8,
+    msg_type: u16,
+    id: u32,
+});
+
+define_layout!(response, LittleEndian, {
+    version: u8,
+    msg_type: u16,
+    id: u32,
+    status: u8,
+    body: [u8],
+});
+
+define_layout_enum!(message, header::msg_type, {
+    0x10 => Request(request),
+    0x20 => Response(response),
+});
+
+#[test]
+fn dispatch() {
+    let storage = [1, 0x10, 0, 7, 0, 0, 0];
+    match message::parse(&storage[..]).unwrap() {
+        message::View::Request(view) => assert_eq!(7, view.id().read()),
+        message::View::Response(_) => panic!("Expected a request"),
+    }
+
+    let storage = vec![1, 0x20, 0, 7, 0, 0, 0, 200, 1, 2];
+    match message::parse(storage).unwrap() {
+        message::View::Response(view) => {
+            assert_eq!(7, view.id().read());
+            assert_eq!(200, view.status().read());
+            assert_eq!(&[1, 2], view.body());
+        }
+        message::View::Request(_) => panic!("Expected a response"),
+    }
+}
+
+#[test]
+fn write_through_variant() {
+    let mut storage = [1, 0x10, 0, 0, 0, 0, 0];
+    if let message::View::Request(mut view) = message::parse(&mut storage[..]).unwrap() {
+        view.id_mut().write(0x01020304);
+    }
+    assert_eq!([1, 0x10, 0, 4, 3, 2, 1], storage);
+
+    let view = message::parse(&storage[..]).unwrap();
+    assert_eq!(&storage[..], view.into_storage());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Some(LayoutError::UnknownTag(0x30)),
+        message::parse(&[1, 0x30, 0, 7, 0, 0, 0][..]).err()
+    );
+    assert_eq!(
+        Some(LayoutError::BufferTooSmall {
+            required: 3,
+            actual: 2
+        }),
+        message::parse(&[1, 0x10][..]).err()
+    );
+    assert_eq!(
+        Some(LayoutError::BufferTooSmall {
+            required: 8,
+            actual: 7
+        }),
+        message::parse(&[1, 0x20, 0, 7, 0, 0, 0][..]).err()
+    );
+}
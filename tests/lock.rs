@@ -0,0 +1,85 @@
+use binary_layout::prelude::*;
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+define_layout!(lock_layout, LittleEndian, {
+    first: u8,
+    second: u32,
+    tail: [u8],
+});
+
+#[test]
+fn mutex_guard_vec() {
+    let storage = Mutex::new(vec![0; 8]);
+    {
+        let mut guard = storage.lock().unwrap();
+        let mut view = lock_layout::View::new(&mut *guard);
+        view.first_mut().write(5);
+        view.second_mut().write(0x0102_0304);
+        view.tail_mut().copy_from_slice(&[7, 8, 9]);
+    }
+
+    let guard = storage.lock().unwrap();
+    let view = lock_layout::View::new(&*guard);
+    assert_eq!(5, view.first().read());
+    assert_eq!(0x0102_0304, view.second().read());
+    assert_eq!(&[7, 8, 9], view.tail());
+    assert_eq!(vec![5, 4, 3, 2, 1, 7, 8, 9], *guard);
+}
+
+#[test]
+fn mutex_guard_slice() {
+    let mut data = vec![0; 8];
+    {
+        let storage: Mutex<&mut [u8]> = Mutex::new(&mut data);
+        let mut guard = storage.lock().unwrap();
+        let mut view = lock_layout::View::new(&mut **guard);
+        view.second_mut().write(10);
+        assert_eq!(10, view.second().read());
+    }
+    assert_eq!([0, 10, 0, 0, 0], data[..5]);
+}
+
+#[test]
+fn mutex_shared_between_threads() {
+    let storage = Arc::new(Mutex::new(vec![0; 8]));
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let storage = Arc::clone(&storage);
+            thread::spawn(move || {
+                let mut guard = storage.lock().unwrap();
+                let mut view = lock_layout::View::new(&mut guard[..]);
+                view.second_mut().update(|value| value + 1);
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let guard = storage.lock().unwrap();
+    assert_eq!(4, lock_layout::View::new(&guard[..]).second().read());
+}
+
+#[test]
+fn rwlock_guard() {
+    let storage = RwLock::new(vec![0; 8]);
+    lock_layout::View::new(&mut *storage.write().unwrap())
+        .first_mut()
+        .write(3);
+
+    let guard = storage.read().unwrap();
+    assert_eq!(3, lock_layout::View::new(&*guard).first().read());
+}
+
+#[test]
+fn refcell_guard() {
+    let storage = RefCell::new(vec![0; 8]);
+    lock_layout::View::new(&mut *storage.borrow_mut())
+        .second_mut()
+        .write(42);
+
+    let guard = storage.borrow();
+    assert_eq!(42, lock_layout::View::new(&*guard).second().read());
+}
@@ -0,0 +1,80 @@
+use binary_layout::prelude::*;
+use binary_layout::{migrate, LayoutView};
+
+define_layout!(record_v1, BigEndian, {
+    id: u32,
+    version: u8,
+    name: [u8; 4],
+    checksum: u16,
+});
+
+define_layout!(record_v2, BigEndian, {
+    version: u8,
+    id: u32,
+    flags: u16,
+    name: [u8; 4],
+    checksum: u32,
+    tail: [u8],
+});
+
+define_layout!(record_v1_le, LittleEndian, {
+    id: u32,
+    version: u8,
+});
+
+#[test]
+fn migrate_shared_fields() {
+    let mut src = record_v1::View::new(vec![0; 11]);
+    src.id_mut().write(0x0102_0304);
+    src.version_mut().write(1);
+    src.name_mut().copy_from_slice(b"abcd");
+    src.checksum_mut().write(0xFFFF);
+
+    let mut dst = record_v2::View::new(vec![0; 18]);
+    dst.flags_mut().write(5);
+    assert_eq!(3, migrate(&src, &mut dst));
+
+    assert_eq!(1, dst.version().read());
+    assert_eq!(0x0102_0304, dst.id().read());
+    assert_eq!(5, dst.flags().read());
+    assert_eq!(b"abcd", dst.name());
+    // The type of the checksum changed, so it isn't migrated
+    assert_eq!(0, dst.checksum().read());
+    assert_eq!(&[0, 0, 0], dst.tail());
+}
+
+#[test]
+fn migrate_back() {
+    let mut src = record_v2::View::new(vec![0; 15]);
+    src.id_mut().write(42);
+    src.version_mut().write(2);
+    src.checksum_mut().write(0xFFFF_FFFF);
+
+    let mut storage = vec![0; 11];
+    assert_eq!(3, migrate(&src, &mut record_v1::View::new(&mut storage)));
+    let dst = record_v1::View::new(&storage);
+    assert_eq!(42, dst.id().read());
+    assert_eq!(2, dst.version().read());
+    assert_eq!(0, dst.checksum().read());
+}
+
+#[test]
+fn different_endianness_is_not_migrated() {
+    let mut src = record_v1::View::new(vec![0; 11]);
+    src.id_mut().write(7);
+    src.version_mut().write(1);
+
+    let mut dst = record_v1_le::View::new(vec![0; 5]);
+    assert_eq!(0, migrate(&src, &mut dst));
+    assert_eq!(0, dst.id().read());
+}
+
+#[test]
+fn layout_view_metadata() {
+    assert_eq!(
+        record_v1::FIELDS,
+        <record_v1::View<&[u8]> as LayoutView>::FIELDS
+    );
+    let storage = [1; 11];
+    assert_eq!(&storage, record_v1::View::new(&storage).bytes());
+}
@@ -0,0 +1,32 @@
+use binary_layout::prelude::*;
+
+define_layout!(pdp, BigEndian, {
+    magic: u16,
+    block: MixedEndian<u32>,
+    delta: MixedEndian<i32>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(2, pdp::block::OFFSET);
+    assert_eq!(Some(4), pdp::block::SIZE);
+    assert_eq!(6, pdp::delta::OFFSET);
+    assert_eq!(Some(10), pdp::SIZE);
+}
+
+#[test]
+fn known_byte_pattern() {
+    // 0x0A0B0C0D in PDP-11 byte order
+    let mut storage = vec![0, 0, 0x0B, 0x0A, 0x0D, 0x0C, 0xFF, 0xFF, 0xFE, 0xFF];
+    let mut view = pdp::View::new(&mut storage);
+    assert_eq!(0x0A0B_0C0D, view.block().read());
+    assert_eq!(-2, view.delta().read());
+
+    view.magic_mut().write(0x0102);
+    view.block_mut().write(0x1122_3344);
+    view.delta_mut().write(i32::MIN);
+    assert_eq!(
+        &[0x01, 0x02, 0x22, 0x11, 0x44, 0x33, 0x00, 0x80, 0x00, 0x00],
+        &storage[..]
+    );
+}
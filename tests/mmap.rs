@@ -0,0 +1,81 @@
+#![cfg(feature = "memmap2")]
+
+use binary_layout::prelude::*;
+use binary_layout::LayoutError;
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+define_layout!(entry, LittleEndian, {
+    id: u32,
+    value: i16,
+});
+
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, content: &[u8]) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "binary_layout_test_{}_{}",
+            std::process::id(),
+            name
+        ));
+        File::create(&path).unwrap().write_all(content).unwrap();
+        Self(path)
+    }
+
+    fn map(&self) -> Mmap {
+        let file = File::open(&self.0).unwrap();
+        unsafe { Mmap::map(&file) }.unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn from_mmap() {
+    let file = TempFile::new("from_mmap", &[1, 0, 0, 0, 0xFE, 0xFF]);
+
+    let view = entry::View::from_mmap(file.map()).unwrap();
+    assert_eq!(1, view.id().read());
+    assert_eq!(-2, view.value().read());
+}
+
+#[test]
+fn from_mmap_too_small() {
+    let file = TempFile::new("from_mmap_too_small", &[1, 0, 0, 0]);
+
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 6,
+            actual: 4
+        }),
+        entry::View::from_mmap(file.map()).map(|_| ())
+    );
+}
+
+#[test]
+fn records() {
+    let mut content = Vec::new();
+    for id in 0..100u32 {
+        content.extend_from_slice(&id.to_le_bytes());
+        content.extend_from_slice(&(-(id as i16)).to_le_bytes());
+    }
+    // Trailing partial record
+    content.extend_from_slice(&[1, 2, 3]);
+    let file = TempFile::new("records", &content);
+    let mmap = file.map();
+
+    let mut count = 0;
+    for (index, record) in entry::records(&mmap).enumerate() {
+        assert_eq!(index as u32, record.id().read());
+        assert_eq!(-(index as i16), record.value().read());
+        count += 1;
+    }
+    assert_eq!(100, count);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::borrow_deref_ref)]
+
 use binary_layout::prelude::*;
 use std::convert::TryInto;
 
@@ -189,6 +191,27 @@ fn view_readwrite() {
     assert_eq!(&data_region(1024, 6)[36..], &storage[36..]);
 }
 
+#[test]
+fn nested_view_mut_writes_at_absolute_offset() {
+    let mut storage = data_region(1024, 5);
+    let mut view = whole::View::new(&mut storage[..]);
+
+    let mut mid: middle::View<&mut [u8]> = view.mid_mut();
+    assert_eq!(4, mid.storage.len());
+    mid.field1_mut().write(0xABCD);
+    let mut deep: deep_nesting::View<&mut [u8]> = mid.deep_mut();
+    deep.field1_mut().write(0x1234);
+
+    let mut foot = view.foot_mut();
+    foot.deep_mut().field1_mut().write(0x5678);
+
+    let mut expected = data_region(1024, 5);
+    expected[10..12].copy_from_slice(&0x1234u16.to_le_bytes());
+    expected[12..14].copy_from_slice(&0xABCDu16.to_be_bytes());
+    expected[34..36].copy_from_slice(&0x5678u16.to_le_bytes());
+    assert_eq!(expected, storage);
+}
+
 #[test]
 fn view_vec_readonly() {
     let view = whole::View::new(data_region(1024, 5));
@@ -333,3 +356,41 @@ fn view_vec_readwrite() {
     let extracted_storage_2 = view.into_foot().into_storage();
     assert_eq!(&&extracted_storage[30..], &extracted_storage_2.as_ref());
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn to_owned_struct() {
+    let storage = data_region(1024, 5);
+    let view = whole::View::new(&storage);
+
+    let owned: whole::Owned = view.to_owned_struct();
+    let _: &header::Owned = &owned.head;
+    assert_eq!(view.head().field1().read(), owned.head.field1);
+    assert_eq!(view.field1().read(), owned.field1);
+    assert_eq!(view.mid().deep().field1().read(), owned.mid.deep.field1);
+    assert_eq!(view.mid().field1().read(), owned.mid.field1);
+    assert_eq!(view.field2().read(), owned.field2);
+    assert_eq!(view.foot().field1().read(), owned.foot.field1);
+    assert_eq!(view.foot().deep().field1().read(), owned.foot.deep.field1);
+    assert_eq!(view.foot().tail(), &owned.foot.tail[..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_struct_roundtrip() {
+    let storage = data_region(1024, 5);
+    let owned = whole::View::new(&storage).to_owned_struct();
+
+    let view: whole::View<Vec<u8>> = owned.to_view();
+    assert_eq!(&storage, &view.storage);
+
+    let roundtripped = view.to_owned_struct();
+    assert_eq!(owned.head.field1, roundtripped.head.field1);
+    assert_eq!(owned.field1, roundtripped.field1);
+    assert_eq!(owned.mid.deep.field1, roundtripped.mid.deep.field1);
+    assert_eq!(owned.mid.field1, roundtripped.mid.field1);
+    assert_eq!(owned.field2, roundtripped.field2);
+    assert_eq!(owned.foot.field1, roundtripped.foot.field1);
+    assert_eq!(owned.foot.deep.field1, roundtripped.foot.deep.field1);
+    assert_eq!(owned.foot.tail, roundtripped.foot.tail);
+}
@@ -1,4 +1,5 @@
 use binary_layout::prelude::*;
+use binary_layout::{absolute_offset, FieldTuple, LayoutError};
 use std::convert::TryInto;
 
 mod common;
@@ -18,6 +19,7 @@ fn metadata() {
     assert_eq!(Some(8), noslice::second::SIZE);
     assert_eq!(9, noslice::third::OFFSET);
     assert_eq!(Some(2), noslice::third::SIZE);
+    assert_eq!(11, noslice::MIN_SIZE);
 }
 
 #[test]
@@ -49,6 +51,13 @@ fn fields() {
     assert_eq!(1_000, noslice::third::read(&storage));
 }
 
+#[test]
+fn tail_offset() {
+    let storage = data_region(1024, 5);
+    let view = noslice::View::new(&storage);
+    assert_eq!(noslice::SIZE.unwrap(), view.tail_offset());
+}
+
 #[test]
 fn view_readonly() {
     let storage = data_region(1024, 5);
@@ -184,3 +193,282 @@ fn view_vec_readwrite() {
         u16::from_le_bytes((&extracted_storage[9..11]).try_into().unwrap())
     );
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn to_owned_struct() {
+    let mut storage = data_region(1024, 5);
+    let mut view = noslice::View::new(&mut storage);
+    view.first_mut().write(50);
+    view.second_mut().write(10i64.pow(15));
+    view.third_mut().write(1000);
+
+    let owned: noslice::Owned = view.to_owned_struct();
+    assert_eq!(50, owned.first);
+    assert_eq!(10i64.pow(15), owned.second);
+    assert_eq!(1000, owned.third);
+
+    // Test the owned struct is detached from the storage
+    view.first_mut().write(60);
+    assert_eq!(50, owned.first);
+    assert_eq!(50, owned.clone().first);
+    assert_eq!(
+        "Owned { first: 50, second: 1000000000000000, third: 1000 }",
+        format!("{:?}", owned)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_struct_roundtrip() {
+    let owned = noslice::Owned {
+        first: 50,
+        second: 10i64.pow(15),
+        third: 1000,
+    };
+
+    let view: noslice::View<Vec<u8>> = owned.to_view();
+    assert_eq!(11, view.storage.len());
+    assert_eq!(50, view.first().read());
+    assert_eq!(10i64.pow(15), view.second().read());
+    assert_eq!(1000, view.third().read());
+
+    let roundtripped = view.to_owned_struct();
+    assert_eq!(owned.first, roundtripped.first);
+    assert_eq!(owned.second, roundtripped.second);
+    assert_eq!(owned.third, roundtripped.third);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_from_struct() {
+    let mut storage = data_region(1024, 5);
+    let mut view = noslice::View::new(&mut storage);
+    view.write_from_struct(&noslice::Owned {
+        first: 50,
+        second: 10i64.pow(15),
+        third: 1000,
+    });
+
+    assert_eq!(50, i8::from_le_bytes((&storage[0..1]).try_into().unwrap()));
+    assert_eq!(
+        10i64.pow(15),
+        i64::from_le_bytes((&storage[1..9]).try_into().unwrap())
+    );
+    assert_eq!(
+        1000,
+        u16::from_le_bytes((&storage[9..11]).try_into().unwrap())
+    );
+    // Test the rest of the storage is untouched
+    assert_eq!(&data_region(1024, 5)[11..], &storage[11..]);
+}
+
+#[test]
+fn peek() {
+    let storage = data_region(1024, 5);
+
+    // The buffer is truncated but still large enough for the peeked field
+    assert_eq!(
+        noslice::first::read(&storage),
+        noslice::peek_first(&storage[..1]).unwrap()
+    );
+    assert_eq!(
+        noslice::second::read(&storage),
+        noslice::peek_second(&storage[..9]).unwrap()
+    );
+
+    // The buffer is too small for the peeked field
+    assert_eq!(
+        Err(binary_layout::LayoutError::BufferTooSmall {
+            required: 11,
+            actual: 10
+        }),
+        noslice::peek_third(&storage[..10])
+    );
+    assert!(noslice::peek_first(&[]).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hexdump() {
+    let storage: Vec<u8> = (0x40..0x50).collect();
+    let view = noslice::View::new(&storage);
+    assert_eq!(
+        "00000000: 4041 4243 4445 4647 4849 4a4b 4c4d 4e4f  @ABCDEFGHIJKLMNO\n",
+        view.hexdump()
+    );
+}
+
+#[test]
+fn new_at() {
+    let mut storage = data_region(1024, 5);
+    let mut view = noslice::View::new_at(&mut storage, 100);
+    view.second_mut().write(-1000);
+    view.third_mut().write(2000);
+    assert_eq!(-1000, view.second().read());
+    assert_eq!(101, view.absolute_offset::<noslice::second>());
+    assert_eq!(109, view.absolute_offset::<noslice::third>());
+
+    assert_eq!(
+        -1000,
+        i64::from_le_bytes(storage[101..109].try_into().unwrap())
+    );
+    assert_eq!(
+        2000,
+        u16::from_le_bytes(storage[109..111].try_into().unwrap())
+    );
+    assert_eq!(
+        noslice::first::read(&storage[100..]),
+        noslice::View::new_at(&storage, 100).first().read()
+    );
+}
+
+#[test]
+fn absolute_offset_in_const_context() {
+    const BASE: usize = 2 * noslice::MIN_SIZE;
+    const THIRD: usize = absolute_offset::<noslice::third>(BASE);
+    const BUFFER: [u8; BASE + noslice::MIN_SIZE] = [0; BASE + noslice::MIN_SIZE];
+
+    assert_eq!(31, THIRD);
+    assert_eq!(33, BUFFER.len());
+
+    let mut storage = BUFFER;
+    noslice::View::new_at(&mut storage[..], BASE)
+        .third_mut()
+        .write(5);
+    assert_eq!([5, 0], storage[THIRD..]);
+    assert_eq!(
+        THIRD,
+        noslice::View::new_at(&storage[..], BASE).absolute_offset::<noslice::third>()
+    );
+}
+
+#[test]
+fn swap() {
+    let mut storage = data_region(1024, 5);
+    let mut view = noslice::View::new(&mut storage);
+    view.third_mut().write(1000);
+
+    assert_eq!(1000, view.third_mut().swap(2000));
+    assert_eq!(2000, view.third().read());
+    assert_eq!(2000, noslice::third::swap(&mut storage, 3000));
+    assert_eq!(3000, noslice::third::read(&storage));
+    assert_eq!(3000, u16::from_le_bytes(storage[9..11].try_into().unwrap()));
+}
+
+#[test]
+fn update() {
+    let mut storage = data_region(1024, 5);
+    let mut view = noslice::View::new(&mut storage);
+    view.third_mut().write(0);
+
+    for _ in 0..5 {
+        view.third_mut().update(|counter| counter + 1);
+    }
+    assert_eq!(5, view.third().read());
+    noslice::third::update(&mut storage, |counter| counter * 100);
+    assert_eq!(500, noslice::third::read(&storage));
+    assert_eq!(500, u16::from_le_bytes(storage[9..11].try_into().unwrap()));
+}
+
+#[test]
+fn new_exact() {
+    let storage = data_region(11, 5);
+    let view = noslice::View::new_exact(&storage[..]).unwrap();
+    assert_eq!(noslice::third::read(&storage), view.third().read());
+
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 11,
+            actual: 10
+        }),
+        noslice::View::new_exact(&storage[..10]).map(|_| ())
+    );
+
+    let storage = data_region(12, 5);
+    assert_eq!(
+        Err(LayoutError::BufferTooLarge {
+            expected: 11,
+            actual: 12
+        }),
+        noslice::View::new_exact(&storage[..]).map(|_| ())
+    );
+}
+
+#[test]
+fn read_as_sign_extends() {
+    let mut storage = data_region(1024, 5);
+    let mut view = noslice::View::new(&mut storage);
+    view.first_mut().write(-3);
+    assert_eq!(-3i32, view.first().read_as::<i32>());
+    assert_eq!(-3i64, noslice::first::read_as::<i64>(&storage));
+    assert_eq!(0xFD, noslice::first::read(&storage) as u8);
+}
+
+#[test]
+fn from_array() {
+    let mut view = noslice::View::from_array([0; 11]);
+    view.second_mut().write(-5);
+    view.third_mut().write(1_000);
+    let storage: [u8; 11] = view.into_storage();
+    assert_eq!(-5, noslice::second::read(&storage));
+    assert_eq!(1_000, noslice::third::read(&storage));
+}
+
+#[test]
+fn read_fields() {
+    let storage = data_region(1024, 5);
+    let view = noslice::View::new(&storage);
+
+    let (third, first) = view.read_fields::<(noslice::third, noslice::first)>();
+    assert_eq!(view.third().read(), third);
+    assert_eq!(view.first().read(), first);
+
+    assert_eq!(
+        (
+            noslice::first::read(&storage),
+            noslice::second::read(&storage),
+            noslice::third::read(&storage)
+        ),
+        <(noslice::first, noslice::second, noslice::third)>::read_fields(&storage)
+    );
+}
+
+#[test]
+fn validated_view() {
+    let mut storage = data_region(1024, 5);
+    let mut view = noslice::ValidatedView::try_new_mut(&mut storage[..]).unwrap();
+    view.view_mut().second_mut().write(-50);
+    assert_eq!(-50, view.second().read());
+    assert_eq!(11, view.storage.len());
+    assert_eq!(-50, noslice::second::read(&storage));
+
+    let storage = data_region(11, 5);
+    let view = noslice::ValidatedView::try_new(&storage[..]).unwrap();
+    assert_eq!(noslice::third::read(&storage), view.third().read());
+    assert_eq!(
+        noslice::first::read(&storage),
+        view.into_view().first().read()
+    );
+
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 11,
+            actual: 10
+        }),
+        noslice::ValidatedView::try_new(&storage[..10]).map(|_| ())
+    );
+}
+
+#[test]
+fn records() {
+    let storage = data_region(1024, 5);
+    let records: Vec<_> = noslice::records(&storage).collect();
+    assert_eq!(1024 / 11, records.len());
+    for (index, record) in records.iter().enumerate() {
+        assert_eq!(
+            noslice::second::read(&storage[index * 11..]),
+            record.second().read()
+        );
+    }
+}
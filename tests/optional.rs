@@ -0,0 +1,87 @@
+use binary_layout::prelude::*;
+use std::convert::TryInto;
+
+define_layout!(optional, BigEndian, optional(presence: u8) {
+    first: u16,
+    second: i32,
+});
+
+#[test]
+fn size() {
+    assert_eq!(1, optional::size(0b00));
+    assert_eq!(3, optional::size(0b01));
+    assert_eq!(5, optional::size(0b10));
+    assert_eq!(7, optional::size(0b11));
+    assert_eq!(1, optional::MIN_SIZE);
+}
+
+#[test]
+fn none_present() {
+    let storage = vec![0b00];
+    let view = optional::View::new(&storage);
+
+    assert_eq!(0b00, view.presence().read());
+    assert!(view.first().is_none());
+    assert!(view.second().is_none());
+}
+
+#[test]
+fn only_first_present() {
+    let mut storage = vec![0; optional::size(0b01)];
+    let mut view = optional::View::new(&mut storage);
+    view.presence_mut().write(0b01);
+    view.first_mut().unwrap().write(1000);
+    assert!(view.second_mut().is_none());
+
+    assert_eq!(1000, view.first().unwrap().read());
+    assert!(view.second().is_none());
+    assert_eq!(
+        1000,
+        u16::from_be_bytes((&storage[1..3]).try_into().unwrap())
+    );
+}
+
+#[test]
+fn only_second_present() {
+    let mut storage = vec![0; optional::size(0b10)];
+    let mut view = optional::View::new(&mut storage);
+    view.presence_mut().write(0b10);
+    view.second_mut().unwrap().write(-50_000);
+    assert!(view.first_mut().is_none());
+
+    assert!(view.first().is_none());
+    assert_eq!(-50_000, view.second().unwrap().read());
+    assert_eq!(
+        -50_000,
+        i32::from_be_bytes((&storage[1..5]).try_into().unwrap())
+    );
+}
+
+#[test]
+fn both_present() {
+    let mut storage = vec![0; optional::size(0b11)];
+    let mut view = optional::View::new(&mut storage);
+    view.presence_mut().write(0b11);
+    view.first_mut().unwrap().write(1000);
+    view.second_mut().unwrap().write(-50_000);
+
+    assert_eq!(1000, view.first().unwrap().read());
+    assert_eq!(-50_000, view.second().unwrap().read());
+    assert_eq!(
+        1000,
+        u16::from_be_bytes((&storage[1..3]).try_into().unwrap())
+    );
+    assert_eq!(
+        -50_000,
+        i32::from_be_bytes((&storage[3..7]).try_into().unwrap())
+    );
+}
+
+#[test]
+fn unknown_bits_are_ignored() {
+    let storage = vec![0b1111_1110, 0, 0, 0, 10];
+    let view = optional::View::new(&storage);
+
+    assert!(view.first().is_none());
+    assert_eq!(10, view.second().unwrap().read());
+}
@@ -0,0 +1,49 @@
+use binary_layout::prelude::*;
+use binary_layout::{assert_layout_prefix_compatible, FieldInfo};
+
+define_layout!(record_v1, BigEndian, {
+    version: u8,
+    length: u32,
+    payload: [u8],
+});
+
+define_layout!(record_v2, BigEndian, {
+    version: u8,
+    length: u32,
+    payload: [u8],
+});
+
+mod legacy {
+    use binary_layout::prelude::*;
+
+    define_layout!(pub record_v0, LittleEndian, {
+        version: u8,
+    });
+}
+
+assert_layout_prefix_compatible!(record_v1, record_v2);
+assert_layout_prefix_compatible!(legacy::record_v0, record_v1);
+
+#[test]
+fn fields() {
+    assert_eq!(
+        &[
+            FieldInfo {
+                name: "version",
+                offset: 0,
+                size: Some(1),
+            },
+            FieldInfo {
+                name: "length",
+                offset: 1,
+                size: Some(4),
+            },
+            FieldInfo {
+                name: "payload",
+                offset: 5,
+                size: None,
+            },
+        ],
+        record_v2::FIELDS
+    );
+}
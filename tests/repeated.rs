@@ -0,0 +1,204 @@
+use binary_layout::prelude::*;
+
+define_layout!(entry, BigEndian, {
+    id: u16,
+    value: i32,
+});
+
+define_layout!(fixed_table, BigEndian, {
+    version: u8,
+    entries: [entry::NestedView; 3],
+    checksum: u16,
+});
+
+define_layout!(table, BigEndian, {
+    count: u16,
+    entries: RepeatedLayout<entry::NestedView, count>,
+});
+
+define_layout!(log, BigEndian, {
+    version: u8,
+    entries: [entry::NestedView],
+});
+
+fn table_storage() -> Vec<u8> {
+    vec![
+        0, 3, // count
+        0, 1, 0, 0, 0, 10, // entry 0
+        0, 2, 255, 255, 255, 236, // entry 1
+        0, 3, 0, 0, 1, 0, // entry 2
+        0xAB, 0xCD, // trailing bytes
+    ]
+}
+
+#[test]
+fn metadata() {
+    assert_eq!(1, fixed_table::entries::OFFSET);
+    assert_eq!(Some(18), fixed_table::entries::SIZE);
+    assert_eq!(19, fixed_table::checksum::OFFSET);
+    assert_eq!(Some(21), fixed_table::SIZE);
+
+    assert_eq!(2, table::entries::OFFSET);
+    assert_eq!(None, table::entries::SIZE);
+    assert_eq!(None, table::SIZE);
+
+    assert_eq!(1, log::entries::OFFSET);
+    assert_eq!(None, log::entries::SIZE);
+    assert_eq!(1, log::MIN_SIZE);
+}
+
+#[test]
+fn iterate_repeated() {
+    let storage = table_storage();
+    let view = table::View::new(&storage[..]);
+    let entries = view.entries();
+
+    assert_eq!(3, entries.len());
+    let values: Vec<(u16, i32)> = entries
+        .iter()
+        .map(|entry| (entry.id().read(), entry.value().read()))
+        .collect();
+    assert_eq!(vec![(1, 10), (2, -20), (3, 256)], values);
+}
+
+#[test]
+fn index_repeated() {
+    let mut storage = table_storage();
+    let mut view = table::View::new(&mut storage);
+
+    assert_eq!(2, view.entries().get(1).unwrap().id().read());
+    assert!(view.entries().get(3).is_none());
+
+    view.entries_mut().get_mut(2).unwrap().value_mut().write(-1);
+    assert!(view.entries_mut().get_mut(3).is_none());
+    assert_eq!(-1, view.entries().get(2).unwrap().value().read());
+    assert_eq!([255, 255, 255, 255], storage[16..20]);
+
+    let mut view = table::View::new(&mut storage);
+    view.count_mut().write(1);
+    assert_eq!(1, view.entries().len());
+    assert!(view.entries().get(1).is_none());
+}
+
+#[test]
+#[should_panic(
+    expected = "Error: Storage too small for 4 repetitions: required 26 bytes but only got 22"
+)]
+fn repeated_storage_too_small() {
+    let mut storage = table_storage();
+    storage[1] = 4;
+    table::View::new(&storage[..]).entries();
+}
+
+#[test]
+fn fixed_count() {
+    let mut storage = vec![0; 21];
+    let mut view = fixed_table::View::new(&mut storage);
+    for (index, id) in [5, 6, 7].into_iter().enumerate() {
+        view.entries_mut()
+            .get_mut(index)
+            .unwrap()
+            .id_mut()
+            .write(id);
+    }
+    view.checksum_mut().write(0xFFFF);
+
+    assert_eq!(3, view.entries().len());
+    assert!(view.entries().get(3).is_none());
+    let ids: Vec<u16> = view
+        .entries()
+        .iter()
+        .map(|entry| entry.id().read())
+        .collect();
+    assert_eq!(vec![5, 6, 7], ids);
+    assert_eq!([0, 7], storage[13..15]);
+    assert_eq!([0xFF, 0xFF], storage[19..21]);
+}
+
+#[test]
+fn read_embedded_fixed_count() {
+    let storage = [
+        1, // version
+        0, 1, 0, 0, 0, 10, // entry 0
+        0, 2, 0, 0, 0, 20, // entry 1
+        0, 3, 255, 255, 255, 226, // entry 2
+        0xAB, 0xCD, // checksum
+    ];
+    let view = fixed_table::View::new(&storage[..]);
+    let entries = view.entries();
+    let third = entries.get(2).unwrap();
+    assert_eq!(3, third.id().read());
+    assert_eq!(-30, third.value().read());
+    assert_eq!(
+        Some(-30),
+        view.entries()
+            .iter()
+            .nth(2)
+            .map(|entry| entry.value().read())
+    );
+    assert_eq!(1, view.version().read());
+    assert_eq!(0xABCD, view.checksum().read());
+}
+
+#[test]
+fn into_repeated() {
+    let view = table::View::new(table_storage());
+    let entries = view.into_entries();
+    assert_eq!(256, entries.get(2).unwrap().value().read());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned() {
+    let storage = table_storage();
+    let owned = table::View::new(&storage[..20]).to_owned_struct();
+    assert_eq!(3, owned.count);
+    assert_eq!(3, owned.entries.len());
+    assert_eq!(-20, owned.entries[1].value);
+    assert_eq!(storage[..20], owned.to_view().into_storage()[..]);
+
+    let mut storage = vec![0; 21];
+    fixed_table::View::new(&mut storage)
+        .entries_mut()
+        .get_mut(1)
+        .unwrap()
+        .value_mut()
+        .write(42);
+    let owned = fixed_table::View::new(&storage).to_owned_struct();
+    assert_eq!(42, owned.entries[1].value);
+    assert_eq!(storage, owned.to_view().into_storage());
+}
+
+#[test]
+fn repeated_until_end() {
+    for count in 0..4 {
+        let mut storage = vec![1];
+        for index in 0..count {
+            storage.extend_from_slice(&[0, index as u8, 0, 0, 0, 10 * index as u8]);
+        }
+        let view = log::View::new(&storage[..]);
+        let entries = view.entries();
+        assert_eq!(count, entries.len());
+        let values: Vec<(u16, i32)> = entries
+            .iter()
+            .map(|entry| (entry.id().read(), entry.value().read()))
+            .collect();
+        let expected: Vec<(u16, i32)> = (0..count).map(|i| (i as u16, 10 * i as i32)).collect();
+        assert_eq!(expected, values);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn repeated_until_end_skips_partial_record() {
+    let mut storage = vec![1, 0, 1, 0, 0, 0, 10, 0, 2, 0, 0, 0, 20, 0xAB, 0xCD];
+    let mut view = log::View::new(&mut storage[..]);
+    assert_eq!(2, view.entries().len());
+    assert!(view.entries().get(2).is_none());
+    view.entries_mut().get_mut(1).unwrap().value_mut().write(-1);
+    assert_eq!(&[0xFF, 0xFF, 0xFF, 0xFF, 0xAB, 0xCD], &storage[9..]);
+
+    let owned = log::View::new(&storage[..]).to_owned_struct();
+    assert_eq!(2, owned.entries.len());
+    assert_eq!(-1, owned.entries[1].value);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "std")]
+
+use binary_layout::prelude::*;
+
+define_layout!(message, LittleEndian, {
+    id: u16,
+    length: u8,
+    payload: [u8],
+});
+
+#[test]
+fn grow_tail() {
+    let mut view = message::View::new(vec![0; 3]);
+    view.id_mut().write(0x0102);
+    view.length_mut().write(4);
+    view.resize_tail(4);
+    assert_eq!(&[0, 0, 0, 0], view.payload());
+    view.payload_mut().copy_from_slice(&[5, 6, 7, 8]);
+    assert_eq!(0x0102, view.id().read());
+    assert_eq!(4, view.length().read());
+    assert_eq!(vec![2, 1, 4, 5, 6, 7, 8], view.into_storage());
+}
+
+#[test]
+fn shrink_tail() {
+    let mut view = message::View::new(vec![2, 1, 4, 5, 6, 7, 8]);
+    view.resize_tail(1);
+    assert_eq!(&[5], view.payload());
+    view.resize_tail(0);
+    assert!(view.payload().is_empty());
+    assert_eq!(0x0102, view.id().read());
+    assert_eq!(vec![2, 1, 4], view.into_storage());
+}
+
+#[test]
+fn resize_tail_of_short_storage() {
+    let mut view = message::View::new(vec![2]);
+    view.resize_tail(2);
+    assert_eq!(vec![2, 0, 0, 0, 0], view.into_storage());
+}
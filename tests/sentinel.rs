@@ -0,0 +1,74 @@
+use binary_layout::prelude::*;
+
+mod common;
+use common::data_region;
+
+define_layout!(sentinel_layout, BigEndian, {
+    first: u8,
+    id: u32 = sentinel(0xFFFF_FFFF),
+    delta: i16 = sentinel(-1),
+    value: f32 = sentinel(0.0),
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(1, sentinel_layout::id::OFFSET);
+    assert_eq!(Some(4), sentinel_layout::id::SIZE);
+    assert_eq!(5, sentinel_layout::delta::OFFSET);
+    assert_eq!(7, sentinel_layout::value::OFFSET);
+    assert_eq!(Some(11), sentinel_layout::SIZE);
+}
+
+#[test]
+fn fields() {
+    let mut storage = data_region(1024, 0);
+
+    sentinel_layout::id::write(&mut storage, Some(10));
+    sentinel_layout::delta::write(&mut storage, Some(-5));
+    sentinel_layout::value::write(&mut storage, Some(1.5));
+    assert_eq!(Some(10), sentinel_layout::id::read(&storage));
+    assert_eq!(Some(-5), sentinel_layout::delta::read(&storage));
+    assert_eq!(Some(1.5), sentinel_layout::value::read(&storage));
+    assert_eq!(&10u32.to_be_bytes(), &storage[1..5]);
+
+    sentinel_layout::id::write(&mut storage, None);
+    sentinel_layout::delta::write(&mut storage, None);
+    sentinel_layout::value::write(&mut storage, None);
+    assert_eq!(None, sentinel_layout::id::read(&storage));
+    assert_eq!(None, sentinel_layout::delta::read(&storage));
+    assert_eq!(None, sentinel_layout::value::read(&storage));
+    assert_eq!(
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0],
+        &storage[1..11]
+    );
+}
+
+#[test]
+fn view() {
+    let mut storage = data_region(1024, 0);
+    let mut view = sentinel_layout::View::new(&mut storage);
+
+    view.id_mut().write(Some(0xFFFF_FFFE));
+    assert_eq!(Some(0xFFFF_FFFE), view.id().read());
+
+    view.id_mut().write(None);
+    assert_eq!(None, view.id().read());
+
+    // The raw sentinel value written through another layout reads as None
+    storage[1..5].copy_from_slice(&[0xFF; 4]);
+    let view = sentinel_layout::View::new(&storage);
+    assert_eq!(None, view.id().read());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn to_owned_struct() {
+    let mut view = sentinel_layout::View::new(vec![0; 11]);
+    view.id_mut().write(None);
+    view.delta_mut().write(Some(3));
+    let owned = view.to_owned_struct();
+    assert_eq!(None, owned.id);
+    assert_eq!(Some(3), owned.delta);
+    assert_eq!(None, owned.value);
+    assert_eq!(view.into_storage(), owned.to_view().into_storage());
+}
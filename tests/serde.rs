@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+
+use binary_layout::prelude::*;
+use binary_layout::SerdeCodec;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+struct Bincode;
+
+impl SerdeCodec for Bincode {
+    type Error = bincode::Error;
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Self::Error> {
+        bincode::deserialize(bytes)
+    }
+
+    fn encode<T: Serialize + ?Sized>(value: &T, dst: &mut [u8]) -> Result<(), Self::Error> {
+        bincode::serialize_into(dst, value)
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    id: u32,
+    name: String,
+    enabled: bool,
+}
+
+define_layout!(record, LittleEndian, {
+    version: u8,
+    config: [u8; 32],
+    extra: [u8],
+});
+
+fn config() -> Config {
+    Config {
+        id: 7,
+        name: "sensor".into(),
+        enabled: true,
+    }
+}
+
+#[test]
+fn round_trip_in_sized_array() {
+    let mut storage = vec![0; 40];
+    record::config::write_encoded::<Bincode, _>(&mut storage, &config()).unwrap();
+    assert_eq!(
+        bincode::serialize(&config()).unwrap(),
+        storage[1..1 + bincode::serialized_size(&config()).unwrap() as usize]
+    );
+    assert_eq!(
+        config(),
+        record::config::read_decoded::<Bincode, Config>(&storage).unwrap()
+    );
+}
+
+#[test]
+fn round_trip_in_open_ended_array() {
+    let mut storage = vec![0; 33 + 24];
+    record::extra::write_encoded::<Bincode, _>(&mut storage, &config()).unwrap();
+    assert_eq!(
+        config(),
+        record::extra::read_decoded::<Bincode, Config>(&storage).unwrap()
+    );
+}
+
+#[test]
+fn encoded_value_too_large() {
+    let mut storage = vec![0; 40];
+    let large = Config {
+        id: 1,
+        name: "a name that doesn't fit into the byte array".into(),
+        enabled: false,
+    };
+    assert!(record::config::write_encoded::<Bincode, _>(&mut storage, &large).is_err());
+    assert!(record::extra::read_decoded::<Bincode, Config>(&storage).is_err());
+}
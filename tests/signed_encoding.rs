@@ -0,0 +1,75 @@
+use binary_layout::prelude::*;
+
+define_layout!(legacy, BigEndian, {
+    magnitude: SignMagnitude<u16>,
+    complement: OnesComplement<u32>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, legacy::magnitude::OFFSET);
+    assert_eq!(Some(2), legacy::magnitude::SIZE);
+    assert_eq!(2, legacy::complement::OFFSET);
+    assert_eq!(Some(4), legacy::complement::SIZE);
+}
+
+#[test]
+fn known_bit_patterns() {
+    let mut storage = vec![0x80, 0x01, 0xFF, 0xFF, 0xFF, 0xFE];
+    let mut view = legacy::View::new(&mut storage);
+    assert_eq!(-1, view.magnitude().read());
+    assert_eq!(-1, view.complement().read());
+
+    view.magnitude_mut().write(-0x1234);
+    view.complement_mut().write(-0x1234);
+    assert_eq!(&[0x92, 0x34, 0xFF, 0xFF, 0xED, 0xCB], &storage[..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn negative_zero() {
+    let storage = vec![0x80, 0x00, 0xFF, 0xFF, 0xFF, 0xFF];
+    let view = legacy::View::new(&storage);
+    assert_eq!(0, view.magnitude().read());
+    assert_eq!(0, view.complement().read());
+
+    let owned = view.to_owned_struct();
+    let view = owned.to_view();
+    assert_eq!(&[0, 0, 0, 0, 0, 0], &view.into_storage()[..]);
+}
+
+define_layout!(biased, LittleEndian, {
+    exponent: ExcessK<u16, 1023>,
+    small: ExcessK<u8, 100>,
+});
+
+#[cfg(feature = "std")]
+#[test]
+fn excess_k() {
+    let mut storage = vec![0; 3];
+    let mut view = biased::View::new(&mut storage);
+    view.exponent_mut().write(-1023);
+    view.small_mut().write(-100);
+    assert_eq!(&[0, 0, 0], &storage[..]);
+
+    let mut view = biased::View::new(&mut storage);
+    view.exponent_mut().write(-5);
+    view.small_mut().write(155);
+    assert_eq!(-5, view.exponent().read());
+    assert_eq!(155, view.small().read());
+    assert_eq!(&[0xFA, 0x03, 0xFF], &storage[..]);
+
+    let owned = biased::View::new(&storage).to_owned_struct();
+    assert_eq!(-5i32, owned.exponent);
+    assert_eq!(155i16, owned.small);
+    assert_eq!(&[0xFA, 0x03, 0xFF], &*owned.to_view().into_storage());
+}
+
+#[test]
+fn excess_k_matches_f64_exponent() {
+    let mut storage = [0; 3];
+    let bits = 0.125f64.to_bits();
+    let exponent = ((bits >> 52) & 0x7FF) as u16;
+    storage[..2].copy_from_slice(&exponent.to_le_bytes());
+    assert_eq!(-3, biased::View::new(&storage).exponent().read());
+}
@@ -9,6 +9,7 @@ define_layout!(sliceonly, LittleEndian, { field: [u8] });
 fn metadata() {
     assert_eq!(0, sliceonly::field::OFFSET);
     assert_eq!(None, sliceonly::field::SIZE);
+    assert_eq!(0, sliceonly::MIN_SIZE);
 }
 
 #[test]
@@ -0,0 +1,55 @@
+#![cfg(feature = "time")]
+
+use binary_layout::prelude::*;
+use binary_layout::time::{DurationAs, Milliseconds, Nanoseconds};
+use std::convert::TryInto;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+define_layout!(log_entry, BigEndian, {
+    level: u8,
+    timestamp: DurationAs<Milliseconds>,
+    elapsed: DurationAs<Nanoseconds>,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(1, log_entry::timestamp::OFFSET);
+    assert_eq!(Some(8), log_entry::timestamp::SIZE);
+    assert_eq!(9, log_entry::elapsed::OFFSET);
+    assert_eq!(Some(8), log_entry::elapsed::SIZE);
+}
+
+#[test]
+fn roundtrip_known_instant() {
+    // 2020-09-13T12:26:40Z
+    let instant = UNIX_EPOCH + Duration::from_millis(1_600_000_000_000);
+
+    let mut storage = vec![0; 17];
+    let mut view = log_entry::View::new(&mut storage);
+    view.timestamp_mut()
+        .write(instant.duration_since(UNIX_EPOCH).unwrap());
+    view.elapsed_mut().write(Duration::new(2, 500));
+
+    assert_eq!(instant, UNIX_EPOCH + view.timestamp().read());
+    assert_eq!(Duration::new(2, 500), view.elapsed().read());
+    assert_eq!(
+        1_600_000_000_000,
+        u64::from_be_bytes((&storage[1..9]).try_into().unwrap())
+    );
+    assert_eq!(
+        2_000_000_500,
+        u64::from_be_bytes((&storage[9..17]).try_into().unwrap())
+    );
+}
+
+#[test]
+fn sub_unit_precision_is_truncated() {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+    let mut storage = vec![0; 17];
+    log_entry::timestamp::write(&mut storage, now);
+
+    let read = log_entry::timestamp::read(&storage);
+    assert_eq!(now.as_millis(), read.as_millis());
+    assert_eq!(0, read.subsec_nanos() % 1_000_000);
+}
@@ -0,0 +1,176 @@
+use binary_layout::prelude::*;
+use binary_layout::{migrate, FieldInfo, FieldValue};
+
+define_layout!(file, LittleEndian, {
+    magic: u32,
+    body: [u8],
+    @trailer {
+        /// Checksum over the body
+        crc: u32,
+    },
+});
+
+define_layout!(framed_samples, BigEndian, {
+    count: u8,
+    payload: [u8; ..=8],
+    @trailer {
+        version: u8,
+        footer: [u8; 2],
+    },
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(4, file::body::OFFSET);
+    assert_eq!(None, file::body::SIZE);
+    assert_eq!(4, file::crc::OFFSET);
+    assert_eq!(Some(4), file::crc::SIZE);
+    assert_eq!(None, file::SIZE);
+    assert_eq!(8, file::MIN_SIZE);
+    assert_eq!(None, file::MAX_SIZE);
+    assert_eq!(
+        &[
+            FieldInfo {
+                name: "magic",
+                offset: 0,
+                size: Some(4)
+            },
+            FieldInfo {
+                name: "body",
+                offset: 4,
+                size: None
+            },
+            FieldInfo {
+                name: "crc",
+                offset: 4,
+                size: Some(4)
+            },
+        ],
+        file::FIELDS,
+    );
+
+    assert_eq!(1, framed_samples::version::OFFSET);
+    assert_eq!(2, framed_samples::footer::OFFSET);
+    assert_eq!(4, framed_samples::MIN_SIZE);
+    assert_eq!(Some(12), framed_samples::MAX_SIZE);
+}
+
+#[test]
+fn read_footer_after_variable_tail() {
+    for body in [&[][..], &[0xAA][..], &[1, 2, 3, 4, 5, 6, 7]] {
+        let mut storage = vec![0x7F, b'E', b'L', b'F'];
+        storage.extend_from_slice(body);
+        storage.extend_from_slice(&0xDEADBEEFu32.to_le_bytes());
+
+        let view = file::View::new(&storage[..]);
+        assert_eq!(u32::from_le_bytes(*b"\x7FELF"), view.magic().read());
+        assert_eq!(body, view.body());
+        assert_eq!(0xDEADBEEF, view.crc().read());
+        assert_eq!(0xDEADBEEF, file::crc::read(&storage));
+        assert_eq!(4, view.tail_offset());
+    }
+}
+
+#[test]
+fn write_footer() {
+    let mut storage = vec![0; 11];
+    let mut view = file::View::new(&mut storage);
+    view.body_mut().copy_from_slice(&[1, 2, 3]);
+    view.crc_mut().write(0x01020304);
+    assert_eq!(&[0, 0, 0, 0, 1, 2, 3, 4, 3, 2, 1], &*storage);
+}
+
+#[test]
+fn slice_fields_in_trailer() {
+    let storage = vec![2, 0, 1, 0, 2, 9, 0xAB, 0xCD];
+    let view = framed_samples::View::new(&storage[..]);
+    assert_eq!(2, view.count().read());
+    assert_eq!(&[0, 1, 0, 2], view.payload());
+    assert_eq!(9, view.version().read());
+    assert_eq!(&[0xAB, 0xCD], view.footer());
+
+    let view = framed_samples::View::new(storage);
+    assert_eq!(vec![0xAB, 0xCD], view.into_footer().as_ref().to_vec());
+}
+
+#[test]
+fn too_small_for_trailer() {
+    assert!(file::View::new_exact([0; 7]).is_err());
+    assert!(file::View::new_exact([0; 8]).is_ok());
+    assert!(framed_samples::View::new_exact([0; 13]).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn resize_tail_keeps_trailer() {
+    let mut view = file::View::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    view.resize_tail(3);
+    assert_eq!(&[1, 2, 3, 4, 5, 0, 0, 6, 7, 8, 9], &*view.storage);
+    view.resize_tail(0);
+    assert_eq!(&[1, 2, 3, 4, 6, 7, 8, 9], &*view.storage);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn resize_tail_of_short_storage_appends_trailer() {
+    let mut view = file::View::new(vec![1]);
+    view.resize_tail(3);
+    assert_eq!(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], &*view.storage);
+    assert!(view.body().iter().all(|&b| b == 0));
+    assert_eq!(0, view.crc().read());
+    view.crc_mut().write(0x01020304);
+    view.resize_tail(1);
+    assert_eq!(&[1, 0, 0, 0, 0, 4, 3, 2, 1], &*view.storage);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned() {
+    let storage = vec![1, 0, 0, 0, 5, 6, 4, 3, 2, 1];
+    let owned = file::View::new(&storage[..]).to_owned_struct();
+    assert_eq!(1, owned.magic);
+    assert_eq!(vec![5, 6], owned.body);
+    assert_eq!(0x01020304, owned.crc);
+    assert_eq!(storage, owned.to_view().into_storage());
+}
+
+#[test]
+fn for_each_field() {
+    let storage = [1, 0, 0, 0, 5, 6, 4, 3, 2, 1];
+    let view = file::View::new(&storage[..]);
+    let mut fields = Vec::new();
+    view.for_each_field(|name, offset, value| {
+        fields.push((name, offset, value));
+    });
+    assert_eq!(
+        vec![
+            ("magic", 0, FieldValue::U32(1)),
+            ("body", 4, FieldValue::Bytes(&[5, 6])),
+            ("crc", 6, FieldValue::U32(0x01020304)),
+        ],
+        fields
+    );
+}
+
+#[test]
+fn eq_ignoring() {
+    let lhs = file::View::new([0, 0, 0, 0, 5, 1, 1, 1, 1]);
+    let rhs = file::View::new([0, 0, 0, 0, 5, 2, 2, 2, 2]);
+    assert!(!lhs.eq_ignoring(&rhs, &[]));
+    assert!(lhs.eq_ignoring(&rhs, &["crc"]));
+}
+
+define_layout!(file_v1, LittleEndian, {
+    magic: u32,
+    crc: u32,
+});
+
+#[test]
+fn migrate_trailer_field() {
+    let src = file_v1::View::new([1, 0, 0, 0, 4, 3, 2, 1]);
+    let mut dst = file::View::new(vec![0; 10]);
+    assert_eq!(2, migrate(&src, &mut dst));
+    assert_eq!(1, dst.magic().read());
+    assert_eq!(0x01020304, dst.crc().read());
+    assert_eq!(&[0, 0], dst.body());
+}
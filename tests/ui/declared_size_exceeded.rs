@@ -0,0 +1,10 @@
+use binary_layout::prelude::*;
+
+define_layout!(slot, LittleEndian, size = 8, {
+    id: u32,
+    value: u64,
+});
+
+fn main() {
+    let _ = slot::SIZE;
+}
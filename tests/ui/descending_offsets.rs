@@ -0,0 +1,17 @@
+use binary_layout::prelude::*;
+
+define_layout!(header, LittleEndian, {
+    magic: u32,
+    end_of_header: (),
+});
+
+// `@include header` takes over the fields of `header` at the start of the layout, where they keep their offsets.
+// This composition was broken by hand and takes over `end_of_header` after a new `version` field, so it doesn't
+// overlap any field but starts at the same offset as the field declared before it.
+define_layout!(broken, LittleEndian, {
+    magic: u32,
+    version: u32,
+    end_of_header: binary_layout::internal::Included<header::end_of_header>,
+});
+
+fn main() {}
@@ -0,0 +1,9 @@
+use binary_layout::prelude::*;
+use binary_layout::{check_field_bounds, PrimitiveField};
+
+type Checksum = PrimitiveField<u32, LittleEndian, 14>;
+const _: () = check_field_bounds::<Checksum>(16);
+
+fn main() {
+    let _ = Checksum::OFFSET;
+}
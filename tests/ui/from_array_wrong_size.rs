@@ -0,0 +1,11 @@
+use binary_layout::prelude::*;
+
+define_layout!(my_layout, LittleEndian, {
+    first: u16,
+    second: u32,
+});
+
+fn main() {
+    let view = my_layout::View::from_array([0; 5]);
+    assert_eq!(0, view.first().read());
+}
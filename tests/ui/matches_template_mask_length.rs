@@ -0,0 +1,11 @@
+use binary_layout::prelude::*;
+
+define_layout!(record, BigEndian, {
+    kind: u8,
+    checksum: u16,
+});
+
+fn main() {
+    let view = record::View::new([1, 0, 0]);
+    view.matches_template(&[1, 0, 0], &[0xFF, 0x00]);
+}
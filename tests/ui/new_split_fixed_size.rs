@@ -0,0 +1,10 @@
+use binary_layout::prelude::*;
+
+define_layout!(message, LittleEndian, {
+    id: u32,
+    value: u16,
+});
+
+fn main() {
+    let _ = message::View::new_split([0; 6], [0; 2]);
+}
@@ -0,0 +1,13 @@
+use binary_layout::prelude::*;
+
+define_layout!(message, LittleEndian, {
+    id: u32,
+    payload: [u8],
+    @trailer {
+        checksum: u32,
+    },
+});
+
+fn main() {
+    let _ = message::View::new_split([0; 4], [0; 6]);
+}
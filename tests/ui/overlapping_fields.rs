@@ -0,0 +1,15 @@
+use binary_layout::prelude::*;
+
+define_layout!(header, LittleEndian, {
+    version: u16,
+    length: u32,
+});
+
+// `@include header` takes over the fields of `header` at the start of the layout, where they keep their offsets.
+// This composition was broken by hand and takes over `length` after another field, so it overlaps that field.
+define_layout!(broken, LittleEndian, {
+    id: u32,
+    length: binary_layout::internal::Included<header::length>,
+});
+
+fn main() {}
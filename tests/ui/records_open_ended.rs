@@ -0,0 +1,11 @@
+use binary_layout::prelude::*;
+
+define_layout!(message, LittleEndian, {
+    id: u32,
+    payload: [u8],
+});
+
+fn main() {
+    let storage = [0; 16];
+    let _ = message::records(&storage);
+}
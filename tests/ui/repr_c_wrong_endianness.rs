@@ -0,0 +1,17 @@
+use binary_layout::prelude::*;
+
+#[cfg(target_endian = "little")]
+define_layout!(record, BigEndian, repr_c = RecordC, {
+    kind: u8,
+    value: u32,
+});
+
+#[cfg(target_endian = "big")]
+define_layout!(record, LittleEndian, repr_c = RecordC, {
+    kind: u8,
+    value: u32,
+});
+
+fn main() {
+    let _ = record::SIZE;
+}
@@ -0,0 +1,11 @@
+use binary_layout::prelude::*;
+
+define_layout!(frame, BigEndian, max_size = 8, {
+    kind: u8,
+    length: u16,
+    payload: [u8; ..=8],
+});
+
+fn main() {
+    let _ = frame::MAX_SIZE;
+}
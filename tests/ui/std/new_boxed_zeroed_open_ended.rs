@@ -0,0 +1,10 @@
+use binary_layout::prelude::*;
+
+define_layout!(message, LittleEndian, {
+    id: u32,
+    payload: [u8],
+});
+
+fn main() {
+    let _ = message::View::new_boxed_zeroed();
+}
@@ -0,0 +1,11 @@
+use binary_layout::prelude::*;
+
+define_layout!(message, LittleEndian, {
+    id: u32,
+    value: u16,
+});
+
+fn main() {
+    let mut view = message::View::new(vec![0; 6]);
+    view.resize_tail(2);
+}
@@ -0,0 +1,15 @@
+use binary_layout::prelude::*;
+
+define_layout!(options, LittleEndian, optional(presence: u8) {
+    f0: u8,
+    f1: u8,
+    f2: u8,
+    f3: u8,
+    f4: u8,
+    f5: u8,
+    f6: u8,
+    f7: u8,
+    f8: u8,
+});
+
+fn main() {}
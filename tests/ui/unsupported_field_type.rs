@@ -0,0 +1,11 @@
+use binary_layout::prelude::*;
+use binary_layout::PrimitiveField;
+
+// This is what define_layout! generates for a field of an unsupported type.
+// A full layout reports the same error for every place the field is used.
+#[allow(non_camel_case_types)]
+type field = PrimitiveField<String, LittleEndian, 0>;
+
+fn main() {
+    let _ = <field as Field>::SIZE;
+}
@@ -0,0 +1,8 @@
+use binary_layout::prelude::*;
+
+define_layout!(my_layout, LittleEndian, {
+    first: u16,
+    empty: [u8; 0],
+});
+
+fn main() {}
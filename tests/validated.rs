@@ -0,0 +1,67 @@
+use binary_layout::prelude::*;
+
+define_layout!(validated_layout, BigEndian, {
+    version: u8 where |v| v <= 3,
+    length: u16 where |v| v % 4 == 0,
+    value: i32,
+});
+
+#[test]
+fn metadata() {
+    assert_eq!(0, validated_layout::version::OFFSET);
+    assert_eq!(Some(1), validated_layout::version::SIZE);
+    assert_eq!(1, validated_layout::length::OFFSET);
+    assert_eq!(Some(2), validated_layout::length::SIZE);
+    assert_eq!(3, validated_layout::value::OFFSET);
+    assert_eq!(Some(7), validated_layout::SIZE);
+}
+
+#[test]
+fn read_valid() {
+    let mut storage = [0; 7];
+    let mut view = validated_layout::View::new(&mut storage);
+    view.version_mut().write(3);
+    view.length_mut().write(8);
+    view.value_mut().write(-5);
+
+    assert_eq!(3, view.version().read());
+    assert_eq!(8, view.length().read());
+    assert_eq!(-5, view.value().read());
+    assert_eq!([3, 0, 8, 255, 255, 255, 251], storage);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Error: Field value doesn't fulfill `|v| v <= 3`")]
+fn read_invalid_panics_in_debug() {
+    let storage = [4, 0, 0, 0, 0, 0, 0];
+    validated_layout::View::new(&storage[..]).version().read();
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn read_invalid_in_release() {
+    let storage = [4, 0, 0, 0, 0, 0, 0];
+    assert_eq!(
+        4,
+        validated_layout::View::new(&storage[..]).version().read()
+    );
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Error: Field value doesn't fulfill `|v| v % 4 == 0`")]
+fn peek_invalid_panics_in_debug() {
+    let storage = [0, 0, 5, 0, 0, 0, 0];
+    let _ = validated_layout::peek_length(&storage);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned() {
+    let storage = [2, 0, 4, 0, 0, 0, 1];
+    let owned = validated_layout::View::new(&storage[..]).to_owned_struct();
+    assert_eq!(2, owned.version);
+    assert_eq!(4, owned.length);
+    assert_eq!(storage[..], owned.to_view().into_storage()[..]);
+}
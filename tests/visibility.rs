@@ -0,0 +1,64 @@
+use binary_layout::prelude::*;
+
+mod inner {
+    use binary_layout::prelude::*;
+
+    define_layout!(pub(crate) crate_layout, LittleEndian, {
+        field1: u16,
+        field2: u32,
+    });
+
+    define_layout!(pub(super) super_layout, BigEndian, {
+        field: u16,
+    });
+
+    define_layout!(pub(in crate::inner) path_layout, LittleEndian, {
+        field: u8,
+    });
+
+    pub fn read_path_layout(storage: &[u8]) -> u8 {
+        path_layout::View::new(storage).field().read()
+    }
+}
+
+define_layout!(pub public_layout, LittleEndian, {
+    field: u16,
+});
+
+define_layout!(pub(self) private_layout, LittleEndian, {
+    field: u16,
+});
+
+#[test]
+fn pub_crate() {
+    let mut storage = vec![0; 6];
+    let mut view = inner::crate_layout::View::new(&mut storage);
+    view.field1_mut().write(1);
+    view.field2_mut().write(2);
+    assert_eq!(1, view.field1().read());
+    assert_eq!(2, view.field2().read());
+    assert_eq!(Some(6), inner::crate_layout::SIZE);
+}
+
+#[test]
+fn pub_super() {
+    let view = inner::super_layout::View::new(vec![1, 2]);
+    assert_eq!(0x0102, view.field().read());
+}
+
+#[test]
+fn pub_in_path() {
+    assert_eq!(5, inner::read_path_layout(&[5]));
+}
+
+#[test]
+fn explicit_pub() {
+    let view = public_layout::View::new(vec![1, 0]);
+    assert_eq!(1, view.field().read());
+}
+
+#[test]
+fn private() {
+    let view = private_layout::View::new(vec![2, 0]);
+    assert_eq!(2, view.field().read());
+}
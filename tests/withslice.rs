@@ -1,4 +1,6 @@
 use binary_layout::prelude::*;
+use binary_layout::FieldValue;
+use binary_layout::LayoutError;
 use core::any::{Any, TypeId};
 use std::convert::TryInto;
 
@@ -25,6 +27,100 @@ fn metadata() {
     assert_eq!(Some(2), withslice::fourth::SIZE);
     assert_eq!(16, withslice::fifth::OFFSET);
     assert_eq!(None, withslice::fifth::SIZE);
+    assert_eq!(16, withslice::MIN_SIZE);
+}
+
+#[test]
+fn for_each_field() {
+    let mut storage = data_region(1024, 5);
+    withslice::first::write(&mut storage, -3);
+    withslice::fourth::write(&mut storage, 1000);
+    let view = withslice::View::new(&storage);
+
+    let mut fields = Vec::new();
+    view.for_each_field(|name, offset, _value| fields.push((name, offset)));
+    assert_eq!(
+        vec![
+            ("first", 0),
+            ("second", 1),
+            ("third", 9),
+            ("fourth", 14),
+            ("fifth", 16),
+        ],
+        fields
+    );
+
+    let mut values = Vec::new();
+    view.for_each_field(|_name, _offset, value| values.push(value));
+    assert_eq!(FieldValue::I8(-3), values[0]);
+    assert_eq!(FieldValue::I64(view.second().read()), values[1]);
+    assert_eq!(FieldValue::Bytes(&view.third()[..]), values[2]);
+    assert_eq!(FieldValue::U16(1000), values[3]);
+    assert_eq!(FieldValue::Bytes(view.fifth()), values[4]);
+}
+
+#[test]
+fn field_names() {
+    assert_eq!(5, withslice::FIELD_COUNT);
+    assert_eq!(
+        &["first", "second", "third", "fourth", "fifth"],
+        withslice::FIELD_NAMES
+    );
+}
+
+#[test]
+fn offsets_and_sizes() {
+    assert_eq!(
+        [
+            withslice::first::OFFSET,
+            withslice::second::OFFSET,
+            withslice::third::OFFSET,
+            withslice::fourth::OFFSET,
+            withslice::fifth::OFFSET,
+        ],
+        withslice::OFFSETS
+    );
+    assert_eq!(
+        [
+            withslice::first::SIZE,
+            withslice::second::SIZE,
+            withslice::third::SIZE,
+            withslice::fourth::SIZE,
+            withslice::fifth::SIZE,
+        ],
+        withslice::SIZES
+    );
+    for ((name, offset), size) in withslice::FIELD_NAMES
+        .iter()
+        .zip(withslice::OFFSETS)
+        .zip(withslice::SIZES)
+    {
+        assert_eq!(Some((offset, size)), withslice::field_info(name));
+    }
+}
+
+#[test]
+fn field_meta() {
+    assert_eq!(withslice::FIELD_COUNT, withslice::FIELD_META.len());
+    let fourth = withslice::FIELD_META[3];
+    assert_eq!("fourth", fourth.name);
+    assert_eq!("u16", fourth.type_name);
+    assert_eq!(withslice::fourth::OFFSET, fourth.offset);
+    assert_eq!(withslice::fourth::SIZE, fourth.size);
+    assert!(fourth.little_endian);
+    assert_eq!(
+        &["i8", "i64", "[u8; 5]", "u16", "[u8]"],
+        &withslice::FIELD_META
+            .iter()
+            .map(|meta| meta.type_name)
+            .collect::<Vec<_>>()[..]
+    );
+    for (meta, info) in withslice::FIELD_META.iter().zip(withslice::FIELDS) {
+        assert_eq!(
+            (meta.name, meta.offset, meta.size),
+            (info.name, info.offset, info.size)
+        );
+    }
 }
 
 #[test]
@@ -242,3 +338,287 @@ fn view_vec_readwrite() {
     );
     assert_eq!(&data_region(1024, 6)[16..], &extracted_storage[16..]);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn to_owned_struct() {
+    let storage = data_region(1024, 5);
+    let view = withslice::View::new(&storage);
+
+    let owned: withslice::Owned = view.to_owned_struct();
+    assert_eq!(view.first().read(), owned.first);
+    assert_eq!(view.second().read(), owned.second);
+    assert_eq!(view.third(), &owned.third);
+    assert_eq!(view.fourth().read(), owned.fourth);
+    let fifth: &Vec<u8> = &owned.fifth;
+    assert_eq!(view.fifth(), &fifth[..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_struct_roundtrip() {
+    let owned = withslice::Owned {
+        first: 50,
+        second: 10i64.pow(15),
+        third: [1, 2, 3, 4, 5],
+        fourth: 1000,
+        fifth: vec![6, 7, 8],
+    };
+
+    let view: withslice::View<Vec<u8>> = owned.to_view();
+    assert_eq!(19, view.storage.len());
+    assert_eq!(50, view.first().read());
+    assert_eq!(10i64.pow(15), view.second().read());
+    assert_eq!(&[1, 2, 3, 4, 5], view.third());
+    assert_eq!(1000, view.fourth().read());
+    assert_eq!(&[6, 7, 8], view.fifth());
+
+    let roundtripped = view.to_owned_struct();
+    assert_eq!(owned.first, roundtripped.first);
+    assert_eq!(owned.second, roundtripped.second);
+    assert_eq!(owned.third, roundtripped.third);
+    assert_eq!(owned.fourth, roundtripped.fourth);
+    assert_eq!(owned.fifth, roundtripped.fifth);
+}
+
+#[test]
+fn field_region() {
+    let storage = data_region(1024, 5);
+    let view = withslice::View::new(&storage);
+
+    assert_eq!(
+        &storage[0..14],
+        view.field_region::<withslice::first, withslice::third>()
+    );
+    assert_eq!(
+        &storage[1..9],
+        view.field_region::<withslice::second, withslice::second>()
+    );
+    assert_eq!(
+        &storage[9..],
+        view.field_region::<withslice::third, withslice::fifth>()
+    );
+}
+
+#[test]
+fn tail_checked() {
+    let storage = data_region(20, 5);
+
+    assert_eq!(
+        Ok(&storage[16..]),
+        withslice::fifth::data_checked(&storage, 4)
+    );
+    assert_eq!(
+        Err(binary_layout::LayoutError::TooShort {
+            min_len: 5,
+            actual_len: 4
+        }),
+        withslice::fifth::data_checked(&storage, 5)
+    );
+
+    let mut view = withslice::View::new(storage);
+    assert!(withslice::fifth::data_mut_checked(&mut view.storage, 5).is_err());
+    withslice::fifth::data_mut_checked(&mut view.storage, 4).unwrap()[0] = 1;
+    assert_eq!(1, view.fifth()[0]);
+}
+
+#[test]
+fn empty_tail() {
+    let mut storage = data_region(16, 5);
+    assert_eq!(withslice::MIN_SIZE, storage.len());
+
+    let empty: &[u8] = &[];
+    assert_eq!(empty, withslice::fifth::data(&storage));
+    assert_eq!(Ok(empty), withslice::fifth::data_checked(&storage, 0));
+    assert!(withslice::fifth::data_mut(&mut storage).is_empty());
+
+    let mut view = withslice::View::new(&mut storage);
+    assert_eq!(empty, view.fifth());
+    assert!(view.fifth_mut().is_empty());
+    view.fourth_mut().write(1000);
+    assert_eq!(1000, view.fourth().read());
+    assert_eq!(16, view.tail_offset());
+}
+
+#[test]
+fn new_split() {
+    let header = data_region(16, 5);
+    let body = data_region(100, 6);
+    let view = withslice::View::new_split(&header[..], &body[..]).unwrap();
+    assert_eq!(withslice::first::read(&header), view.first().read());
+    assert_eq!(withslice::second::read(&header), view.second().read());
+    assert_eq!(&header[9..14], view.third());
+    assert_eq!(withslice::fourth::read(&header), view.fourth().read());
+    assert_eq!(&body[..], view.body());
+    assert!(view.fifth().is_empty());
+}
+
+#[test]
+fn new_split_readwrite() {
+    let mut header = [0; 16];
+    let mut body = [0; 3];
+    let mut view = withslice::View::new_split(&mut header[..], &mut body[..]).unwrap();
+    view.fourth_mut().write(1000);
+    view.body_mut().copy_from_slice(&[1, 2, 3]);
+    assert_eq!(1000, view.fourth().read());
+    let (header, body) = view.into_parts();
+    assert_eq!(1000, withslice::fourth::read(header));
+    assert_eq!(&[1, 2, 3], body);
+}
+
+#[test]
+fn new_split_with_wrong_header_size() {
+    let storage = data_region(20, 5);
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 16,
+            actual: 15
+        }),
+        withslice::View::new_split(&storage[..15], &storage[15..]).map(|_| ())
+    );
+    assert_eq!(
+        Err(LayoutError::BufferTooLarge {
+            expected: 16,
+            actual: 17
+        }),
+        withslice::View::new_split(&storage[..17], &storage[17..]).map(|_| ())
+    );
+}
+
+#[test]
+fn read_array() {
+    let storage = data_region(1024, 5);
+    let array: [u8; 5] = withslice::third::read_array(&storage);
+    let array_from_view: [u8; 5] = *withslice::View::new(&storage).third();
+    let expected = data_region(1024, 5)[9..14].to_vec();
+    drop(storage);
+
+    assert_eq!(&expected[..], &array[..]);
+    assert_eq!(array, array_from_view);
+}
+
+#[test]
+fn copy_into() {
+    let storage = data_region(20, 5);
+
+    let mut array_buffer = [0; 5];
+    assert_eq!(
+        Ok(5),
+        withslice::third::copy_into(&storage, &mut array_buffer)
+    );
+    assert_eq!(&storage[9..14], &array_buffer);
+
+    let mut too_small = [0; 4];
+    assert_eq!(
+        Err(binary_layout::LayoutError::DestinationTooShort {
+            required: 5,
+            actual: 4
+        }),
+        withslice::third::copy_into(&storage, &mut too_small)
+    );
+    assert_eq!([0; 4], too_small);
+
+    let mut tail_buffer = [0; 8];
+    assert_eq!(
+        Ok(4),
+        withslice::fifth::copy_into(&storage, &mut tail_buffer)
+    );
+    assert_eq!(&storage[16..], &tail_buffer[..4]);
+    assert_eq!(
+        Err(binary_layout::LayoutError::DestinationTooShort {
+            required: 4,
+            actual: 3
+        }),
+        withslice::fifth::copy_into(&storage, &mut [0; 3])
+    );
+
+    let view = withslice::View::new(&storage[..]);
+    let mut buffer = [0; 4];
+    assert_eq!(Ok(4), view.into_fifth().copy_into(&mut buffer));
+    assert_eq!(&storage[16..], &buffer);
+}
+
+#[test]
+fn new_exact() {
+    let storage = data_region(1024, 5);
+    let view = withslice::View::new_exact(&storage[..]).unwrap();
+    assert_eq!(1024 - 16, view.fifth().len());
+    assert!(withslice::View::new_exact(&storage[..16]).is_ok());
+    assert_eq!(
+        Err(LayoutError::BufferTooSmall {
+            required: 16,
+            actual: 15
+        }),
+        withslice::View::new_exact(&storage[..15]).map(|_| ())
+    );
+}
+
+#[test]
+fn field_info_in_const_context() {
+    const SECOND: Option<(usize, Option<usize>)> = withslice::field_info("second");
+    const FIFTH: Option<(usize, Option<usize>)> = withslice::field_info("fifth");
+    const MISSING: Option<(usize, Option<usize>)> = withslice::field_info("sixth");
+    assert_eq!(Some((1, Some(8))), SECOND);
+    assert_eq!(Some((16, None)), FIFTH);
+    assert_eq!(None, MISSING);
+
+    const SECOND_END: usize = match SECOND {
+        Some((offset, Some(size))) => offset + size,
+        _ => panic!("second should have a size"),
+    };
+    assert_eq!(9, SECOND_END);
+}
+
+#[test]
+fn from_array() {
+    let mut view = withslice::View::from_array([0; 16]);
+    view.fourth_mut().write(1_000);
+    assert_eq!(1_000, view.fourth().read());
+    assert!(view.fifth().is_empty());
+}
+
+#[test]
+fn tail_offset() {
+    let storage = data_region(1024, 5);
+    let view = withslice::View::new(&storage[..]);
+    assert_eq!(16, view.tail_offset());
+    assert_eq!(withslice::fifth::OFFSET, view.tail_offset());
+    assert_eq!(view.fifth(), &storage[view.tail_offset()..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tail_as_str() {
+    let mut storage = data_region(16, 5);
+    storage.extend_from_slice("grüße".as_bytes());
+    let view = withslice::View::new(&storage);
+    assert_eq!(Ok("grüße"), view.fifth().as_str());
+    assert_eq!("grüße", view.fifth().to_str_lossy());
+
+    storage.push(0xFF);
+    storage.extend_from_slice(b"!");
+    let view = withslice::View::new(&storage);
+    assert!(matches!(
+        view.fifth().as_str(),
+        Err(LayoutError::Utf8Error(error)) if error.valid_up_to() == 7
+    ));
+    assert_eq!("grüße\u{FFFD}!", view.fifth().to_str_lossy());
+}
+
+#[test]
+fn count_ones() {
+    let mut storage = vec![0; 20];
+    let mut view = withslice::View::new(&mut storage);
+    view.first_mut().write(-128);
+    view.second_mut().write(0x0F0F);
+    view.third_mut()
+        .copy_from_slice(&[0b1000_0001, 0xFF, 0, 0b0110_0000, 1]);
+    view.fourth_mut().write(u16::MAX);
+
+    assert_eq!(1, view.first().count_ones());
+    assert_eq!(8, view.second().count_ones());
+    assert_eq!(16, view.fourth().count_ones());
+    assert_eq!(13, withslice::third::count_ones(&storage));
+    assert_eq!(13, withslice::View::new(&storage).into_third().count_ones());
+    assert_eq!(0, withslice::View::new(&storage).into_fifth().count_ones());
+}
@@ -250,3 +250,15 @@ fn view_vec_readwrite() {
         u16::from_le_bytes((&extracted_storage[9..11]).try_into().unwrap())
     );
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn to_owned_struct() {
+    let storage = data_region(1024, 5);
+    let view = noslice::View::new(&storage);
+
+    let owned: noslice::Owned = view.to_owned_struct();
+    assert_eq!(view.first().read(), owned.first);
+    assert_eq!(view.second().read(), owned.second);
+    assert_eq!(view.third().read(), owned.third);
+}
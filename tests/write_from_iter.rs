@@ -0,0 +1,135 @@
+use binary_layout::prelude::*;
+use binary_layout::LayoutError;
+
+define_layout!(packet, BigEndian, {
+    kind: u8,
+    name: [u8; 4],
+    payload: [u8],
+});
+
+define_layout!(samples, BigEndian, {
+    channel: u8,
+    data: [u16],
+});
+
+#[test]
+fn array_exact() {
+    let mut storage = [0; 7];
+    assert_eq!(
+        Ok(()),
+        packet::name::write_from_iter(&mut storage, b"abcd".iter().copied())
+    );
+    assert_eq!(b"abcd", packet::View::new(&storage).name());
+}
+
+#[test]
+fn array_short() {
+    let mut storage = [0; 7];
+    assert_eq!(
+        Err(LayoutError::IterLength {
+            expected: 4,
+            actual: 2
+        }),
+        packet::name::write_from_iter(&mut storage, [1, 2])
+    );
+    assert_eq!(&[1, 2, 0, 0], packet::View::new(&storage).name());
+}
+
+#[test]
+fn array_too_long() {
+    let mut storage = [0; 7];
+    let mut iter = 1..;
+    assert_eq!(
+        Err(LayoutError::IterLength {
+            expected: 4,
+            actual: 5
+        }),
+        packet::name::write_from_iter(&mut storage, &mut iter)
+    );
+    assert_eq!(&[1, 2, 3, 4], packet::View::new(&storage).name());
+    // The iterator isn't advanced further than necessary to detect the error
+    assert_eq!(Some(6), iter.next());
+    assert_eq!(0, packet::View::new(&storage).payload()[0]);
+}
+
+#[test]
+fn open_ended_bytes() {
+    let mut storage = vec![0; 8];
+    assert_eq!(
+        Ok(()),
+        packet::payload::write_from_iter(&mut storage, [7, 8, 9])
+    );
+    assert_eq!(&[7, 8, 9], packet::View::new(&storage).payload());
+    assert_eq!(
+        Err(LayoutError::IterLength {
+            expected: 3,
+            actual: 4
+        }),
+        packet::payload::write_from_iter(&mut storage, [1, 2, 3, 4])
+    );
+    assert_eq!(
+        Err(LayoutError::IterLength {
+            expected: 3,
+            actual: 0
+        }),
+        packet::payload::write_from_iter(&mut storage, [])
+    );
+}
+
+#[test]
+fn elements_exact() {
+    let mut view = samples::View::new(vec![0; 7]);
+    assert_eq!(
+        Ok(()),
+        view.data_mut().write_from_iter([0x0102, 0x0304, 0xFFFF])
+    );
+    assert_eq!(
+        vec![0x0102, 0x0304, 0xFFFF],
+        view.data().iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        &[0, 0x01, 0x02, 0x03, 0x04, 0xFF, 0xFF],
+        &view.into_storage()[..]
+    );
+}
+
+#[test]
+fn elements_short() {
+    let mut view = samples::View::new(vec![0; 7]);
+    assert_eq!(
+        Err(LayoutError::IterLength {
+            expected: 3,
+            actual: 1
+        }),
+        view.data_mut().write_from_iter([0x0102])
+    );
+    assert_eq!(vec![0x0102, 0, 0], view.data().iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn elements_too_long() {
+    let mut view = samples::View::new(vec![0; 5]);
+    assert_eq!(
+        Err(LayoutError::IterLength {
+            expected: 2,
+            actual: 3
+        }),
+        view.data_mut().write_from_iter(1..)
+    );
+    assert_eq!(vec![1, 2], view.data().iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn propagate_as_layout_error() {
+    fn fill(storage: &mut [u8]) -> Result<(), LayoutError> {
+        packet::name::write_from_iter(storage, [1, 2, 3])?;
+        Ok(())
+    }
+    assert_eq!(
+        Err(LayoutError::IterLength {
+            expected: 4,
+            actual: 3
+        }),
+        fill(&mut [0; 7])
+    );
+}